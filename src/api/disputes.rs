@@ -0,0 +1,509 @@
+//! Use the Disputes API to manage disputes.
+//!
+//! A customer can dispute a transaction, after which the merchant must respond to it. The merchant can provide
+//! evidence, accept the claim, make an offer or appeal the outcome of a dispute they lost.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/customer-disputes/v1/>
+
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use reqwest::multipart::{Form, Part};
+use serde::Serialize;
+
+use crate::{
+    data::common::Patch,
+    data::disputes::{
+        AcknowledgeReturnedItemPayload, Dispute, Evidence, EvidenceFile, EvidencePayload, MakeOfferPayload,
+        MessagePayload, NotePayload, RequireEvidenceAction, RequireEvidencePayload, SubsequentAction,
+    },
+    endpoint::Endpoint,
+};
+
+/// Builds the multipart form used by the endpoints that accept evidence documents.
+///
+/// The json payload goes in the `input` part and each file in its own `fileN` part.
+fn evidence_form(payload: &impl Serialize, files: &[EvidenceFile]) -> Form {
+    let input = serde_json::to_string(payload).expect("serialize the evidence payload correctly");
+    let mut form = Form::new().part(
+        "input",
+        Part::text(input)
+            .mime_str("application/json")
+            .expect("application/json is a valid mime type"),
+    );
+
+    for (i, file) in files.iter().enumerate() {
+        let part = Part::bytes(file.content.clone())
+            .file_name(file.file_name.clone())
+            .mime_str(file.mime_type())
+            .expect("the mime type is validated by EvidenceFile::new");
+        form = form.part(format!("file{}", i + 1), part);
+    }
+
+    form
+}
+
+/// Appeals a dispute, by ID. To appeal a dispute, use the appeal link in the HATEOAS links from the show dispute details response.
+///
+/// If this link does not appear, you cannot appeal the dispute.
+/// Submit new evidence as a document or notes in the JSON request body.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct AppealDispute {
+    /// The ID of the dispute to appeal.
+    pub dispute_id: String,
+    /// The new evidences for the dispute.
+    pub evidences: Vec<Evidence>,
+    /// The documents uploaded along with the evidences.
+    #[builder(default)]
+    pub files: Vec<EvidenceFile>,
+}
+
+impl AppealDispute {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString, evidences: Vec<Evidence>) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            evidences,
+            files: Vec::new(),
+        }
+    }
+
+    /// Attaches a document to the appeal.
+    pub fn file(mut self, file: EvidenceFile) -> Self {
+        self.files.push(file);
+        self
+    }
+}
+
+impl Endpoint for AppealDispute {
+    type Query = ();
+
+    type Body = EvidencePayload;
+
+    type Response = SubsequentAction;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/disputes/{}/appeal", self.dispute_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(EvidencePayload {
+            evidences: self.evidences.clone(),
+        })
+    }
+
+    fn multipart(&self) -> Option<Form> {
+        Some(evidence_form(&self.body()?, &self.files))
+    }
+}
+
+/// Acknowledges that the customer returned an item for a dispute, by ID.
+///
+/// A merchant can use this call for disputes with the `MERCHANDISE_OR_SERVICE_NOT_AS_DESCRIBED` reason.
+/// Documents supporting the acknowledgement can be uploaded along with it.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct AcknowledgeReturnedItem {
+    /// The ID of the dispute for which to acknowledge the returned item.
+    pub dispute_id: String,
+    /// The acknowledgement payload.
+    #[builder(default)]
+    pub payload: AcknowledgeReturnedItemPayload,
+    /// The documents uploaded along with the acknowledgement.
+    #[builder(default)]
+    pub files: Vec<EvidenceFile>,
+}
+
+impl AcknowledgeReturnedItem {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString, payload: AcknowledgeReturnedItemPayload) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            payload,
+            files: Vec::new(),
+        }
+    }
+
+    /// Attaches a document to the acknowledgement.
+    pub fn file(mut self, file: EvidenceFile) -> Self {
+        self.files.push(file);
+        self
+    }
+}
+
+impl Endpoint for AcknowledgeReturnedItem {
+    type Query = ();
+
+    type Body = AcknowledgeReturnedItemPayload;
+
+    type Response = SubsequentAction;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v1/customer/disputes/{}/acknowledge-return-item",
+            self.dispute_id
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+
+    fn multipart(&self) -> Option<Form> {
+        if self.files.is_empty() {
+            return None;
+        }
+        Some(evidence_form(&self.payload, &self.files))
+    }
+}
+
+/// Makes an offer to the other party to resolve a dispute, by ID.
+///
+/// To make this call, the stage in the dispute lifecycle must be `INQUIRY`.
+/// If the customer accepts the offer, PayPal automatically makes a refund.
+#[derive(Debug, Clone)]
+pub struct MakeOffer {
+    /// The ID of the dispute for which to make an offer.
+    pub dispute_id: String,
+    /// The offer details.
+    pub payload: MakeOfferPayload,
+}
+
+impl MakeOffer {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString, payload: MakeOfferPayload) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            payload,
+        }
+    }
+}
+
+impl Endpoint for MakeOffer {
+    type Query = ();
+
+    type Body = MakeOfferPayload;
+
+    type Response = SubsequentAction;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/disputes/{}/make-offer", self.dispute_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Accepts the customer's offer to resolve a dispute, by ID.
+#[derive(Debug, Clone)]
+pub struct AcceptOffer {
+    /// The ID of the dispute for which to accept an offer.
+    pub dispute_id: String,
+    /// The merchant's notes about accepting the offer.
+    pub payload: NotePayload,
+}
+
+impl AcceptOffer {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            payload: NotePayload::default(),
+        }
+    }
+
+    /// Sets the note sent along with the acceptance.
+    pub fn note(mut self, note: impl ToString) -> Self {
+        self.payload = NotePayload::new(note);
+        self
+    }
+}
+
+impl Endpoint for AcceptOffer {
+    type Query = ();
+
+    type Body = NotePayload;
+
+    type Response = SubsequentAction;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/disputes/{}/accept-offer", self.dispute_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Denies the customer's offer to resolve a dispute, by ID. The dispute then continues through its lifecycle.
+#[derive(Debug, Clone)]
+pub struct DenyOffer {
+    /// The ID of the dispute for which to deny an offer.
+    pub dispute_id: String,
+    /// The merchant's notes about denying the offer.
+    pub payload: NotePayload,
+}
+
+impl DenyOffer {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString, note: impl ToString) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            payload: NotePayload::new(note),
+        }
+    }
+}
+
+impl Endpoint for DenyOffer {
+    type Query = ();
+
+    type Body = NotePayload;
+
+    type Response = SubsequentAction;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/disputes/{}/deny-offer", self.dispute_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Escalates the dispute, by ID, to a PayPal claim. To make this call, the stage in the dispute lifecycle must be `INQUIRY`.
+///
+/// Useful when the communication with the customer stalls and PayPal should step in to decide the outcome.
+#[derive(Debug, Clone)]
+pub struct EscalateDispute {
+    /// The ID of the dispute to escalate to a claim.
+    pub dispute_id: String,
+    /// The notes about the escalation of the dispute to a claim.
+    pub payload: NotePayload,
+}
+
+impl EscalateDispute {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString, note: impl ToString) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            payload: NotePayload::new(note),
+        }
+    }
+}
+
+impl Endpoint for EscalateDispute {
+    type Query = ();
+
+    type Body = NotePayload;
+
+    type Response = SubsequentAction;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/disputes/{}/escalate", self.dispute_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Shows details for a dispute, by ID, including the thread of messages between the parties.
+#[derive(Debug, Clone)]
+pub struct GetDispute {
+    /// The ID of the dispute for which to show details.
+    pub dispute_id: String,
+}
+
+impl GetDispute {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetDispute {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Dispute;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/disputes/{}", self.dispute_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Sends a message about a dispute, by ID, to the other party in the dispute.
+///
+/// Documents can be attached to the message.
+#[derive(Debug, Clone)]
+pub struct SendDisputeMessage {
+    /// The ID of the dispute for which to send a message.
+    pub dispute_id: String,
+    /// The message.
+    pub payload: MessagePayload,
+    /// The documents attached to the message.
+    pub files: Vec<EvidenceFile>,
+}
+
+impl SendDisputeMessage {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString, message: impl ToString) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            payload: MessagePayload {
+                message: message.to_string(),
+            },
+            files: Vec::new(),
+        }
+    }
+
+    /// Attaches a document to the message.
+    pub fn file(mut self, file: EvidenceFile) -> Self {
+        self.files.push(file);
+        self
+    }
+}
+
+impl Endpoint for SendDisputeMessage {
+    type Query = ();
+
+    type Body = MessagePayload;
+
+    type Response = SubsequentAction;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/disputes/{}/send-message", self.dispute_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+
+    fn multipart(&self) -> Option<Form> {
+        if self.files.is_empty() {
+            return None;
+        }
+        Some(evidence_form(&self.payload, &self.files))
+    }
+}
+
+/// Partially updates a dispute, by ID.
+///
+/// In the sandbox this is used to update the dispute status while testing, for example to add
+/// `partner_actions` or to update the `communication_detail`.
+#[derive(Debug, Clone)]
+pub struct UpdateDispute {
+    /// The ID of the dispute to update.
+    pub dispute_id: String,
+    /// The patches to apply to the dispute.
+    pub patches: Vec<Patch>,
+}
+
+impl UpdateDispute {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString, patches: Vec<Patch>) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            patches,
+        }
+    }
+}
+
+impl Endpoint for UpdateDispute {
+    type Query = ();
+
+    type Body = Vec<Patch>;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/disputes/{}", self.dispute_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::PATCH
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.patches.clone())
+    }
+}
+
+/// Updates the status of a dispute, by ID, from `UNDER_REVIEW` to either `WAITING_FOR_BUYER_RESPONSE`
+/// or `WAITING_FOR_SELLER_RESPONSE`.
+///
+/// This call is only supported in the sandbox environment, it allows driving a dispute through its lifecycle in tests.
+#[derive(Debug, Clone)]
+pub struct RequireEvidence {
+    /// The ID of the dispute that requires evidence.
+    pub dispute_id: String,
+    /// The party from which evidence is requested.
+    pub payload: RequireEvidencePayload,
+}
+
+impl RequireEvidence {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString, action: RequireEvidenceAction) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            payload: RequireEvidencePayload { action },
+        }
+    }
+}
+
+impl Endpoint for RequireEvidence {
+    type Query = ();
+
+    type Body = RequireEvidencePayload;
+
+    type Response = SubsequentAction;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/disputes/{}/require-evidence", self.dispute_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
@@ -0,0 +1,88 @@
+//! Use the Identity API to get the profile of users logged in with PayPal and to generate client tokens.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/identity/v1/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::identity::{ClientToken, GenerateTokenPayload, UserInfo, UserInfoQuery},
+    endpoint::Endpoint,
+};
+
+/// Shows user profile information, filtered by the scopes the user consented to.
+///
+/// It must be executed with the access token of the user, obtained through Log In with PayPal.
+#[derive(Debug, Default, Clone)]
+pub struct GetUserInfo {
+    /// The endpoint query.
+    pub query: UserInfoQuery,
+}
+
+impl GetUserInfo {
+    /// New constructor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Endpoint for GetUserInfo {
+    type Query = UserInfoQuery;
+
+    type Body = ();
+
+    type Response = UserInfo;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/identity/openidconnect/userinfo")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+/// Generates a client token, needed to initialize the JS SDK for advanced card fields and hosted fields.
+///
+/// When a customer id is given, the token is scoped to that customer so their vaulted payment methods can be used.
+#[derive(Debug, Default, Clone)]
+pub struct GenerateClientToken {
+    /// The endpoint body.
+    pub payload: GenerateTokenPayload,
+}
+
+impl GenerateClientToken {
+    /// New constructor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scopes the token to the given vault customer.
+    pub fn customer_id(mut self, customer_id: impl ToString) -> Self {
+        self.payload.customer_id = Some(customer_id.to_string());
+        self
+    }
+}
+
+impl Endpoint for GenerateClientToken {
+    type Query = ();
+
+    type Body = GenerateTokenPayload;
+
+    type Response = ClientToken;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/identity/generate-token")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
@@ -14,10 +14,13 @@ use serde::Serialize;
 use crate::{
     Query,
     data::{
+        common::LinkDescription,
+        ids::InvoiceId,
         invoice::{CancelReason, Invoice, InvoiceList, InvoicePayload, SendInvoicePayload},
         orders::InvoiceNumber,
     },
     endpoint::Endpoint,
+    pagination::PaginatedEndpoint,
 };
 
 /// Generates the next invoice number that is available to the merchant.
@@ -45,7 +48,7 @@ impl Endpoint for GenerateInvoiceNumber {
 
     type Response = InvoiceNumber;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Borrowed("/v2/invoicing/generate-next-invoice-number")
     }
 
@@ -80,7 +83,7 @@ impl Endpoint for CreateDraftInvoice {
 
     type Response = Invoice;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Borrowed("/v2/invoicing/invoices")
     }
 
@@ -97,14 +100,14 @@ impl Endpoint for CreateDraftInvoice {
 #[derive(Debug, Clone)]
 pub struct GetInvoice {
     /// The invoice id.
-    pub invoice_id: String,
+    pub invoice_id: InvoiceId,
 }
 
 impl GetInvoice {
     /// New constructor.
-    pub fn new(invoice_id: impl ToString) -> Self {
+    pub fn new(invoice_id: impl Into<InvoiceId>) -> Self {
         Self {
-            invoice_id: invoice_id.to_string(),
+            invoice_id: invoice_id.into(),
         }
     }
 }
@@ -116,7 +119,7 @@ impl Endpoint for GetInvoice {
 
     type Response = Invoice;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/invoicing/invoices/{}", self.invoice_id))
     }
 
@@ -147,7 +150,7 @@ impl Endpoint for ListInvoices {
 
     type Response = InvoiceList;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Borrowed("/v2/invoicing/invoices")
     }
 
@@ -160,6 +163,34 @@ impl Endpoint for ListInvoices {
     }
 }
 
+impl PaginatedEndpoint for ListInvoices {
+    type Item = Invoice;
+
+    fn page(&self) -> Option<i32> {
+        self.query.page
+    }
+
+    fn set_page(&mut self, page: i32) {
+        self.query.page = Some(page);
+    }
+
+    fn total_pages(response: &InvoiceList) -> Option<i32> {
+        Some(response.total_pages)
+    }
+
+    fn links(response: &InvoiceList) -> &[LinkDescription] {
+        &response.links
+    }
+
+    fn len(response: &InvoiceList) -> usize {
+        response.items.len()
+    }
+
+    fn into_items(response: InvoiceList) -> Vec<Invoice> {
+        response.items
+    }
+}
+
 /// Deletes a draft or scheduled invoice, by ID. Deletes invoices in the draft or scheduled state only.
 ///
 /// For invoices that have already been sent, you can cancel the invoice.
@@ -168,14 +199,14 @@ impl Endpoint for ListInvoices {
 #[derive(Debug, Clone)]
 pub struct DeleteInvoice {
     /// The invocie id.
-    pub invoice_id: String,
+    pub invoice_id: InvoiceId,
 }
 
 impl DeleteInvoice {
     /// New constructor.
-    pub fn new(invoice_id: impl ToString) -> Self {
+    pub fn new(invoice_id: impl Into<InvoiceId>) -> Self {
         Self {
-            invoice_id: invoice_id.to_string(),
+            invoice_id: invoice_id.into(),
         }
     }
 }
@@ -187,7 +218,7 @@ impl Endpoint for DeleteInvoice {
 
     type Response = ();
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/invoicing/invoices/{}", self.invoice_id))
     }
 
@@ -230,7 +261,7 @@ impl Endpoint for UpdateInvoice {
 
     type Response = Invoice;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/invoicing/invoices/{}", self.invoice.id))
     }
 
@@ -251,16 +282,16 @@ impl Endpoint for UpdateInvoice {
 #[derive(Debug, Clone)]
 pub struct CancelInvoice {
     /// The invoice id.
-    pub invoice_id: String,
+    pub invoice_id: InvoiceId,
     /// The reason of the cancelation.
     pub reason: CancelReason,
 }
 
 impl CancelInvoice {
     /// New constructor.
-    pub fn new(invoice_id: impl ToString, reason: CancelReason) -> Self {
+    pub fn new(invoice_id: impl Into<InvoiceId>, reason: CancelReason) -> Self {
         Self {
-            invoice_id: invoice_id.to_string(),
+            invoice_id: invoice_id.into(),
             reason,
         }
     }
@@ -273,7 +304,7 @@ impl Endpoint for CancelInvoice {
 
     type Response = ();
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/invoicing/invoices/{}/cancel", self.invoice_id))
     }
 
@@ -290,16 +321,16 @@ impl Endpoint for CancelInvoice {
 #[derive(Debug, Clone)]
 pub struct SendInvoice {
     /// The invoice id.
-    pub invoice_id: String,
+    pub invoice_id: InvoiceId,
     /// The payload.
     pub payload: SendInvoicePayload,
 }
 
 impl SendInvoice {
     /// New constructor.
-    pub fn new(invoice_id: impl ToString, payload: SendInvoicePayload) -> Self {
+    pub fn new(invoice_id: impl Into<InvoiceId>, payload: SendInvoicePayload) -> Self {
         Self {
-            invoice_id: invoice_id.to_string(),
+            invoice_id: invoice_id.into(),
             payload,
         }
     }
@@ -312,7 +343,7 @@ impl Endpoint for SendInvoice {
 
     type Response = ();
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/invoicing/invoices/{}/send", self.invoice_id))
     }
 
@@ -1,6 +1,11 @@
 //! This module contains the api endpoints.
 
+pub mod disputes;
+pub mod identity;
 pub mod invoice;
 pub mod orders;
+pub mod partner_referrals;
 pub mod payments;
 pub mod tracking;
+pub mod transactions;
+pub mod vault;
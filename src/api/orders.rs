@@ -8,7 +8,11 @@ use derive_builder::Builder;
 use serde::Serialize;
 
 use crate::{
-    data::orders::{Order, OrderPayload},
+    data::{
+        common::Patch,
+        ids::OrderId,
+        orders::{Order, OrderPayload},
+    },
     endpoint::Endpoint,
 };
 
@@ -33,7 +37,7 @@ impl Endpoint for CreateOrder {
 
     type Response = Order;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Borrowed("/v2/checkout/orders")
     }
 
@@ -50,14 +54,14 @@ impl Endpoint for CreateOrder {
 #[derive(Debug)]
 pub struct ShowOrderDetails {
     /// The order id.
-    pub order_id: String,
+    pub order_id: OrderId,
 }
 
 impl ShowOrderDetails {
     /// New constructor.
-    pub fn new(order_id: &str) -> Self {
+    pub fn new(order_id: impl Into<OrderId>) -> Self {
         Self {
-            order_id: order_id.to_string(),
+            order_id: order_id.into(),
         }
     }
 }
@@ -69,7 +73,7 @@ impl Endpoint for ShowOrderDetails {
 
     type Response = Order;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/checkout/orders/{}", self.order_id))
     }
 
@@ -78,6 +82,55 @@ impl Endpoint for ShowOrderDetails {
     }
 }
 
+/// Updates an order with a `CREATED` or `APPROVED` status, for example its amount or shipping address.
+///
+/// ```
+/// use paypal_rs::{api::orders::UpdateOrder, data::{common::Patch, orders::Amount}};
+///
+/// let update = UpdateOrder::new(
+///     "5O190127TN364715T",
+///     vec![Patch::replace(Patch::purchase_unit_path("default", "amount")?, Amount::usd("15.00"))],
+/// );
+/// # Ok::<(), paypal_rs::errors::InvalidReferenceIdError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct UpdateOrder {
+    /// The id of the order.
+    pub order_id: OrderId,
+    /// The patches to apply to the order.
+    pub patches: Vec<Patch>,
+}
+
+impl UpdateOrder {
+    /// New constructor.
+    pub fn new(order_id: impl Into<OrderId>, patches: Vec<Patch>) -> Self {
+        Self {
+            order_id: order_id.into(),
+            patches,
+        }
+    }
+}
+
+impl Endpoint for UpdateOrder {
+    type Query = ();
+
+    type Body = Vec<Patch>;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/checkout/orders/{}", self.order_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::PATCH
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.patches.clone())
+    }
+}
+
 /// The payment source used to fund the payment.
 #[derive(Debug, Serialize, Builder, Clone)]
 pub struct PaymentSourceToken {
@@ -110,16 +163,16 @@ pub struct PaymentSourceBody {
 #[derive(Debug, Clone, Builder)]
 pub struct CaptureOrder {
     /// The id of the order.
-    pub order_id: String,
+    pub order_id: OrderId,
     /// The endpoint body.
     pub body: PaymentSourceBody,
 }
 
 impl CaptureOrder {
     /// New constructor.
-    pub fn new(order_id: &str) -> Self {
+    pub fn new(order_id: impl Into<OrderId>) -> Self {
         Self {
-            order_id: order_id.to_string(),
+            order_id: order_id.into(),
             body: PaymentSourceBody::default(),
         }
     }
@@ -132,7 +185,7 @@ impl Endpoint for CaptureOrder {
 
     type Response = Order;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/checkout/orders/{}/capture", self.order_id))
     }
 
@@ -151,16 +204,16 @@ impl Endpoint for CaptureOrder {
 #[derive(Debug)]
 pub struct AuthorizeOrder {
     /// The order id.
-    order_id: String,
+    order_id: OrderId,
     /// The endpoint body.
     pub body: PaymentSourceBody,
 }
 
 impl AuthorizeOrder {
     /// New constructor.
-    pub fn new(order_id: &str) -> Self {
+    pub fn new(order_id: impl Into<OrderId>) -> Self {
         Self {
-            order_id: order_id.to_string(),
+            order_id: order_id.into(),
             body: PaymentSourceBody::default(),
         }
     }
@@ -173,7 +226,7 @@ impl Endpoint for AuthorizeOrder {
 
     type Response = Order;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/checkout/orders/{}/authorize", self.order_id))
     }
 
@@ -0,0 +1,78 @@
+//! Use the Partner Referrals API to add PayPal seller accounts to PayPal Complete Payments Platform for Marketplaces and Platforms.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/partner-referrals/v2/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::partner_referrals::{PartnerReferral, PartnerReferralPayload, ReferralData},
+    endpoint::Endpoint,
+};
+
+/// Creates a partner referral that is shared by the API caller.
+///
+/// The seller is then redirected to the returned action URL to onboard.
+#[derive(Debug, Clone)]
+pub struct CreatePartnerReferral {
+    /// The referral data.
+    pub payload: PartnerReferralPayload,
+}
+
+impl CreatePartnerReferral {
+    /// New constructor.
+    pub fn new(payload: PartnerReferralPayload) -> Self {
+        Self { payload }
+    }
+}
+
+impl Endpoint for CreatePartnerReferral {
+    type Query = ();
+
+    type Body = PartnerReferralPayload;
+
+    type Response = PartnerReferral;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v2/customer/partner-referrals")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Shows details for a referral, by ID, to inspect the onboarding state of a referred seller.
+#[derive(Debug, Clone)]
+pub struct ShowReferralData {
+    /// The ID of the referral, see [PartnerReferral::referral_id].
+    pub referral_id: String,
+}
+
+impl ShowReferralData {
+    /// New constructor.
+    pub fn new(referral_id: impl ToString) -> Self {
+        Self {
+            referral_id: referral_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowReferralData {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = ReferralData;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/customer/partner-referrals/{}", self.referral_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -6,7 +6,14 @@ use std::borrow::Cow;
 
 use derive_builder::Builder;
 
-use crate::{data::payment::*, endpoint::Endpoint};
+use crate::{
+    data::{
+        ids::{AuthorizationId, CaptureId},
+        orders::Refund,
+        payment::*,
+    },
+    endpoint::Endpoint,
+};
 
 /// Generates the next invoice number that is available to the merchant.
 ///
@@ -16,14 +23,14 @@ use crate::{data::payment::*, endpoint::Endpoint};
 #[derive(Debug, Default, Clone, Builder)]
 pub struct GetAuthorizedPayment {
     /// The ID of the authorized payment for which to show details.
-    pub authorization_id: String,
+    pub authorization_id: AuthorizationId,
 }
 
 impl GetAuthorizedPayment {
     /// New constructor.
-    pub fn new(authorization_id: impl ToString) -> Self {
+    pub fn new(authorization_id: impl Into<AuthorizationId>) -> Self {
         Self {
-            authorization_id: authorization_id.to_string(),
+            authorization_id: authorization_id.into(),
         }
     }
 }
@@ -35,7 +42,7 @@ impl Endpoint for GetAuthorizedPayment {
 
     type Response = AuthorizedPaymentDetails;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/payments/authorizations/{}", self.authorization_id))
     }
 
@@ -43,3 +50,78 @@ impl Endpoint for GetAuthorizedPayment {
         reqwest::Method::GET
     }
 }
+
+/// Refunds a captured payment, by ID. For a full refund, include an empty payload in the JSON request body.
+/// For a partial refund, include an amount object in the JSON request body.
+#[derive(Debug, Default, Clone, Builder)]
+pub struct RefundCapturedPayment {
+    /// The PayPal-generated ID for the captured payment to refund.
+    pub capture_id: CaptureId,
+    /// The refund details.
+    #[builder(default)]
+    pub payload: RefundRequest,
+}
+
+impl RefundCapturedPayment {
+    /// New constructor.
+    pub fn new(capture_id: impl Into<CaptureId>, payload: RefundRequest) -> Self {
+        Self {
+            capture_id: capture_id.into(),
+            payload,
+        }
+    }
+}
+
+impl Endpoint for RefundCapturedPayment {
+    type Query = ();
+
+    type Body = RefundRequest;
+
+    type Response = Refund;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/payments/captures/{}/refund", self.capture_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Voids, or cancels, an authorized payment, by ID. You cannot void an authorized payment that has been fully captured.
+///
+/// PayPal answers without a body unless the full representation is preferred, so the response is optional.
+#[derive(Debug, Default, Clone, Builder)]
+pub struct VoidAuthorizedPayment {
+    /// The PayPal-generated ID for the authorized payment to void.
+    pub authorization_id: AuthorizationId,
+}
+
+impl VoidAuthorizedPayment {
+    /// New constructor.
+    pub fn new(authorization_id: impl Into<AuthorizationId>) -> Self {
+        Self {
+            authorization_id: authorization_id.into(),
+        }
+    }
+}
+
+impl Endpoint for VoidAuthorizedPayment {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Option<AuthorizedPaymentDetails>;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/payments/authorizations/{}/void", self.authorization_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+}
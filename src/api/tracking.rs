@@ -1,10 +1,13 @@
-//! This module contains the api for order tracking.
+//! This module contains the api for order tracking and the shipping api.
 
 #![allow(dead_code)]
 
+use crate::Client;
+use crate::data::ids::{OrderId, TrackerId};
 use crate::data::orders::Order;
-use crate::data::tracking::OrderTracking;
+use crate::data::tracking::{NotifyPayerPolicy, OrderTracking, Tracker, TrackersBatch, TrackersBatchResponse};
 use crate::endpoint::Endpoint;
+use crate::errors::BatchTrackingError;
 use derive_builder::Builder;
 use std::borrow::Cow;
 
@@ -12,16 +15,16 @@ use std::borrow::Cow;
 #[derive(Debug, Clone, Builder)]
 pub struct AddOrderTracking {
     /// The id of the order.
-    pub order_id: String,
+    pub order_id: OrderId,
     /// The endpoint body.
     pub body: OrderTracking,
 }
 
 impl AddOrderTracking {
     /// New constructor.
-    pub fn new(order_id: &str, body: OrderTracking) -> Self {
+    pub fn new(order_id: impl Into<OrderId>, body: OrderTracking) -> Self {
         Self {
-            order_id: order_id.to_string(),
+            order_id: order_id.into(),
             body,
         }
     }
@@ -34,7 +37,7 @@ impl Endpoint for AddOrderTracking {
 
     type Response = Order;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/checkout/orders/{}/track", self.order_id))
     }
 
@@ -46,3 +49,189 @@ impl Endpoint for AddOrderTracking {
         Some(self.body.clone())
     }
 }
+
+/// Adds tracking information for several captures of the same order.
+///
+/// The trackers are added one after the other, the payer notification is decided by the [NotifyPayerPolicy]
+/// and failures don't stop the rest of the batch, they are reported together once it finishes.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct AddOrderTrackingBatch {
+    /// The id of the order.
+    pub order_id: OrderId,
+    /// The trackers to add, one per capture.
+    pub trackers: Vec<OrderTracking>,
+    /// Which trackers notify the payer, overriding their `notify_payer` field.
+    #[builder(default)]
+    pub notify_payer: NotifyPayerPolicy,
+}
+
+impl AddOrderTrackingBatch {
+    /// New constructor.
+    pub fn new(order_id: impl Into<OrderId>, trackers: Vec<OrderTracking>, notify_payer: NotifyPayerPolicy) -> Self {
+        Self {
+            order_id: order_id.into(),
+            trackers,
+            notify_payer,
+        }
+    }
+
+    /// Adds the trackers, returning the order as updated by the last one.
+    ///
+    /// Fails with [BatchTrackingError::Empty] without sending anything when there are no trackers.
+    pub async fn run(&self, client: &Client) -> Result<Order, BatchTrackingError> {
+        if self.trackers.is_empty() {
+            return Err(BatchTrackingError::Empty);
+        }
+
+        let mut order = None;
+        let mut failed = Vec::new();
+        let mut notified = false;
+
+        for tracker in &self.trackers {
+            let notify = match self.notify_payer {
+                NotifyPayerPolicy::Never => false,
+                NotifyPayerPolicy::Always => true,
+                NotifyPayerPolicy::FirstOnly => !notified,
+            };
+
+            let mut body = tracker.clone();
+            body.notify_payer = Some(notify);
+
+            match client.execute(&AddOrderTracking::new(&self.order_id, body)).await {
+                Ok(updated) => {
+                    notified |= notify;
+                    order = Some(updated);
+                }
+                Err(e) => failed.push((tracker.capture_id.clone(), e)),
+            }
+        }
+
+        match order {
+            Some(order) if failed.is_empty() => Ok(order),
+            order => Err(BatchTrackingError::Failed {
+                order,
+                total: self.trackers.len(),
+                failed,
+            }),
+        }
+    }
+}
+
+/// Adds tracking information, with or without tracking numbers, for multiple PayPal transactions.
+///
+/// Each tracker is added independently, the response lists the ones added and the errors of the rest.
+#[derive(Debug, Clone)]
+pub struct AddTrackersBatch {
+    /// The trackers to add.
+    pub trackers: Vec<Tracker>,
+}
+
+impl AddTrackersBatch {
+    /// New constructor.
+    pub fn new(trackers: Vec<Tracker>) -> Self {
+        Self { trackers }
+    }
+}
+
+impl Endpoint for AddTrackersBatch {
+    type Query = ();
+
+    type Body = TrackersBatch;
+
+    type Response = TrackersBatchResponse;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/shipping/trackers-batch")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(TrackersBatch {
+            trackers: self.trackers.clone(),
+        })
+    }
+}
+
+/// Shows tracking information for a tracking number of a transaction.
+#[derive(Debug, Clone)]
+pub struct GetTracker {
+    /// The PayPal transaction ID.
+    pub transaction_id: String,
+    /// The tracking number for the shipment.
+    pub tracking_number: String,
+}
+
+impl GetTracker {
+    /// New constructor.
+    pub fn new(transaction_id: impl ToString, tracking_number: impl ToString) -> Self {
+        Self {
+            transaction_id: transaction_id.to_string(),
+            tracking_number: tracking_number.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetTracker {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Tracker;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v1/shipping/trackers/{}",
+            TrackerId::from_parts(&self.transaction_id, &self.tracking_number)
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Updates or cancels the tracking information of a transaction, by its tracker id.
+///
+/// The tracker id is the one it was created with, see [Tracker::id], so a corrected
+/// tracking number goes in the body while the path keeps the old one.
+#[derive(Debug, Clone)]
+pub struct UpdateTracker {
+    /// The id of the tracker, formatted as `{transaction_id}-{tracking_number}`.
+    pub tracker_id: TrackerId,
+    /// The new tracking information.
+    pub tracker: Tracker,
+}
+
+impl UpdateTracker {
+    /// New constructor.
+    pub fn new(tracker_id: impl Into<TrackerId>, tracker: Tracker) -> Self {
+        Self {
+            tracker_id: tracker_id.into(),
+            tracker,
+        }
+    }
+}
+
+impl Endpoint for UpdateTracker {
+    type Query = ();
+
+    type Body = Tracker;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/shipping/trackers/{}", self.tracker_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::PUT
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.tracker.clone())
+    }
+}
@@ -0,0 +1,142 @@
+//! Use the Transaction Search API to get the history of transactions and the balances for a PayPal account.
+//!
+//! It takes a maximum of three hours for executed transactions to appear in the list transactions call.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/transaction-search/v1/>
+
+use std::borrow::Cow;
+
+use futures::Stream;
+
+use crate::{
+    Client,
+    data::{
+        common::LinkDescription,
+        transactions::{
+            BalancesQuery, BalancesResponse, TransactionDetail, TransactionSearchQuery, TransactionSearchResponse,
+        },
+    },
+    endpoint::Endpoint,
+    errors::ResponseError,
+    pagination::PaginatedEndpoint,
+};
+
+/// Lists transactions. Specify one or more query parameters to filter the transaction that appear in the response.
+///
+/// The start and end date are required and can't be more than 31 days apart.
+#[derive(Debug, Clone)]
+pub struct ListTransactions {
+    /// The endpoint query.
+    pub query: TransactionSearchQuery,
+}
+
+impl ListTransactions {
+    /// New constructor.
+    pub fn new(query: TransactionSearchQuery) -> Self {
+        Self { query }
+    }
+
+    /// Returns a stream of the matching transactions, fetching every page from the query page (or the first one) onwards.
+    ///
+    /// Rate limited pages are retried as set by the [RetryPolicy](crate::retry::RetryPolicy) of the client.
+    /// The stream ends after yielding the first error.
+    ///
+    /// ```no_run
+    /// # async fn run(client: paypal_rs::Client, query: paypal_rs::data::transactions::TransactionSearchQuery) {
+    /// use futures::TryStreamExt;
+    /// use paypal_rs::api::transactions::ListTransactions;
+    ///
+    /// let transactions = ListTransactions::new(query).stream(&client);
+    /// futures::pin_mut!(transactions);
+    ///
+    /// while let Some(transaction) = transactions.try_next().await.unwrap() {
+    ///     println!("{:?}", transaction.transaction_info);
+    /// }
+    /// # }
+    /// ```
+    pub fn stream(self, client: &Client) -> impl Stream<Item = Result<TransactionDetail, ResponseError>> + '_ {
+        client.items(self)
+    }
+}
+
+impl Endpoint for ListTransactions {
+    type Query = TransactionSearchQuery;
+
+    type Body = ();
+
+    type Response = TransactionSearchResponse;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/reporting/transactions")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+impl PaginatedEndpoint for ListTransactions {
+    type Item = TransactionDetail;
+
+    fn page(&self) -> Option<i32> {
+        self.query.page
+    }
+
+    fn set_page(&mut self, page: i32) {
+        self.query.page = Some(page);
+    }
+
+    fn total_pages(response: &TransactionSearchResponse) -> Option<i32> {
+        response.total_pages
+    }
+
+    fn links(response: &TransactionSearchResponse) -> &[LinkDescription] {
+        &response.links
+    }
+
+    fn len(response: &TransactionSearchResponse) -> usize {
+        response.transaction_details.len()
+    }
+
+    fn into_items(response: TransactionSearchResponse) -> Vec<TransactionDetail> {
+        response.transaction_details
+    }
+}
+
+/// Lists all balances of the account, per currency.
+#[derive(Debug, Default, Clone)]
+pub struct ListBalances {
+    /// The endpoint query.
+    pub query: BalancesQuery,
+}
+
+impl ListBalances {
+    /// New constructor.
+    pub fn new(query: BalancesQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListBalances {
+    type Query = BalancesQuery;
+
+    type Body = ();
+
+    type Response = BalancesResponse;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/reporting/balances")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
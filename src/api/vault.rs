@@ -0,0 +1,260 @@
+//! Use the Payment Method Tokens API to save payment methods so payers don't have to enter details for future transactions.
+//!
+//! A setup token holds a payment method temporarily, until the payer approves saving it.
+//! It is then exchanged for a payment token, which can be charged later.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/payment-tokens/v3/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::{
+        common::LinkDescription,
+        vault::{
+            PaymentToken, PaymentTokenList, PaymentTokenPayload, PaymentTokensQuery, SetupToken, SetupTokenPayload,
+        },
+    },
+    endpoint::Endpoint,
+    pagination::PaginatedEndpoint,
+};
+
+/// Creates a setup token, to save a payment method without an immediate purchase.
+#[derive(Debug, Clone)]
+pub struct CreateSetupToken {
+    /// The setup token details.
+    pub payload: SetupTokenPayload,
+}
+
+impl CreateSetupToken {
+    /// New constructor.
+    pub fn new(payload: SetupTokenPayload) -> Self {
+        Self { payload }
+    }
+}
+
+impl Endpoint for CreateSetupToken {
+    type Query = ();
+
+    type Body = SetupTokenPayload;
+
+    type Response = SetupToken;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v3/vault/setup-tokens")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Shows details for a setup token, by ID.
+///
+/// Useful to poll whether the payer approved saving the payment method.
+#[derive(Debug, Clone)]
+pub struct GetSetupToken {
+    /// The ID of the setup token.
+    pub setup_token_id: String,
+}
+
+impl GetSetupToken {
+    /// New constructor.
+    pub fn new(setup_token_id: impl ToString) -> Self {
+        Self {
+            setup_token_id: setup_token_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetSetupToken {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = SetupToken;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v3/vault/setup-tokens/{}", self.setup_token_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Creates a payment token, permanently saving the payment method of an approved setup token.
+#[derive(Debug, Clone)]
+pub struct CreatePaymentToken {
+    /// The payment token details.
+    pub payload: PaymentTokenPayload,
+}
+
+impl CreatePaymentToken {
+    /// New constructor.
+    pub fn new(payload: PaymentTokenPayload) -> Self {
+        Self { payload }
+    }
+
+    /// Creates the payment token from the given approved setup token.
+    pub fn from_setup_token(setup_token_id: impl ToString) -> Self {
+        Self::new(PaymentTokenPayload::from_setup_token(setup_token_id))
+    }
+}
+
+impl Endpoint for CreatePaymentToken {
+    type Query = ();
+
+    type Body = PaymentTokenPayload;
+
+    type Response = PaymentToken;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v3/vault/payment-tokens")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Lists the payment tokens saved for a customer.
+#[derive(Debug, Clone)]
+pub struct ListPaymentTokens {
+    /// The endpoint query.
+    pub query: PaymentTokensQuery,
+}
+
+impl ListPaymentTokens {
+    /// New constructor.
+    pub fn new(query: PaymentTokensQuery) -> Self {
+        Self { query }
+    }
+
+    /// Lists the first page of payment tokens of the given customer.
+    pub fn for_customer(customer_id: impl ToString) -> Self {
+        Self::new(PaymentTokensQuery {
+            customer_id: customer_id.to_string(),
+            ..Default::default()
+        })
+    }
+}
+
+impl Endpoint for ListPaymentTokens {
+    type Query = PaymentTokensQuery;
+
+    type Body = ();
+
+    type Response = PaymentTokenList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v3/vault/payment-tokens")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+impl PaginatedEndpoint for ListPaymentTokens {
+    type Item = PaymentToken;
+
+    fn page(&self) -> Option<i32> {
+        self.query.page
+    }
+
+    fn set_page(&mut self, page: i32) {
+        self.query.page = Some(page);
+    }
+
+    fn total_pages(response: &PaymentTokenList) -> Option<i32> {
+        response.total_pages
+    }
+
+    fn links(response: &PaymentTokenList) -> &[LinkDescription] {
+        &response.links
+    }
+
+    fn len(response: &PaymentTokenList) -> usize {
+        response.payment_tokens.len()
+    }
+
+    fn into_items(response: PaymentTokenList) -> Vec<PaymentToken> {
+        response.payment_tokens
+    }
+}
+
+/// Shows details for a payment token, by ID.
+#[derive(Debug, Clone)]
+pub struct GetPaymentToken {
+    /// The ID of the payment token.
+    pub payment_token_id: String,
+}
+
+impl GetPaymentToken {
+    /// New constructor.
+    pub fn new(payment_token_id: impl ToString) -> Self {
+        Self {
+            payment_token_id: payment_token_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetPaymentToken {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = PaymentToken;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v3/vault/payment-tokens/{}", self.payment_token_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Deletes a payment token, by ID, so the payment method can no longer be charged.
+#[derive(Debug, Clone)]
+pub struct DeletePaymentToken {
+    /// The ID of the payment token.
+    pub payment_token_id: String,
+}
+
+impl DeletePaymentToken {
+    /// New constructor.
+    pub fn new(payment_token_id: impl ToString) -> Self {
+        Self {
+            payment_token_id: payment_token_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for DeletePaymentToken {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v3/vault/payment-tokens/{}", self.payment_token_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::DELETE
+    }
+}
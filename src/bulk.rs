@@ -0,0 +1,255 @@
+//! Helpers to safely execute large amounts of requests.
+//!
+//! These are meant for operations like incident remediation, where thousands of payments must be processed
+//! without tripping the PayPal rate limits and with the ability to resume after an interruption.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+use futures::{StreamExt, stream};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Client, HeaderParams,
+    api::payments::RefundCapturedPayment,
+    data::{ids::CaptureId, orders::Refund},
+    endpoint::Endpoint,
+    errors::ResponseError,
+    retry::RetryPolicy,
+};
+
+/// Identifies a refund of a [BulkRefund]: the capture it refunds, and how many refunds of the same capture come
+/// before it, so the partial refunds of a capture are told apart.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct RefundKey {
+    /// The id of the refunded capture.
+    pub capture_id: CaptureId,
+    /// The position of the refund among the refunds of the same capture, starting at 0.
+    pub index: usize,
+}
+
+impl RefundKey {
+    /// New constructor.
+    pub fn new(capture_id: impl Into<CaptureId>, index: usize) -> Self {
+        Self {
+            capture_id: capture_id.into(),
+            index,
+        }
+    }
+}
+
+impl fmt::Display for RefundKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.capture_id, self.index)
+    }
+}
+
+/// The refunds already performed by a [BulkRefund], used to resume it without refunding twice.
+///
+/// It is serializable so it can be persisted as the refunds complete.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefundCheckpoint {
+    /// The keys of the refunds that were performed.
+    pub completed: BTreeSet<RefundKey>,
+}
+
+/// The outcome of a [BulkRefund].
+#[derive(Debug)]
+pub struct BulkRefundReport {
+    /// The refunds performed during this run.
+    pub refunded: Vec<Refund>,
+    /// The keys of the refunds skipped because they were already in the checkpoint.
+    pub skipped: Vec<RefundKey>,
+    /// The keys of the refunds that could not be performed, along with the error.
+    pub failed: Vec<(RefundKey, ResponseError)>,
+    /// The checkpoint including the refunds performed during this run.
+    pub checkpoint: RefundCheckpoint,
+}
+
+/// Refunds a set of captured payments with bounded concurrency.
+///
+/// Each refund is sent with a `PayPal-Request-Id` made of the idempotency prefix and its [RefundKey], so it is
+/// never performed twice, even when retried or resumed. Rate limited refunds are retried with the [RetryPolicy]
+/// given to [BulkRefund::retry_policy], else the one of the client, else [RetryPolicy::default].
+///
+/// ```no_run
+/// # async fn run(client: paypal_rs::Client) {
+/// use paypal_rs::{api::payments::RefundCapturedPayment, bulk::BulkRefund};
+///
+/// let refunds = vec![
+///     RefundCapturedPayment::new("2GG279541U471931P", Default::default()),
+///     RefundCapturedPayment::new("8MC585209K746392H", Default::default()),
+/// ];
+///
+/// let report = BulkRefund::new(refunds)
+///     .concurrency(4)
+///     .idempotency_prefix("incident-1234-")
+///     .run(&client)
+///     .await;
+///
+/// println!("refunded {}, failed {}", report.refunded.len(), report.failed.len());
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BulkRefund {
+    refunds: Vec<RefundCapturedPayment>,
+    concurrency: usize,
+    retry_policy: Option<RetryPolicy>,
+    idempotency_prefix: String,
+    checkpoint: RefundCheckpoint,
+}
+
+impl BulkRefund {
+    /// New constructor.
+    pub fn new(refunds: Vec<RefundCapturedPayment>) -> Self {
+        Self {
+            refunds,
+            concurrency: 4,
+            retry_policy: None,
+            idempotency_prefix: "bulk-refund-".to_string(),
+            checkpoint: RefundCheckpoint::default(),
+        }
+    }
+
+    /// Sets the maximum amount of refunds in flight at the same time. Defaults to 4.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Retries the refunds with the given policy instead of the one of the client.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the prefix of the `PayPal-Request-Id` sent with each refund, followed by its [RefundKey].
+    /// Defaults to `bulk-refund-`.
+    ///
+    /// Use a prefix of your own, like the id of an incident, so another batch refunding the same captures later
+    /// isn't answered with the refunds of this one. Use the same prefix when resuming an interrupted run, so the
+    /// refunds that were in flight are not performed twice.
+    pub fn idempotency_prefix(mut self, prefix: impl ToString) -> Self {
+        self.idempotency_prefix = prefix.to_string();
+        self
+    }
+
+    /// Skips the refunds already completed in the given checkpoint.
+    pub fn resume_from(mut self, checkpoint: RefundCheckpoint) -> Self {
+        self.checkpoint = checkpoint;
+        self
+    }
+
+    /// Executes the refunds.
+    pub async fn run(self, client: &Client) -> BulkRefundReport {
+        self.run_with(client, |_, _| {}).await
+    }
+
+    /// Executes the refunds, calling `on_result` as each of them completes, which can be used to persist progress.
+    pub async fn run_with<F>(mut self, client: &Client, mut on_result: F) -> BulkRefundReport
+    where
+        F: FnMut(&RefundKey, &Result<Refund, ResponseError>),
+    {
+        let retry_policy = self
+            .retry_policy
+            .take()
+            .or_else(|| client.retry_policy.clone())
+            .unwrap_or_default();
+        let client = client.clone().with_retry_policy(retry_policy);
+        let mut checkpoint = std::mem::take(&mut self.checkpoint);
+
+        let mut counts = HashMap::new();
+        let (skipped, pending): (Vec<_>, Vec<_>) = self
+            .refunds
+            .iter()
+            .map(|refund| {
+                let index = counts.entry(&refund.capture_id).or_insert(0);
+                let key = RefundKey::new(&refund.capture_id, *index);
+                *index += 1;
+                (key, refund)
+            })
+            .partition(|(key, _)| checkpoint.completed.contains(key));
+
+        let mut results = stream::iter(pending)
+            .map(|(key, refund)| {
+                let headers = HeaderParams {
+                    request_id: Some(format!("{}{}", self.idempotency_prefix, key)),
+                    ..Default::default()
+                };
+                let client = &client;
+                async move { (key, client.execute_ext(refund, headers).await) }
+            })
+            .buffer_unordered(self.concurrency);
+
+        let mut refunded = Vec::new();
+        let mut failed = Vec::new();
+
+        while let Some((key, result)) = results.next().await {
+            on_result(&key, &result);
+            match result {
+                Ok(refund) => {
+                    checkpoint.completed.insert(key);
+                    refunded.push(refund);
+                }
+                Err(e) => failed.push((key, e)),
+            }
+        }
+
+        BulkRefundReport {
+            refunded,
+            skipped: skipped.into_iter().map(|(key, _)| key).collect(),
+            failed,
+            checkpoint,
+        }
+    }
+}
+
+/// Executes many endpoints with bounded concurrency, returning their results in order.
+///
+/// Each endpoint gets its own result, so a failure doesn't stop the others. Retries and throttling are left to the
+/// [RetryPolicy](crate::retry::RetryPolicy) and [Throttle](crate::throttle::Throttle) of the client.
+///
+/// ```no_run
+/// # async fn run(client: paypal_rs::Client) {
+/// use paypal_rs::{api::payments::RefundCapturedPayment, bulk::Batch};
+///
+/// let refunds = ["2GG279541U471931P", "8MC585209K746392H"]
+///     .into_iter()
+///     .map(|capture_id| RefundCapturedPayment::new(capture_id, Default::default()));
+///
+/// let results = Batch::new(refunds).concurrency(8).run(&client).await;
+/// for result in results {
+///     println!("{:?}", result.map(|refund| refund.id));
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Batch<E> {
+    endpoints: Vec<E>,
+    concurrency: usize,
+}
+
+impl<E: Endpoint> Batch<E> {
+    /// New constructor.
+    pub fn new(endpoints: impl IntoIterator<Item = E>) -> Self {
+        Self {
+            endpoints: endpoints.into_iter().collect(),
+            concurrency: 4,
+        }
+    }
+
+    /// Sets the maximum amount of requests in flight at the same time. Defaults to 4.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Executes the endpoints, returning the result of each of them in the same order.
+    pub async fn run(self, client: &Client) -> Vec<Result<E::Response, ResponseError>> {
+        stream::iter(&self.endpoints)
+            .map(|endpoint| client.execute(endpoint))
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+}
@@ -0,0 +1,154 @@
+//! Failing fast while PayPal is having an outage, instead of piling up requests that will fail anyway.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Stops sending requests once too many of them fail, see [Client::with_circuit_breaker](crate::Client::with_circuit_breaker).
+///
+/// Requests are counted over a window of time. Once at least `min_requests` were sent in the window and the
+/// share of them that failed for transient reasons reaches `failure_rate`, the circuit opens: requests fail
+/// right away with [ResponseError::CircuitOpen](crate::errors::ResponseError::CircuitOpen) for the `cool_down` period.
+/// A single trial request is then let through, closing the circuit if it succeeds and reopening it otherwise.
+///
+/// ```
+/// use paypal_rs::circuit_breaker::CircuitBreaker;
+/// use std::time::Duration;
+///
+/// // Opens when half of at least 20 requests in 10 seconds fail, for 30 seconds.
+/// let breaker = CircuitBreaker::new(0.5, 20, Duration::from_secs(10), Duration::from_secs(30));
+/// ```
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    failure_rate: f64,
+    min_requests: u32,
+    window: Duration,
+    cool_down: Duration,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed {
+        started: Instant,
+        requests: u32,
+        failures: u32,
+    },
+    Open {
+        until: Instant,
+    },
+    /// A trial request is in flight, another one is let through if it hasn't completed by `until`.
+    HalfOpen {
+        until: Instant,
+    },
+}
+
+impl State {
+    fn closed() -> Self {
+        State::Closed {
+            started: Instant::now(),
+            requests: 0,
+            failures: 0,
+        }
+    }
+}
+
+impl CircuitBreaker {
+    /// Opens the circuit for `cool_down` once `failure_rate` (between 0 and 1) of at least `min_requests`
+    /// requests sent within `window` failed.
+    pub fn new(failure_rate: f64, min_requests: u32, window: Duration, cool_down: Duration) -> Self {
+        Self {
+            failure_rate,
+            min_requests: min_requests.max(1),
+            window,
+            cool_down,
+            state: Mutex::new(State::closed()),
+        }
+    }
+
+    /// Whether the circuit is open, that is requests currently fail right away.
+    pub fn is_open(&self) -> bool {
+        matches!(*self.lock(), State::Open { until } if Instant::now() < until)
+    }
+
+    /// Whether a request can be sent, letting a single trial request through once the cool down is over.
+    pub(crate) fn allow(&self) -> bool {
+        let mut state = self.lock();
+        match *state {
+            State::Closed { .. } => true,
+            State::Open { until } | State::HalfOpen { until } if Instant::now() >= until => {
+                *state = State::HalfOpen {
+                    until: Instant::now() + self.cool_down,
+                };
+                true
+            }
+            State::Open { .. } | State::HalfOpen { .. } => false,
+        }
+    }
+
+    /// Records the outcome of a request let through by [CircuitBreaker::allow].
+    pub(crate) fn record(&self, failed: bool) {
+        let mut state = self.lock();
+        match &mut *state {
+            State::Closed {
+                started,
+                requests,
+                failures,
+            } => {
+                if started.elapsed() >= self.window {
+                    *started = Instant::now();
+                    *requests = 0;
+                    *failures = 0;
+                }
+
+                *requests += 1;
+                *failures += u32::from(failed);
+                if *requests >= self.min_requests && *failures as f64 >= *requests as f64 * self.failure_rate {
+                    *state = self.open();
+                }
+            }
+            State::HalfOpen { .. } if failed => *state = self.open(),
+            State::HalfOpen { .. } => *state = State::closed(),
+            // A request sent before the circuit opened.
+            State::Open { .. } => {}
+        }
+    }
+
+    fn open(&self) -> State {
+        State::Open {
+            until: Instant::now() + self.cool_down,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().expect("circuit breaker lock poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_breaker() {
+        let breaker = CircuitBreaker::new(0.5, 4, Duration::from_secs(60), Duration::from_millis(50));
+
+        for failed in [false, true, false] {
+            assert!(breaker.allow());
+            breaker.record(failed);
+        }
+        assert!(!breaker.is_open());
+
+        assert!(breaker.allow());
+        breaker.record(true);
+        assert!(breaker.is_open());
+        assert!(!breaker.allow());
+
+        std::thread::sleep(Duration::from_millis(60));
+
+        // A single trial request goes through, and closes the circuit once it succeeds.
+        assert!(breaker.allow());
+        assert!(!breaker.allow());
+        breaker.record(false);
+        assert!(breaker.allow());
+    }
+}
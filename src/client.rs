@@ -1,34 +1,150 @@
 //! The paypal api wrapper client, which holds the http request client.
 
 use base64::Engine;
+use derive_builder::Builder;
+use futures::future::Either;
 use reqwest::header::{self, HeaderMap};
-use serde::Deserialize;
-use std::time::Duration;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_with::skip_serializing_none;
+use std::sync::{Arc, RwLock};
 use std::time::Instant;
+use std::time::{Duration, SystemTime};
 
 use crate::{
     AuthAssertionClaims, HeaderParams, LIVE_ENDPOINT, SANDBOX_ENDPOINT,
-    endpoint::Endpoint,
-    errors::{PaypalError, ResponseError},
+    circuit_breaker::CircuitBreaker,
+    config::ClientConfig,
+    data::common::LinkDescription,
+    descriptor::RequestDescriptor,
+    endpoint::{self, Endpoint},
+    errors::{ConfigError, ResponseError},
+    http_config::HttpConfig,
+    redact::{redact_header, redact_json},
+    response::ResponseWithMeta,
+    retry::RetryPolicy,
+    throttle::Throttle,
+    token_store::{StoredToken, TokenStore},
+    transport::Transport,
 };
 
 /// Represents the access token returned by the OAuth2 authentication.
 ///
 /// <https://developer.paypal.com/docs/api/get-an-access-token-postman/>
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AccessToken {
     /// The OAuth2 scopes.
+    #[serde(default)]
     pub scope: String,
     /// The access token.
     pub access_token: String,
     /// The token type.
     pub token_type: String,
     /// The app id.
+    #[serde(default)]
     pub app_id: String,
     /// Seconds until it expires.
     pub expires_in: u64,
     /// The nonce.
+    #[serde(default)]
     pub nonce: String,
+    /// The refresh token, returned when exchanging an authorization code.
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    /// The id token, returned when requested through [TokenOptions::response_types].
+    #[serde(default)]
+    pub id_token: Option<String>,
+}
+
+/// The OAuth2 grant used to obtain an access token.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GrantType {
+    /// The token belongs to the application itself.
+    #[default]
+    ClientCredentials,
+    /// The token was obtained by exchanging an authorization code, it acts on behalf of the user who consented.
+    AuthorizationCode,
+    /// The token was obtained by refreshing a previous user token.
+    RefreshToken,
+}
+
+/// The extra parameters of a client credentials token request.
+///
+/// ```
+/// use paypal_rs::TokenOptionsBuilder;
+///
+/// // A token acting on behalf of a merchant onboarded through partner referrals.
+/// let options = TokenOptionsBuilder::default().target_subject("2UBHZ6WZB2UQE").build().unwrap();
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct TokenOptions {
+    /// The payer id of the merchant on whose behalf the token acts.
+    pub target_subject: Option<String>,
+    /// The additional tokens to return along with the access token.
+    #[serde(
+        rename = "response_type",
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_response_types"
+    )]
+    pub response_types: Vec<TokenResponseType>,
+    /// The vault customer id the id token is generated for, to save payment methods during a purchase.
+    pub target_customer_id: Option<String>,
+}
+
+/// An additional token returned by the token endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenResponseType {
+    /// A client token.
+    ClientToken,
+    /// An id token, used to initialize the JS SDK when vaulting payment methods.
+    IdToken,
+}
+
+impl TokenResponseType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::ClientToken => "client_token",
+            Self::IdToken => "id_token",
+        }
+    }
+}
+
+fn serialize_response_types<S: serde::Serializer>(
+    response_types: &[TokenResponseType],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let response_types: Vec<_> = response_types.iter().map(TokenResponseType::as_str).collect();
+    serializer.serialize_str(&response_types.join(" "))
+}
+
+/// The form sent to the token endpoint.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+struct TokenRequest<'a> {
+    grant_type: GrantType,
+    code: Option<&'a str>,
+    refresh_token: Option<&'a str>,
+    #[serde(flatten)]
+    options: Option<&'a TokenOptions>,
+}
+
+/// The kind of token being revoked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TokenTypeHint {
+    /// An access token.
+    AccessToken,
+    /// A refresh token, revoking it also invalidates the access tokens obtained from it.
+    RefreshToken,
+}
+
+/// The form sent to the token termination endpoint.
+#[derive(Debug, Serialize)]
+struct TerminateTokenRequest<'a> {
+    token: &'a str,
+    token_type_hint: TokenTypeHint,
 }
 
 /// Stores OAuth2 information.
@@ -42,10 +158,60 @@ pub struct Auth {
     pub access_token: Option<AccessToken>,
     /// Used to check when the token expires.
     pub expires: Option<(Instant, Duration)>,
+    /// The grant used to obtain the current access token.
+    pub grant_type: GrantType,
+    /// The refresh token used to renew a user access token once it expires.
+    pub refresh_token: Option<String>,
+    /// The options of the current client credentials token, reused when it is renewed.
+    pub token_options: TokenOptions,
+    /// How long before it expires the access token is considered expired and renewed.
+    pub refresh_margin: Duration,
 }
 
-/// Represents a client used to interact with the paypal api.
+/// The default [Auth::refresh_margin].
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// The access token currently in use.
 #[derive(Debug, Clone)]
+pub(crate) struct CurrentToken {
+    access_token: AccessToken,
+    expires: (Instant, Duration),
+}
+
+impl CurrentToken {
+    fn new(access_token: AccessToken) -> Self {
+        let expires = (Instant::now(), Duration::new(access_token.expires_in, 0));
+        Self { access_token, expires }
+    }
+
+    fn from_stored(stored: StoredToken) -> Option<Self> {
+        let remaining = stored.remaining()?;
+        Some(Self {
+            access_token: stored.access_token,
+            expires: (Instant::now(), remaining),
+        })
+    }
+
+    fn to_stored(&self) -> StoredToken {
+        let (created, lifetime) = self.expires;
+        StoredToken {
+            access_token: self.access_token.clone(),
+            expires_at: SystemTime::now() + lifetime.saturating_sub(created.elapsed()),
+        }
+    }
+}
+
+/// The access token shared by a client and its clones, so a renewal by one of them is seen by all.
+#[derive(Debug, Default)]
+pub(crate) struct SharedToken {
+    /// The latest access token.
+    current: RwLock<Option<CurrentToken>>,
+    /// Held while the token is renewed, so concurrent requests wait for a single renewal.
+    refreshing: tokio::sync::Mutex<()>,
+}
+
+/// Represents a client used to interact with the paypal api.
+#[derive(Debug)]
 pub struct Client {
     /// Internal http client
     pub(crate) client: reqwest::Client,
@@ -53,6 +219,54 @@ pub struct Client {
     pub env: PaypalEnv,
     /// Api Auth information
     pub auth: Auth,
+    /// The latest access token, which may be newer than the one in [Client::auth] after an automatic refresh.
+    ///
+    /// Shared with the clones of this client, until one of them gets a token for another identity.
+    pub(crate) token: Arc<SharedToken>,
+    /// Sends the requests instead of the http client, if set.
+    pub transport: Option<Arc<dyn Transport>>,
+    /// Where the client credentials tokens are shared with other clients, if anywhere.
+    pub token_store: Option<Arc<dyn TokenStore>>,
+    /// How requests that failed for transient reasons are retried, they aren't by default.
+    pub retry_policy: Option<RetryPolicy>,
+    /// Limits the rate of requests, shared with the clones of this client.
+    pub throttle: Option<Arc<Throttle>>,
+    /// Stops sending requests while PayPal is failing, shared with the clones of this client.
+    pub circuit_breaker: Option<Arc<CircuitBreaker>>,
+    /// Whether requests and responses are logged at the debug level, with their secrets redacted.
+    pub debug_logging: bool,
+    /// The headers sent with every request, unless the endpoint or the caller set them.
+    pub default_headers: HeaderParams,
+    /// Other headers sent with every request, including the token requests, unless the client sets them itself.
+    pub extra_headers: HeaderMap,
+    /// Appended to the `User-Agent` header, which is `paypal-rs/<version>` otherwise.
+    pub user_agent_suffix: Option<String>,
+    /// The timeout of each attempt of a request, overriding the one of the http client.
+    pub request_timeout: Option<Duration>,
+    /// The time a request may take in total, including its retries.
+    pub deadline: Option<Duration>,
+}
+
+impl Clone for Client {
+    fn clone(&self) -> Self {
+        Client {
+            client: self.client.clone(),
+            env: self.env.clone(),
+            auth: self.auth.clone(),
+            token: self.token.clone(),
+            token_store: self.token_store.clone(),
+            transport: self.transport.clone(),
+            retry_policy: self.retry_policy.clone(),
+            throttle: self.throttle.clone(),
+            circuit_breaker: self.circuit_breaker.clone(),
+            debug_logging: self.debug_logging,
+            default_headers: self.default_headers.clone(),
+            extra_headers: self.extra_headers.clone(),
+            user_agent_suffix: self.user_agent_suffix.clone(),
+            request_timeout: self.request_timeout,
+            deadline: self.deadline,
+        }
+    }
 }
 
 /// The paypal api environment.
@@ -64,15 +278,40 @@ pub enum PaypalEnv {
     Sandbox,
     /// For mocking.
     Mock(String),
+    /// Any other base urls, for example a corporate gateway in front of PayPal.
+    Custom {
+        /// The base url of the api requests.
+        api_url: String,
+        /// The base url of the OAuth2 token requests.
+        oauth_url: String,
+    },
 }
 
 impl PaypalEnv {
+    /// An environment sending both the api and OAuth2 requests to the given base url.
+    pub fn custom(url: impl ToString) -> Self {
+        let url = url.to_string();
+        PaypalEnv::Custom {
+            api_url: url.clone(),
+            oauth_url: url,
+        }
+    }
+
     /// Returns the endpoint of this environment.
     pub fn endpoint(&self) -> &str {
         match &self {
             PaypalEnv::Live => LIVE_ENDPOINT,
             PaypalEnv::Sandbox => SANDBOX_ENDPOINT,
             PaypalEnv::Mock(endpoint) => endpoint.as_str(),
+            PaypalEnv::Custom { api_url, .. } => api_url.trim_end_matches('/'),
+        }
+    }
+
+    /// Returns the endpoint of the OAuth2 requests of this environment, which is the api one unless customized.
+    pub fn oauth_endpoint(&self) -> &str {
+        match &self {
+            PaypalEnv::Custom { oauth_url, .. } => oauth_url.trim_end_matches('/'),
+            _ => self.endpoint(),
         }
     }
 
@@ -81,6 +320,12 @@ impl PaypalEnv {
         assert!(target.starts_with('/'), "target path must start with '/'");
         format!("{}{}", self.endpoint(), target)
     }
+
+    /// Constructs an OAuth2 url from the target.
+    pub fn make_oauth_url(&self, target: &str) -> String {
+        assert!(target.starts_with('/'), "target path must start with '/'");
+        format!("{}{}", self.oauth_endpoint(), target)
+    }
 }
 
 impl Client {
@@ -114,10 +359,195 @@ impl Client {
                 secret,
                 access_token: None,
                 expires: None,
+                grant_type: GrantType::ClientCredentials,
+                refresh_token: None,
+                token_options: TokenOptions::default(),
+                refresh_margin: DEFAULT_REFRESH_MARGIN,
+            },
+            token: Arc::default(),
+            token_store: None,
+            transport: None,
+            retry_policy: None,
+            throttle: None,
+            circuit_breaker: None,
+            debug_logging: false,
+            default_headers: HeaderParams::default(),
+            extra_headers: HeaderMap::new(),
+            user_agent_suffix: None,
+            request_timeout: None,
+            deadline: None,
+        }
+    }
+
+    /// Returns a new client configured from the `PAYPAL_CLIENT_ID`, `PAYPAL_CLIENT_SECRET` and `PAYPAL_ENV`
+    /// environment variables, see [ClientConfig::from_env].
+    pub fn from_env() -> Result<Client, ConfigError> {
+        Ok(ClientConfig::from_env()?.into_client())
+    }
+
+    /// Returns a client with the same configuration but other credentials, which has yet to get an access token.
+    pub fn with_credentials(&self, client_id: String, secret: String) -> Client {
+        Client {
+            auth: Auth {
+                client_id,
+                secret,
+                access_token: None,
+                expires: None,
+                grant_type: GrantType::ClientCredentials,
+                refresh_token: None,
+                token_options: TokenOptions::default(),
+                refresh_margin: self.auth.refresh_margin,
             },
+            token: Arc::default(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a client for another environment, with the credentials of that environment.
+    ///
+    /// It shares the http client and the configuration of this client, so it is cheap to create,
+    /// for example to verify a live webhook from a tool otherwise using the sandbox.
+    ///
+    /// ```
+    /// use paypal_rs::{Client, PaypalEnv};
+    ///
+    /// let sandbox = Client::new("sandboxid".to_string(), "sandboxsecret".to_string(), PaypalEnv::Sandbox);
+    /// let live = sandbox.for_env(PaypalEnv::Live, "liveid".to_string(), "livesecret".to_string());
+    /// assert_eq!(live.env, PaypalEnv::Live);
+    /// ```
+    pub fn for_env(&self, env: PaypalEnv, client_id: String, secret: String) -> Client {
+        Client {
+            env,
+            ..self.with_credentials(client_id, secret)
+        }
+    }
+
+    /// Retries the requests that failed for transient reasons with the given policy.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Logs the requests and responses at the debug level, under the `paypal_rs` target.
+    ///
+    /// Tokens, secrets and card data are redacted, see [redact_json](crate::redact::redact_json).
+    pub fn with_debug_logging(mut self, debug_logging: bool) -> Self {
+        self.debug_logging = debug_logging;
+        self
+    }
+
+    /// Sends the given headers with every request, like the `PayPal-Partner-Attribution-Id` of a partner.
+    ///
+    /// The headers of the endpoints and the ones given to [Client::execute_ext] take precedence.
+    pub fn with_default_headers(mut self, default_headers: HeaderParams) -> Self {
+        self.default_headers = default_headers;
+        self
+    }
+
+    /// Sends the given headers with every request, like the ones a gateway routes requests with.
+    ///
+    /// The headers set by the client, like `Authorization` or `PayPal-Request-Id`, take precedence.
+    pub fn with_extra_headers(mut self, extra_headers: HeaderMap) -> Self {
+        self.extra_headers = extra_headers;
+        self
+    }
+
+    /// Appends the given suffix to the `User-Agent` header, like the name and version of your application.
+    pub fn with_user_agent_suffix(mut self, suffix: impl ToString) -> Self {
+        self.user_agent_suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// The `User-Agent` header sent with every request.
+    pub fn user_agent(&self) -> String {
+        let user_agent = concat!("paypal-rs/", env!("CARGO_PKG_VERSION"));
+        match &self.user_agent_suffix {
+            Some(suffix) => format!("{} {}", user_agent, suffix),
+            None => user_agent.to_string(),
         }
     }
 
+    /// Sets the timeout of each attempt of a request, see [Client::request_timeout].
+    ///
+    /// Clients are cheap to clone, so a clone can be used to give some calls, like the ones of a checkout, a shorter timeout.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the time a request may take in total, including its retries, see [Client::deadline].
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Limits the rate of requests made by this client and its clones.
+    pub fn with_throttle(mut self, throttle: Throttle) -> Self {
+        self.throttle = Some(Arc::new(throttle));
+        self
+    }
+
+    /// Fails requests right away while the given circuit breaker is open, for this client and its clones.
+    pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(Arc::new(circuit_breaker));
+        self
+    }
+
+    /// Uses the given http client instead of a default one, for example to go through a proxy or trust custom root certificates.
+    ///
+    /// ```
+    /// use paypal_rs::{Client, PaypalEnv};
+    /// use std::time::Duration;
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = Client::new("clientid".to_string(), "secret".to_string(), PaypalEnv::Sandbox)
+    ///     .with_http_client(http_client);
+    /// ```
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Uses a http client built with the given configuration, for example to trust only some root certificates.
+    ///
+    /// Fails if the http client can't be built, like when a certificate isn't supported by the tls backend.
+    pub fn with_http_config(self, config: &HttpConfig) -> Result<Self, reqwest::Error> {
+        Ok(self.with_http_client(config.build_client()?))
+    }
+
+    /// Shares the client credentials tokens through the given store, instead of each client requesting its own.
+    ///
+    /// Clones of a client share its store, tokens of user grants are never stored.
+    ///
+    /// ```
+    /// use paypal_rs::{Client, PaypalEnv, token_store::InMemoryTokenStore};
+    /// use std::sync::Arc;
+    ///
+    /// let store = Arc::new(InMemoryTokenStore::new());
+    /// let client = Client::new("clientid".to_string(), "secret".to_string(), PaypalEnv::Sandbox)
+    ///     .with_token_store(store);
+    /// ```
+    pub fn with_token_store(mut self, token_store: Arc<dyn TokenStore>) -> Self {
+        self.token_store = Some(token_store);
+        self
+    }
+
+    /// Sends the requests through the given transport instead of the http client, for example to mock PayPal in tests.
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sets how long before it expires the access token is renewed, see [Auth::refresh_margin].
+    pub fn with_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.auth.refresh_margin = refresh_margin;
+        self
+    }
+
     /// Sets up the request headers as required on https://developer.paypal.com/docs/api/reference/api-requests/#http-request-headers
     async fn setup_headers(
         &self,
@@ -128,7 +558,7 @@ impl Client {
 
         headers.append(header::ACCEPT, "application/json".parse().unwrap());
 
-        if let Some(token) = &self.auth.access_token {
+        if let Some(token) = self.current_token() {
             headers.append(
                 header::AUTHORIZATION,
                 format!("Bearer {}", token.access_token).parse().unwrap(),
@@ -163,7 +593,13 @@ impl Client {
             headers.append("PayPal-Request-Id", request_id.parse().unwrap());
         }
 
-        headers.append("Prefer", "return=representation".parse().unwrap());
+        let prefer = header_params.prefer.unwrap_or_default();
+        headers.append("Prefer", prefer.as_str().parse().unwrap());
+
+        if let Some(code) = header_params.mock_application_code {
+            let mock_response = serde_json::json!({ "mock_application_codes": code });
+            headers.append("PayPal-Mock-Response", mock_response.to_string().parse().unwrap());
+        }
 
         if let Some(content_type) = header_params.content_type {
             headers.append(header::CONTENT_TYPE, content_type.parse().unwrap());
@@ -173,84 +609,730 @@ impl Client {
     }
 
     /// Gets a access token used in all the api calls and saves it.
+    ///
+    /// If the current token is a user token with a refresh token, it is refreshed instead.
+    /// Tokens requested with [Client::get_access_token_with] are renewed with the same options.
     pub async fn get_access_token(&mut self) -> Result<(), ResponseError> {
         if !self.access_token_expired() {
             return Ok(());
         }
-        let res = self
+
+        if self.auth.grant_type != GrantType::ClientCredentials
+            && let Some(refresh_token) = self.auth.refresh_token.clone()
+        {
+            return self.refresh_access_token(&refresh_token).await;
+        }
+
+        let options = self.auth.token_options.clone();
+        self.request_token(TokenRequest {
+            options: Some(&options),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Gets a client credentials access token with the given options and saves it.
+    ///
+    /// The current token is kept only if it is still valid and was requested with the same options,
+    /// so a token minted for one merchant is never used for another.
+    pub async fn get_access_token_with(&mut self, options: TokenOptions) -> Result<(), ResponseError> {
+        if !self.access_token_expired()
+            && self.auth.grant_type == GrantType::ClientCredentials
+            && self.auth.token_options == options
+        {
+            return Ok(());
+        }
+
+        self.request_token(TokenRequest {
+            options: Some(&options),
+            ..Default::default()
+        })
+        .await?;
+        self.auth.token_options = options;
+        Ok(())
+    }
+
+    /// Returns the id token returned along with the current access token, if any.
+    pub fn id_token(&self) -> Option<String> {
+        self.current_token()?.id_token
+    }
+
+    /// Returns the payer id of the merchant the current token acts on behalf of, if any.
+    pub fn token_subject(&self) -> Option<&str> {
+        match self.auth.grant_type {
+            GrantType::ClientCredentials if self.auth.access_token.is_some() => {
+                self.auth.token_options.target_subject.as_deref()
+            }
+            _ => None,
+        }
+    }
+
+    /// Exchanges an authorization code, obtained when a user connects with PayPal, for an access token acting on their behalf.
+    ///
+    /// The returned refresh token is kept in [Auth::refresh_token], so it can be stored to renew the token later.
+    pub async fn exchange_authorization_code(&mut self, code: &str) -> Result<(), ResponseError> {
+        self.request_token(TokenRequest {
+            grant_type: GrantType::AuthorizationCode,
+            code: Some(code),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Obtains a new user access token from a refresh token, for example one stored after a previous session.
+    pub async fn refresh_access_token(&mut self, refresh_token: &str) -> Result<(), ResponseError> {
+        self.request_token(TokenRequest {
+            grant_type: GrantType::RefreshToken,
+            refresh_token: Some(refresh_token),
+            ..Default::default()
+        })
+        .await?;
+
+        // The refresh response doesn't include a new refresh token, the current one stays valid.
+        if self.auth.refresh_token.is_none() {
+            self.auth.refresh_token = Some(refresh_token.to_string());
+        }
+        Ok(())
+    }
+
+    async fn request_token(&mut self, request: TokenRequest<'_>) -> Result<(), ResponseError> {
+        let default_options = TokenOptions::default();
+
+        // A token for another merchant or user must not replace the one of the clones.
+        let same_identity = match request.grant_type {
+            GrantType::ClientCredentials => {
+                self.auth.grant_type == GrantType::ClientCredentials
+                    && request.options.unwrap_or(&default_options) == &self.auth.token_options
+            }
+            GrantType::RefreshToken => self.auth.grant_type != GrantType::ClientCredentials,
+            GrantType::AuthorizationCode => false,
+        };
+        let token = match request.grant_type {
+            GrantType::ClientCredentials => {
+                self.client_credentials_token(request.options.unwrap_or(&default_options))
+                    .await?
+            }
+            _ => CurrentToken::new(self.fetch_token(&request).await?),
+        };
+
+        self.auth.expires = Some(token.expires);
+        self.auth.grant_type = request.grant_type;
+        self.auth.refresh_token = match request.grant_type {
+            GrantType::ClientCredentials => None,
+            _ => token.access_token.refresh_token.clone(),
+        };
+        self.auth.access_token = Some(token.access_token.clone());
+        if !same_identity {
+            self.token = Arc::default();
+        }
+        self.store_token(Some(token));
+        Ok(())
+    }
+
+    /// Returns a client credentials token, taken from the [Client::token_store] when it holds a valid one.
+    async fn client_credentials_token(&self, options: &TokenOptions) -> Result<CurrentToken, ResponseError> {
+        let Some(store) = &self.token_store else {
+            let request = TokenRequest {
+                options: Some(options),
+                ..Default::default()
+            };
+            return Ok(CurrentToken::new(self.fetch_token(&request).await?));
+        };
+
+        let key = self.token_store_key(options);
+        if let Some(token) = store.load(&key).await.and_then(CurrentToken::from_stored)
+            && !self.expired(token.expires)
+        {
+            return Ok(token);
+        }
+
+        let request = TokenRequest {
+            options: Some(options),
+            ..Default::default()
+        };
+        let token = CurrentToken::new(self.fetch_token(&request).await?);
+        store.save(&key, token.to_stored()).await;
+        Ok(token)
+    }
+
+    /// The key of the tokens requested with the given options in the [Client::token_store].
+    fn token_store_key(&self, options: &TokenOptions) -> String {
+        let options = serde_qs::to_string(options).expect("serialize the token options correctly");
+        format!("{}|{}|{}", self.env.oauth_endpoint(), self.auth.client_id, options)
+    }
+
+    async fn send(&self, mut request: reqwest::Request) -> Result<reqwest::Response, reqwest::Error> {
+        let headers = request.headers_mut();
+        for (name, value) in &self.extra_headers {
+            if !headers.contains_key(name) {
+                headers.insert(name, value.clone());
+            }
+        }
+        if let Ok(user_agent) = self.user_agent().parse() {
+            headers.insert(header::USER_AGENT, user_agent);
+        }
+
+        match &self.transport {
+            Some(transport) => transport.execute(request).await,
+            None => self.client.execute(request).await,
+        }
+    }
+
+    async fn fetch_token(&self, request: &TokenRequest<'_>) -> Result<AccessToken, ResponseError> {
+        let body = serde_qs::to_string(request).expect("serialize the token request correctly");
+        let request = self
             .client
-            .post(self.env.make_url("/v1/oauth2/token"))
+            .post(self.env.make_oauth_url("/v1/oauth2/token"))
             .basic_auth(&self.auth.client_id, Some(&self.auth.secret))
             .header("Content-Type", "x-www-form-urlencoded")
             .header("Accept", "application/json")
-            .body("grant_type=client_credentials")
-            .send()
-            .await
-            .map_err(ResponseError::HttpError)?;
+            .body(body)
+            .build()?;
+        let res = self.send(request).await?;
+
+        if res.status().is_success() {
+            Ok(res.json::<AccessToken>().await.map_err(ResponseError::HttpError)?)
+        } else {
+            Err(ResponseError::from_response(res).await)
+        }
+    }
+
+    /// Revokes the given token, so it can no longer be used.
+    pub async fn revoke_token(&self, token: &str, token_type_hint: TokenTypeHint) -> Result<(), ResponseError> {
+        let body = serde_qs::to_string(&TerminateTokenRequest { token, token_type_hint })
+            .expect("serialize the terminate request correctly");
+        let request = self
+            .client
+            .post(self.env.make_oauth_url("/v1/oauth2/token/terminate"))
+            .basic_auth(&self.auth.client_id, Some(&self.auth.secret))
+            .header("Content-Type", "x-www-form-urlencoded")
+            .header("Accept", "application/json")
+            .body(body)
+            .build()?;
+        let res = self.send(request).await?;
 
         if res.status().is_success() {
-            let token = res.json::<AccessToken>().await.map_err(ResponseError::HttpError)?;
-            self.auth.expires = Some((Instant::now(), Duration::new(token.expires_in, 0)));
-            self.auth.access_token = Some(token);
             Ok(())
         } else {
-            Err(ResponseError::ApiError(
-                res.json::<PaypalError>().await.map_err(ResponseError::HttpError)?,
-            ))
+            Err(ResponseError::from_response(res).await)
+        }
+    }
+
+    /// Revokes the current refresh token, or the access token when there is none, and forgets them.
+    ///
+    /// Meant to be used when a user disconnects their PayPal account.
+    pub async fn revoke_current_token(&mut self) -> Result<(), ResponseError> {
+        if let Some(refresh_token) = &self.auth.refresh_token {
+            self.revoke_token(refresh_token, TokenTypeHint::RefreshToken).await?;
+        } else if let Some(token) = self.current_token() {
+            self.revoke_token(&token.access_token, TokenTypeHint::AccessToken)
+                .await?;
+        }
+
+        if self.auth.grant_type == GrantType::ClientCredentials
+            && let Some(store) = &self.token_store
+        {
+            store.remove(&self.token_store_key(&self.auth.token_options)).await;
         }
+
+        self.auth.access_token = None;
+        self.auth.expires = None;
+        self.store_token(None);
+        self.auth.refresh_token = None;
+        self.auth.grant_type = GrantType::ClientCredentials;
+        self.auth.token_options = TokenOptions::default();
+        Ok(())
     }
 
-    /// Checks if the access token expired.
+    /// Checks if the access token expired, or expires within the [Auth::refresh_margin].
     pub fn access_token_expired(&self) -> bool {
-        if let Some(expires) = self.auth.expires {
-            expires.0.elapsed() >= expires.1
-        } else {
-            true
+        let expires = match self.stored_token() {
+            Some(token) => Some(token.expires),
+            None => self.auth.expires,
+        };
+
+        expires.is_none_or(|expires| self.expired(expires))
+    }
+
+    fn expired(&self, (created, lifetime): (Instant, Duration)) -> bool {
+        created.elapsed() + self.auth.refresh_margin >= lifetime
+    }
+
+    fn stored_token(&self) -> Option<CurrentToken> {
+        self.token.current.read().expect("token lock poisoned").clone()
+    }
+
+    fn store_token(&self, token: Option<CurrentToken>) {
+        *self.token.current.write().expect("token lock poisoned") = token;
+    }
+
+    /// Returns the access token used by the requests, which may have been refreshed automatically.
+    pub fn current_token(&self) -> Option<AccessToken> {
+        match self.stored_token() {
+            Some(token) => Some(token.access_token),
+            None => self.auth.access_token.clone(),
         }
     }
 
+    /// Renews the access token before it expires, so requests never go out with a stale token.
+    ///
+    /// Nothing is done until a first token was obtained, or when an expired user token can't be refreshed.
+    /// Requests of the client and its clones finding the token expired at the same time wait for a single renewal.
+    async fn refresh_if_needed(&self) -> Result<(), ResponseError> {
+        if self.current_token().is_none() || !self.access_token_expired() {
+            return Ok(());
+        }
+
+        let _refreshing = self.token.refreshing.lock().await;
+        // Renewed by another request while waiting.
+        if !self.access_token_expired() {
+            return Ok(());
+        }
+        self.renew_token(false).await.map(|_| ())
+    }
+
+    /// Replaces the given token rejected by PayPal, unless another request already did.
+    async fn renew_rejected_token(&self, rejected: &AccessToken) -> Result<bool, ResponseError> {
+        let _refreshing = self.token.refreshing.lock().await;
+        if self.current_token().as_ref() != Some(rejected) {
+            return Ok(true);
+        }
+        self.renew_token(true).await
+    }
+
+    /// Gets a new access token, returning whether one could be obtained without the user.
+    ///
+    /// A client credentials token is requested again, a user token is refreshed with its refresh token.
+    /// When the current token was `rejected` by PayPal, the one in the [Client::token_store] isn't reused.
+    async fn renew_token(&self, rejected: bool) -> Result<bool, ResponseError> {
+        let token = match (self.auth.grant_type, self.auth.refresh_token.as_deref()) {
+            (GrantType::ClientCredentials, _) => {
+                if rejected && let Some(store) = &self.token_store {
+                    store.remove(&self.token_store_key(&self.auth.token_options)).await;
+                }
+                self.client_credentials_token(&self.auth.token_options).await?
+            }
+            (_, Some(refresh_token)) => {
+                let request = TokenRequest {
+                    grant_type: GrantType::RefreshToken,
+                    refresh_token: Some(refresh_token),
+                    ..Default::default()
+                };
+                CurrentToken::new(self.fetch_token(&request).await?)
+            }
+            _ => return Ok(false),
+        };
+
+        self.store_token(Some(token));
+        Ok(true)
+    }
+
     /// Executes the given endpoint with the given headers.
+    ///
+    /// The given headers take precedence over the ones of the endpoint, see [Endpoint::headers].
+    /// Transient failures are retried according to the [Client::retry_policy], if any.
+    /// A request rejected because the access token was revoked or expired early is retried once with a new token.
+    ///
+    /// With the `strict` feature, responses with fields this crate doesn't know fail with [ResponseError::ParseError].
     pub async fn execute_ext<E>(&self, endpoint: &E, headers: HeaderParams) -> Result<E::Response, ResponseError>
     where
         E: Endpoint,
     {
-        let mut url = self.env.make_url(&endpoint.relative_path());
+        self.execute_ext_with_meta(endpoint, headers)
+            .await
+            .map(|response| response.body)
+    }
+
+    /// Executes the given endpoint with the given headers, returning the response metadata along with the body.
+    ///
+    /// See [Client::execute_ext].
+    pub async fn execute_ext_with_meta<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> Result<ResponseWithMeta<E::Response>, ResponseError>
+    where
+        E: Endpoint,
+    {
+        let response = self.execute_raw_ext(endpoint, headers).await?;
+        decode_response(response, true)
+    }
+
+    /// Executes the given endpoint with the given headers, returning the status, headers and unparsed body.
+    ///
+    /// Useful to read fields the typed responses don't have yet, or to archive the exact payload.
+    /// Unsuccessful responses are still turned into errors.
+    pub async fn execute_raw_ext<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> Result<ResponseWithMeta<Vec<u8>>, ResponseError>
+    where
+        E: Endpoint,
+    {
+        let headers = headers.or(endpoint.headers()).or(self.default_headers.clone());
 
-        if let Some(query) = endpoint.query() {
-            let query_string = serde_qs::to_string(&query).expect("serialize the query correctly");
-            url.push_str(&query_string);
+        match self.deadline {
+            Some(deadline) => tokio::time::timeout(deadline, self.execute_with_retries(endpoint, headers))
+                .await
+                .unwrap_or(Err(ResponseError::DeadlineExceeded(deadline))),
+            None => self.execute_with_retries(endpoint, headers).await,
         }
+    }
+
+    /// Executes the given endpoint with the given headers, aborting it as soon as `cancel` completes.
+    ///
+    /// Meant for shutdowns: pass the future of your shutdown signal, or [tokio::time::sleep_until] for a deadline,
+    /// and the in-flight request is dropped right away with [ResponseError::Cancelled].
+    ///
+    /// A cancelled request may or may not have reached PayPal. Re-issuing it afterwards is only safe for
+    /// `GET`, `PUT` and `DELETE` requests, and for `POST` and `PATCH` requests sent with a request id,
+    /// see [Idempotent](crate::endpoint::Idempotent), which PayPal answers with the result of the first one.
+    ///
+    /// ```no_run
+    /// # async fn run(client: paypal_rs::Client, shutdown: impl Future<Output = ()>) {
+    /// use paypal_rs::{HeaderParams, api::orders::CaptureOrder, endpoint::Idempotent};
+    ///
+    /// let capture = Idempotent::new(CaptureOrder::new("5O190127TN364715T"), "capture-5O190127TN364715T");
+    /// let result = client
+    ///     .execute_until(&capture, HeaderParams::default(), shutdown)
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn execute_until<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<E::Response, ResponseError>
+    where
+        E: Endpoint,
+    {
+        let request = std::pin::pin!(self.execute_ext(endpoint, headers));
+        let cancel = std::pin::pin!(cancel);
+        match futures::future::select(request, cancel).await {
+            Either::Left((result, _)) => result,
+            Either::Right(((), _)) => Err(ResponseError::Cancelled),
+        }
+    }
+
+    async fn execute_with_retries<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> Result<ResponseWithMeta<Vec<u8>>, ResponseError>
+    where
+        E: Endpoint,
+    {
+        let Some(policy) = &self.retry_policy else {
+            return self.execute_once(endpoint, headers).await;
+        };
+
+        let method = endpoint.method();
+        let idempotent =
+            !(method == reqwest::Method::POST || method == reqwest::Method::PATCH) || headers.request_id.is_some();
+
+        let mut attempt = 1;
+        loop {
+            match self.execute_once(endpoint, headers.clone()).await {
+                Err(e) if policy.should_retry(&e, attempt, idempotent) => {
+                    tokio::time::sleep(policy.delay_after(&e, attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn execute_once<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> Result<ResponseWithMeta<Vec<u8>>, ResponseError>
+    where
+        E: Endpoint,
+    {
+        let Some(breaker) = &self.circuit_breaker else {
+            return self.send_authenticated(endpoint, headers).await;
+        };
+
+        if !breaker.allow() {
+            return Err(ResponseError::CircuitOpen);
+        }
+
+        let result = self.send_authenticated(endpoint, headers).await;
+        breaker.record(result.as_ref().is_err_and(ResponseError::is_transient));
+        result
+    }
+
+    /// Sends the endpoint, retrying it once with a new token if the current one was rejected.
+    ///
+    /// PayPal rejects the request before processing it, so it's safe to send again whatever its method.
+    async fn send_authenticated<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> Result<ResponseWithMeta<Vec<u8>>, ResponseError>
+    where
+        E: Endpoint,
+    {
+        self.refresh_if_needed().await?;
+        let token = self.current_token();
+
+        let result = self.send_endpoint(endpoint, headers.clone()).await;
+        if let Err(e) = &result
+            && e.is_unauthorized()
+            && let Some(token) = &token
+            && self.renew_rejected_token(token).await?
+        {
+            return self.send_endpoint(endpoint, headers).await;
+        }
+        result
+    }
+
+    async fn send_endpoint<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> Result<ResponseWithMeta<Vec<u8>>, ResponseError>
+    where
+        E: Endpoint,
+    {
+        if let Some(throttle) = &self.throttle {
+            throttle.acquire().await;
+        }
+
+        let url = self.env.make_url(&endpoint::path_and_query(endpoint));
 
         let mut request = self.client.request(endpoint.method(), url);
         request = self.setup_headers(request, headers).await?;
+        request = request.headers(endpoint.extra_headers());
 
-        if let Some(body) = endpoint.body() {
+        if let Some(form) = endpoint.multipart() {
+            request = request.multipart(form);
+        } else if let Some(body) = endpoint.body() {
             request = request.json(&body);
         }
 
-        let res = request.send().await?;
+        if let Some(timeout) = self.request_timeout {
+            request = request.timeout(timeout);
+        }
 
-        if res.status().is_success() {
-            // code to debug responses when parse fails.
-            //let resp_text = res.text().await?;
-            //dbg!(&resp_text);
-            //let mut f = std::fs::File::create("output.txt").unwrap();
-            //f.write_all(resp_text.as_bytes()).ok();
-            //let response_body: E::Response = serde_json::from_str(&resp_text).unwrap();
-            let response_body = res.json::<E::Response>().await?;
-            Ok(response_body)
+        let request = request.build()?;
+        if self.debug_logging {
+            log_request(&request);
+        }
+
+        let res = self.send(request).await?;
+        let status = res.status();
+        let headers = res.headers().clone();
+        let status_error = res.error_for_status_ref().err();
+        let body = res.bytes().await?;
+
+        if self.debug_logging {
+            log_response(status, &body);
+        }
+
+        if status.is_success() {
+            Ok(ResponseWithMeta::new(status, headers, body.to_vec()))
         } else {
-            Err(ResponseError::ApiError(res.json::<PaypalError>().await?))
+            Err(ResponseError::from_body(status, status_error, &headers, &body))
         }
     }
 
     /// Executes the given endpoints with the default headers.
     ///
-    /// You must remember to call [Client::get_access_token] first or this may fail due to not being authed.
+    /// You must remember to call [Client::get_access_token] first or this may fail due to not being authed,
+    /// afterwards the token is renewed automatically shortly before it expires.
     pub async fn execute<E>(&self, endpoint: &E) -> Result<E::Response, ResponseError>
     where
         E: Endpoint,
     {
         self.execute_ext(endpoint, HeaderParams::default()).await
     }
+
+    /// Executes the given endpoint with the default headers, returning the response metadata along with the body.
+    ///
+    /// Useful to log the `Paypal-Debug-Id` of successful requests, which PayPal support asks for.
+    pub async fn execute_with_meta<E>(&self, endpoint: &E) -> Result<ResponseWithMeta<E::Response>, ResponseError>
+    where
+        E: Endpoint,
+    {
+        self.execute_ext_with_meta(endpoint, HeaderParams::default()).await
+    }
+
+    /// Executes the given endpoint with the default headers, returning the status, headers and unparsed body.
+    ///
+    /// See [Client::execute_raw_ext].
+    pub async fn execute_raw<E>(&self, endpoint: &E) -> Result<ResponseWithMeta<Vec<u8>>, ResponseError>
+    where
+        E: Endpoint,
+    {
+        self.execute_raw_ext(endpoint, HeaderParams::default()).await
+    }
+
+    /// Executes the given endpoint with the given headers, deserializing the response into `T`
+    /// instead of the [Endpoint::Response].
+    ///
+    /// Useful to read fields PayPal added since the release of this crate, or to deserialize only the fields you need.
+    /// Unknown fields are ignored, even with the `strict` feature.
+    ///
+    /// ```no_run
+    /// # async fn run(client: paypal_rs::Client) -> Result<(), paypal_rs::errors::ResponseError> {
+    /// use paypal_rs::{HeaderParams, api::orders::ShowOrderDetails};
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct OrderStatus {
+    ///     id: String,
+    ///     status: String,
+    /// }
+    ///
+    /// let order: OrderStatus = client
+    ///     .execute_ext_as(&ShowOrderDetails::new("5O190127TN364715T"), HeaderParams::default())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_ext_as<T, E>(&self, endpoint: &E, headers: HeaderParams) -> Result<T, ResponseError>
+    where
+        T: DeserializeOwned,
+        E: Endpoint,
+    {
+        let response = self.execute_raw_ext(endpoint, headers).await?;
+        decode_response(response, false).map(|response| response.body)
+    }
+
+    /// Executes the given endpoint with the default headers, deserializing the response into `T`.
+    ///
+    /// See [Client::execute_ext_as].
+    pub async fn execute_as<T, E>(&self, endpoint: &E) -> Result<T, ResponseError>
+    where
+        T: DeserializeOwned,
+        E: Endpoint,
+    {
+        self.execute_ext_as(endpoint, HeaderParams::default()).await
+    }
+
+    /// Executes the request described by a HATEOAS link of a response, deserializing the response into `T`.
+    ///
+    /// The link method is used, GET if it has none, and the body is sent as json if given.
+    /// Only the path of the link is used, so the request goes to the environment of the client along with its token:
+    /// links meant for the payer, like `approve`, can't be followed.
+    ///
+    /// ```no_run
+    /// # async fn run(client: paypal_rs::Client) -> Result<(), paypal_rs::errors::ResponseError> {
+    /// use paypal_rs::{api::orders::ShowOrderDetails, data::common::LinkRel, data::orders::Order};
+    ///
+    /// let order = client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    /// if let Some(capture) = order.links.iter().find(|link| link.rel == Some(LinkRel::Capture)) {
+    ///     let order: Order = client.follow(capture, Some(serde_json::json!({}))).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn follow<T>(&self, link: &LinkDescription, body: Option<serde_json::Value>) -> Result<T, ResponseError>
+    where
+        T: DeserializeOwned,
+    {
+        let path = link
+            .path()
+            .ok_or_else(|| ResponseError::InvalidLink(link.href.clone()))?;
+        let descriptor = RequestDescriptor {
+            operation: "FollowLink".to_string(),
+            method: link.method.map_or(reqwest::Method::GET, Into::into),
+            path,
+            body,
+            request_id: None,
+            prefer: None,
+            headers: HeaderMap::new(),
+        };
+        self.execute_as(&descriptor).await
+    }
+
+    /// Executes a previously stored request descriptor, sending its request id if it has one.
+    pub async fn execute_descriptor(&self, descriptor: &RequestDescriptor) -> Result<serde_json::Value, ResponseError> {
+        self.execute(descriptor).await
+    }
+}
+
+/// Deserializes the body of a successful response, failing on unknown fields if `strict` with the `strict` feature.
+#[allow(clippy::result_large_err)]
+fn decode_response<T: DeserializeOwned>(
+    response: ResponseWithMeta<Vec<u8>>,
+    strict: bool,
+) -> Result<ResponseWithMeta<T>, ResponseError> {
+    // A 204, or a 201 without `Prefer: return=representation`, has no body,
+    // which deserializes into the responses allowing it, like `()` and `Option<T>`.
+    let body = if response.body.iter().all(u8::is_ascii_whitespace) {
+        serde_json::from_value::<T>(serde_json::Value::Null)?
+    } else if strict {
+        from_slice_strict::<T>(&response.body)?
+    } else {
+        serde_json::from_slice::<T>(&response.body)?
+    };
+    Ok(response.map(|_| body))
+}
+
+/// Deserializes the body, failing with the path of every field `T` doesn't have,
+/// so contract tests notice when PayPal adds or renames fields.
+#[cfg(feature = "strict")]
+fn from_slice_strict<T: DeserializeOwned>(body: &[u8]) -> Result<T, serde_json::Error> {
+    let mut unknown = Vec::new();
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let value = serde_ignored::deserialize(&mut deserializer, |path| unknown.push(path.to_string()))?;
+    deserializer.end()?;
+
+    if unknown.is_empty() {
+        Ok(value)
+    } else {
+        Err(serde::de::Error::custom(format!(
+            "unknown fields: {}",
+            unknown.join(", ")
+        )))
+    }
+}
+
+#[cfg(not(feature = "strict"))]
+fn from_slice_strict<T: DeserializeOwned>(body: &[u8]) -> Result<T, serde_json::Error> {
+    serde_json::from_slice(body)
+}
+
+fn log_request(request: &reqwest::Request) {
+    let headers: Vec<_> = request
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            let value = value.to_str().unwrap_or_default();
+            format!("{}: {}", name, redact_header(name.as_str(), value))
+        })
+        .collect();
+    let body = request.body().and_then(reqwest::Body::as_bytes).map(redacted_body);
+
+    log::debug!(
+        target: "paypal_rs",
+        "request {} {} headers=[{}] body={}",
+        request.method(),
+        request.url(),
+        headers.join(", "),
+        body.as_deref().unwrap_or("<none>")
+    );
+}
+
+fn log_response(status: reqwest::StatusCode, body: &[u8]) {
+    log::debug!(target: "paypal_rs", "response {} body={}", status, redacted_body(body));
+}
+
+/// Returns the body with its secrets redacted, or a placeholder when it isn't json.
+fn redacted_body(body: &[u8]) -> String {
+    if body.is_empty() {
+        return "<empty>".to_string();
+    }
+
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            redact_json(&mut value);
+            value.to_string()
+        }
+        Err(_) => format!("<{} bytes>", body.len()),
+    }
 }
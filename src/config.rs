@@ -0,0 +1,141 @@
+//! Configuring a client from the environment, so every service reads the same variables.
+
+use crate::{Client, HeaderParams, client::PaypalEnv, errors::ConfigError};
+
+/// The variable holding the client id.
+pub const CLIENT_ID_VAR: &str = "PAYPAL_CLIENT_ID";
+/// The variable holding the secret.
+pub const CLIENT_SECRET_VAR: &str = "PAYPAL_CLIENT_SECRET";
+/// The variable holding the environment: `sandbox`, `live`, or the base url of a custom one.
+pub const ENV_VAR: &str = "PAYPAL_ENV";
+/// The variable holding the optional partner BN code, sent as `PayPal-Partner-Attribution-Id`.
+pub const BN_CODE_VAR: &str = "PAYPAL_BN_CODE";
+/// The variable holding the optional id of the webhook whose events are received.
+pub const WEBHOOK_ID_VAR: &str = "PAYPAL_WEBHOOK_ID";
+
+/// The validated configuration of a client.
+///
+/// ```no_run
+/// use paypal_rs::config::ClientConfig;
+///
+/// let config = ClientConfig::from_env().unwrap();
+/// let webhook_id = config.webhook_id.clone();
+/// let client = config.into_client();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientConfig {
+    /// The client id.
+    pub client_id: String,
+    /// The secret.
+    pub secret: String,
+    /// The environment.
+    pub env: PaypalEnv,
+    /// The partner BN code, sent with every request.
+    pub bn_code: Option<String>,
+    /// The id of the webhook whose events are received, used to verify them.
+    pub webhook_id: Option<String>,
+}
+
+impl ClientConfig {
+    /// Reads the configuration from the environment variables.
+    ///
+    /// `PAYPAL_CLIENT_ID`, `PAYPAL_CLIENT_SECRET` and `PAYPAL_ENV` are required,
+    /// `PAYPAL_BN_CODE` and `PAYPAL_WEBHOOK_ID` are optional.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Reads the configuration from the variables returned by the given function, for example from a config file.
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, ConfigError> {
+        let var = |name| {
+            lookup(name)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let required = |name| var(name).ok_or(ConfigError::Missing(name));
+
+        let env = required(ENV_VAR)?;
+        let env = match env.to_ascii_lowercase().as_str() {
+            "sandbox" => PaypalEnv::Sandbox,
+            "live" => PaypalEnv::Live,
+            _ if env.starts_with("https://") || env.starts_with("http://") => PaypalEnv::custom(env),
+            _ => {
+                return Err(ConfigError::Invalid {
+                    name: ENV_VAR,
+                    reason: format!("expected sandbox, live or an url, got {}", env),
+                });
+            }
+        };
+
+        let client_id = required(CLIENT_ID_VAR)?;
+        if client_id.contains(char::is_whitespace) {
+            return Err(ConfigError::Invalid {
+                name: CLIENT_ID_VAR,
+                reason: "contains whitespace".to_string(),
+            });
+        }
+
+        Ok(Self {
+            client_id,
+            secret: required(CLIENT_SECRET_VAR)?,
+            env,
+            bn_code: var(BN_CODE_VAR),
+            webhook_id: var(WEBHOOK_ID_VAR),
+        })
+    }
+
+    /// Creates a client with this configuration, you must get_access_token afterwards to interact with the api.
+    pub fn into_client(self) -> Client {
+        Client::new(self.client_id, self.secret, self.env).with_default_headers(HeaderParams {
+            partner_attribution_id: self.bn_code,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn config(vars: &[(&str, &str)]) -> Result<ClientConfig, ConfigError> {
+        let vars: HashMap<_, _> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        ClientConfig::from_lookup(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_from_lookup() {
+        let config = config(&[
+            (CLIENT_ID_VAR, "clientid"),
+            (CLIENT_SECRET_VAR, "secret"),
+            (ENV_VAR, "Sandbox"),
+            (BN_CODE_VAR, "PARTNER_BN"),
+        ])
+        .unwrap();
+        assert_eq!(config.env, PaypalEnv::Sandbox);
+        assert_eq!(config.bn_code.as_deref(), Some("PARTNER_BN"));
+        assert_eq!(config.webhook_id, None);
+
+        let client = config.into_client();
+        assert_eq!(
+            client.default_headers.partner_attribution_id.as_deref(),
+            Some("PARTNER_BN")
+        );
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert_eq!(
+            config(&[(CLIENT_ID_VAR, "clientid"), (ENV_VAR, "live")]),
+            Err(ConfigError::Missing(CLIENT_SECRET_VAR))
+        );
+        assert!(matches!(
+            config(&[
+                (CLIENT_ID_VAR, "clientid"),
+                (CLIENT_SECRET_VAR, "secret"),
+                (ENV_VAR, "production")
+            ]),
+            Err(ConfigError::Invalid { name: ENV_VAR, .. })
+        ));
+    }
+}
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 /// IS0-3166-1 country codes
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum Country {
     /// ALBANIA
     AL,
@@ -390,6 +390,7 @@ pub enum Country {
     /// UNITED KINGDOM
     GB,
     /// UNITED STATES
+    #[default]
     US,
     /// URUGUAY
     UY,
@@ -411,12 +412,6 @@ pub enum Country {
     ZW,
 }
 
-impl Default for Country {
-    fn default() -> Self {
-        Self::US
-    }
-}
-
 impl std::fmt::Display for Country {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Debug::fmt(&self, f)
@@ -633,3 +628,11 @@ impl FromStr for Country {
         }
     }
 }
+
+impl TryFrom<&str> for Country {
+    type Error = InvalidCountryError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
@@ -1,6 +1,9 @@
 //! Common paypal object definitions used by 2 or more APIs
 
-use crate::errors::InvalidCurrencyError;
+use crate::countries::Country;
+use crate::errors::{
+    InvalidAddressError, InvalidCurrencyError, InvalidMoneyError, InvalidReferenceIdError, InvalidUpcError,
+};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -57,11 +60,217 @@ pub struct Address {
     /// The postal code, which is the zip code or equivalent. Typically required for countries with a postal code or an equivalent.
     pub postal_code: Option<String>,
     /// The two-character [ISO 3166-1](https://developer.paypal.com/docs/api/reference/country-codes/) code that identifies the country or region.
-    pub country_code: Option<String>,
+    pub country_code: Option<Country>,
     /// The non-portable additional address details that are sometimes needed for compliance, risk, or other scenarios where fine-grain address information might be needed.
     pub address_details: Option<AddressDetails>,
 }
 
+/// How strictly [AddressBuilder::build_checked] validates an address.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum AddressValidation {
+    /// Rejects any component that doesn't follow the conventions of the country.
+    #[default]
+    Strict,
+    /// Fixes what can be fixed, like the case of the postal code or the length of the lines,
+    /// and only rejects the missing components.
+    Lenient,
+}
+
+/// The components an address requires in a country, and the formats of its postal codes.
+struct CountryRules {
+    country: Country,
+    /// Whether the state or province is required, as a code of 2 or 3 letters.
+    state_code: bool,
+    /// The formats of the postal codes, `9` standing for a digit and `A` for a letter.
+    postal_codes: &'static [&'static str],
+}
+
+const COUNTRY_RULES: &[CountryRules] = &[
+    CountryRules {
+        country: Country::US,
+        state_code: true,
+        postal_codes: &["99999", "99999-9999"],
+    },
+    CountryRules {
+        country: Country::CA,
+        state_code: true,
+        postal_codes: &["A9A 9A9", "A9A9A9"],
+    },
+    CountryRules {
+        country: Country::AU,
+        state_code: true,
+        postal_codes: &["9999"],
+    },
+    CountryRules {
+        country: Country::BR,
+        state_code: true,
+        postal_codes: &["99999-999", "99999999"],
+    },
+    CountryRules {
+        country: Country::GB,
+        state_code: false,
+        postal_codes: &[
+            "A9 9AA", "A99 9AA", "AA9 9AA", "AA99 9AA", "A9A 9AA", "AA9A 9AA", "A99AA", "A999AA", "AA99AA", "AA999AA",
+            "A9A9AA", "AA9A9AA",
+        ],
+    },
+    CountryRules {
+        country: Country::DE,
+        state_code: false,
+        postal_codes: &["99999"],
+    },
+    CountryRules {
+        country: Country::FR,
+        state_code: false,
+        postal_codes: &["99999"],
+    },
+    CountryRules {
+        country: Country::ES,
+        state_code: false,
+        postal_codes: &["99999"],
+    },
+    CountryRules {
+        country: Country::IT,
+        state_code: false,
+        postal_codes: &["99999"],
+    },
+    CountryRules {
+        country: Country::MX,
+        state_code: false,
+        postal_codes: &["99999"],
+    },
+    CountryRules {
+        country: Country::NL,
+        state_code: false,
+        postal_codes: &["9999 AA", "9999AA"],
+    },
+    CountryRules {
+        country: Country::IN,
+        state_code: false,
+        postal_codes: &["999999"],
+    },
+    CountryRules {
+        country: Country::C2,
+        state_code: false,
+        postal_codes: &["999999"],
+    },
+    CountryRules {
+        country: Country::JP,
+        state_code: false,
+        postal_codes: &["999-9999", "9999999"],
+    },
+];
+
+fn matches_format(value: &str, format: &str) -> bool {
+    value.len() == format.len()
+        && value.bytes().zip(format.bytes()).all(|(v, f)| match f {
+            b'9' => v.is_ascii_digit(),
+            b'A' => v.is_ascii_uppercase(),
+            f => v == f,
+        })
+}
+
+impl AddressBuilder {
+    /// Builds the address, checking that it has the components its country requires in the expected formats.
+    ///
+    /// The state (`admin_area_1`) must be a code like `CA` in the United States, Canada, Australia and Brazil,
+    /// and the postal code must have the format of the country in the most common markets.
+    /// Addresses in other countries are only checked for the length of their components.
+    ///
+    /// ```
+    /// use paypal_rs::countries::Country;
+    /// use paypal_rs::data::common::{AddressBuilder, AddressValidation};
+    ///
+    /// let address = AddressBuilder::default()
+    ///     .address_line_1("10 Downing Street")
+    ///     .admin_area_2("London")
+    ///     .postal_code("sw1a 2aa")
+    ///     .country_code(Country::GB)
+    ///     .build_checked(AddressValidation::Lenient)
+    ///     .unwrap();
+    /// assert_eq!(address.postal_code.as_deref(), Some("SW1A 2AA"));
+    /// ```
+    pub fn build_checked(&self, validation: AddressValidation) -> Result<Address, InvalidAddressError> {
+        let mut address = self.build().expect("all the address fields have a default");
+        let lenient = validation == AddressValidation::Lenient;
+
+        // The maximum lengths accepted by PayPal.
+        let fields = [
+            ("address_line_1", &mut address.address_line_1, 300),
+            ("address_line_2", &mut address.address_line_2, 300),
+            ("admin_area_2", &mut address.admin_area_2, 120),
+            ("admin_area_1", &mut address.admin_area_1, 300),
+            ("postal_code", &mut address.postal_code, 60),
+        ];
+        for (field, value, max_len) in fields {
+            let Some(text) = value else { continue };
+            let mut trimmed = text.trim().to_string();
+            if lenient && matches!(field, "admin_area_1" | "postal_code") {
+                trimmed = trimmed.to_uppercase();
+            }
+            if trimmed.chars().count() > max_len {
+                if !lenient {
+                    return Err(InvalidAddressError::Invalid {
+                        field,
+                        reason: format!("longer than {} characters", max_len),
+                    });
+                }
+                trimmed = trimmed.chars().take(max_len).collect();
+            }
+            *value = (!trimmed.is_empty()).then_some(trimmed);
+        }
+
+        let country = address
+            .country_code
+            .ok_or(InvalidAddressError::Missing("country_code"))?;
+        let Some(rules) = COUNTRY_RULES.iter().find(|rules| rules.country == country) else {
+            return Ok(address);
+        };
+
+        if address.address_line_1.is_none() {
+            return Err(InvalidAddressError::Missing("address_line_1"));
+        }
+        if address.admin_area_2.is_none() {
+            return Err(InvalidAddressError::Missing("admin_area_2"));
+        }
+
+        if rules.state_code {
+            let state = address
+                .admin_area_1
+                .as_deref()
+                .ok_or(InvalidAddressError::Missing("admin_area_1"))?;
+            let is_code = (2..=3).contains(&state.len()) && state.bytes().all(|b| b.is_ascii_uppercase());
+            if !lenient && !is_code {
+                return Err(InvalidAddressError::Invalid {
+                    field: "admin_area_1",
+                    reason: format!("expected a state code like CA, got {:?}", state),
+                });
+            }
+        }
+
+        let postal_code = address
+            .postal_code
+            .as_deref()
+            .ok_or(InvalidAddressError::Missing("postal_code"))?;
+        if !lenient
+            && !rules
+                .postal_codes
+                .iter()
+                .any(|format| matches_format(postal_code, format))
+        {
+            return Err(InvalidAddressError::Invalid {
+                field: "postal_code",
+                reason: format!(
+                    "expected a postal code like {}, got {:?}",
+                    rules.postal_codes[0], postal_code
+                ),
+            });
+        }
+
+        Ok(address)
+    }
+}
+
 /// Represents money
 #[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone, Builder)]
 #[builder(setter(strip_option, into))]
@@ -76,25 +285,46 @@ pub struct Money {
     pub value: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
-#[allow(missing_docs)]
+/// The type of a Universal Product Code.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
 pub enum UpcType {
+    /// The 12 digits code used on most products.
     #[serde(rename = "UPC-A")]
     UpcA,
+    /// The 12 digits code used by the US pharmaceutical industry.
     #[serde(rename = "UPC-B")]
     UpcB,
+    /// The 12 digits code combining a UPC-A with a product variant.
     #[serde(rename = "UPC-C")]
     UpcC,
+    /// The code of variable length, with at least 12 digits.
     #[serde(rename = "UPC-D")]
     UpcD,
+    /// The 8 digits code of small packages, a compressed UPC-A.
     #[serde(rename = "UPC-E")]
     UpcE,
+    /// A UPC-A followed by a 2 digits supplement, like the issue of a magazine.
     #[serde(rename = "UPC-2")]
     Upc2,
+    /// A UPC-A followed by a 5 digits supplement, like the price of a book.
     #[serde(rename = "UPC-5")]
     Upc5,
 }
 
+impl std::fmt::Display for UpcType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            UpcType::UpcA => "UPC-A",
+            UpcType::UpcB => "UPC-B",
+            UpcType::UpcC => "UPC-C",
+            UpcType::UpcD => "UPC-D",
+            UpcType::UpcE => "UPC-E",
+            UpcType::Upc2 => "UPC-2",
+            UpcType::Upc5 => "UPC-5",
+        })
+    }
+}
+
 /// Represents an item UPC code.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Builder)]
 #[builder(setter(strip_option, into))]
@@ -106,6 +336,78 @@ pub struct ItemUpc {
     pub value: String,
 }
 
+impl ItemUpc {
+    /// Creates a code of the given type, checking its length and, for the UPC-A based types and UPC-E, its check digit.
+    ///
+    /// PayPal silently drops the codes it doesn't accept.
+    ///
+    /// ```
+    /// use paypal_rs::data::common::{ItemUpc, UpcType};
+    ///
+    /// assert!(ItemUpc::upc_a("036000291452").is_ok());
+    /// assert!(ItemUpc::upc_a("036000291453").is_err());
+    /// assert!(ItemUpc::new(UpcType::Upc5, "03600029145252495").is_ok());
+    /// ```
+    pub fn new(upc_type: UpcType, value: impl ToString) -> Result<Self, InvalidUpcError> {
+        let value = value.to_string();
+        let digits: Option<Vec<u8>> = value.bytes().map(|b| b.is_ascii_digit().then(|| b - b'0')).collect();
+
+        let valid = digits.is_some_and(|digits| match upc_type {
+            UpcType::UpcA => digits.len() == 12 && has_valid_check_digit(&digits),
+            UpcType::UpcB | UpcType::UpcC => digits.len() == 12,
+            UpcType::UpcD => (12..=17).contains(&digits.len()),
+            UpcType::UpcE => {
+                digits.len() == 8 && expand_upc_e(&digits).is_some_and(|upc_a| has_valid_check_digit(&upc_a))
+            }
+            UpcType::Upc2 => digits.len() == 14 && has_valid_check_digit(&digits[..12]),
+            UpcType::Upc5 => digits.len() == 17 && has_valid_check_digit(&digits[..12]),
+        });
+
+        if valid {
+            Ok(Self { upc_type, value })
+        } else {
+            Err(InvalidUpcError { upc_type, value })
+        }
+    }
+
+    /// Creates a UPC-A code, see [ItemUpc::new].
+    pub fn upc_a(value: impl ToString) -> Result<Self, InvalidUpcError> {
+        Self::new(UpcType::UpcA, value)
+    }
+
+    /// Creates a UPC-E code, see [ItemUpc::new].
+    pub fn upc_e(value: impl ToString) -> Result<Self, InvalidUpcError> {
+        Self::new(UpcType::UpcE, value)
+    }
+}
+
+/// Whether the last digit is the check digit of the others, as computed for UPC-A.
+fn has_valid_check_digit(digits: &[u8]) -> bool {
+    let Some((check, digits)) = digits.split_last() else {
+        return false;
+    };
+    let sum: u32 = digits
+        .iter()
+        .enumerate()
+        .map(|(i, digit)| u32::from(*digit) * if i % 2 == 0 { 3 } else { 1 })
+        .sum();
+    (10 - sum % 10) % 10 == u32::from(*check)
+}
+
+/// Expands a UPC-E code to the equivalent UPC-A code.
+fn expand_upc_e(digits: &[u8]) -> Option<Vec<u8>> {
+    let [system @ (0 | 1), d1, d2, d3, d4, d5, d6, check] = *digits else {
+        return None;
+    };
+    let body = match d6 {
+        0..=2 => [d1, d2, d6, 0, 0, 0, 0, d3, d4, d5],
+        3 => [d1, d2, d3, 0, 0, 0, 0, 0, d4, d5],
+        4 => [d1, d2, d3, d4, 0, 0, 0, 0, 0, d5],
+        _ => [d1, d2, d3, d4, d5, 0, 0, 0, 0, d6],
+    };
+    Some([&[system][..], &body, &[check]].concat())
+}
+
 macro_rules! impl_money {
     ($name:ident, $type:expr) => {
         #[doc=concat!("Creates a instance of Money with the currency ", stringify!($type))]
@@ -125,6 +427,120 @@ impl Money {
     impl_money!(cny, Currency::CNY);
     impl_money!(czk, Currency::CZK);
     impl_money!(jpy, Currency::JPY);
+
+    /// Formats the value with the number of decimal places of the currency, so PayPal doesn't reject it.
+    ///
+    /// Missing decimal places are padded with zeros and superfluous zeros are removed, but a value that would need
+    /// rounding is rejected.
+    ///
+    /// ```
+    /// use paypal_rs::data::common::Money;
+    ///
+    /// assert_eq!(Money::usd("10.5").normalize().unwrap().value, "10.50");
+    /// assert_eq!(Money::jpy("100.00").normalize().unwrap().value, "100");
+    /// assert!(Money::jpy("100.5").normalize().is_err());
+    /// ```
+    pub fn normalize(&self) -> Result<Money, InvalidMoneyError> {
+        let malformed = || InvalidMoneyError::Malformed(self.value.clone());
+
+        let value = self.value.trim();
+        let (sign, value) = match value.strip_prefix('-') {
+            Some(value) => ("-", value),
+            None => ("", value),
+        };
+        let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+        if (integer.is_empty() && fraction.is_empty())
+            || value.ends_with('.')
+            || !integer.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return Err(malformed());
+        }
+
+        let places = self.currency_code.decimal_places() as usize;
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > places {
+            return Err(InvalidMoneyError::TooManyDecimals {
+                value: self.value.clone(),
+                currency: self.currency_code.clone(),
+            });
+        }
+
+        let integer = match integer.trim_start_matches('0') {
+            "" => "0",
+            integer => integer,
+        };
+        let value = if places == 0 {
+            format!("{}{}", sign, integer)
+        } else {
+            format!("{}{}.{:0<places$}", sign, integer, fraction)
+        };
+        Ok(Money {
+            currency_code: self.currency_code.clone(),
+            value,
+        })
+    }
+
+    /// Checks that the value is a valid amount of the currency, see [Money::normalize].
+    pub fn validate(&self) -> Result<(), InvalidMoneyError> {
+        self.normalize().map(|_| ())
+    }
+
+    /// The value in the smallest unit of the currency, like cents for USD.
+    pub fn to_minor_units(&self) -> Result<i128, InvalidMoneyError> {
+        self.normalize()?
+            .value
+            .replace('.', "")
+            .parse()
+            .map_err(|_| InvalidMoneyError::Malformed(self.value.clone()))
+    }
+
+    /// Creates an instance of Money from a value in the smallest unit of the currency, like cents for USD.
+    pub fn from_minor_units(currency_code: Currency, units: i128) -> Self {
+        let places = currency_code.decimal_places() as usize;
+        let sign = if units < 0 { "-" } else { "" };
+        let digits = format!("{:0>width$}", units.unsigned_abs(), width = places + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - places);
+        let value = if places == 0 {
+            format!("{}{}", sign, integer)
+        } else {
+            format!("{}{}.{}", sign, integer, fraction)
+        };
+        Self { currency_code, value }
+    }
+}
+
+/// Exact arithmetic on the amounts, requires the `rust_decimal` feature.
+///
+/// ```
+/// use paypal_rs::data::common::Money;
+/// use rust_decimal::Decimal;
+///
+/// let total = Money::usd("10.25").checked_add(&Money::usd("0.75")).unwrap();
+/// assert_eq!(total.as_decimal().unwrap(), Decimal::new(1100, 2));
+/// ```
+#[cfg(feature = "rust_decimal")]
+impl Money {
+    /// Creates an instance of Money with the given currency and amount.
+    pub fn from_decimal(currency_code: Currency, value: rust_decimal::Decimal) -> Self {
+        Self {
+            currency_code,
+            value: value.to_string(),
+        }
+    }
+
+    /// Parses the amount.
+    pub fn as_decimal(&self) -> Result<rust_decimal::Decimal, rust_decimal::Error> {
+        self.value.parse()
+    }
+
+    /// Adds the given amount, returns None if the currencies differ, an amount is invalid or the sum overflows.
+    pub fn checked_add(&self, other: &Money) -> Option<Money> {
+        if self.currency_code != other.currency_code {
+            return None;
+        }
+        let sum = self.as_decimal().ok()?.checked_add(other.as_decimal().ok()?)?;
+        Some(Self::from_decimal(self.currency_code.clone(), sum))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
@@ -141,6 +557,177 @@ pub enum LinkMethod {
     Patch,
 }
 
+impl From<LinkMethod> for reqwest::Method {
+    fn from(method: LinkMethod) -> Self {
+        match method {
+            LinkMethod::Get => reqwest::Method::GET,
+            LinkMethod::Post => reqwest::Method::POST,
+            LinkMethod::Put => reqwest::Method::PUT,
+            LinkMethod::Delete => reqwest::Method::DELETE,
+            LinkMethod::Head => reqwest::Method::HEAD,
+            LinkMethod::Connect => reqwest::Method::CONNECT,
+            LinkMethod::Options => reqwest::Method::OPTIONS,
+            LinkMethod::Patch => reqwest::Method::PATCH,
+        }
+    }
+}
+
+/// The relation type of a [LinkDescription].
+///
+/// Lists the relations used across the APIs, others deserialize to [LinkRel::Other].
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum LinkRel {
+    /// The resource itself, `self`.
+    SelfLink,
+    /// The page where the payer approves the payment.
+    Approve,
+    /// The page where the payer completes an action, like 3D Secure, `payer-action`.
+    PayerAction,
+    /// Captures the authorized payment or order.
+    Capture,
+    /// Authorizes the order.
+    Authorize,
+    /// Reauthorizes the authorized payment.
+    Reauthorize,
+    /// Voids the authorized payment.
+    Void,
+    /// Refunds the captured payment.
+    Refund,
+    /// The parent resource, like the order of a captured payment.
+    Up,
+    /// Updates the resource.
+    Update,
+    /// Edits the resource.
+    Edit,
+    /// Replaces the resource.
+    Replace,
+    /// Deletes the resource.
+    Delete,
+    /// Cancels the resource.
+    Cancel,
+    /// Sends the invoice.
+    Send,
+    /// Sends a reminder of the invoice.
+    Remind,
+    /// Records a payment of the invoice, `record-payment`.
+    RecordPayment,
+    /// Records a refund of the invoice, `record-refund`.
+    RecordRefund,
+    /// The page where the payer views the invoice, `payer-view`.
+    PayerView,
+    /// The page where the merchant views the invoice, `invoicer-view`.
+    InvoicerView,
+    /// Activates the subscription or plan.
+    Activate,
+    /// Suspends the subscription.
+    Suspend,
+    /// Deactivates the plan.
+    Deactivate,
+    /// The page where the seller completes the onboarding, `action_url`.
+    ActionUrl,
+    /// The first page of a list.
+    First,
+    /// The previous page of a list.
+    Previous,
+    /// The next page of a list.
+    Next,
+    /// The last page of a list.
+    Last,
+    /// A relation unknown to this crate.
+    Other(String),
+}
+
+impl LinkRel {
+    /// The relation as sent by PayPal.
+    pub fn as_str(&self) -> &str {
+        match self {
+            LinkRel::SelfLink => "self",
+            LinkRel::Approve => "approve",
+            LinkRel::PayerAction => "payer-action",
+            LinkRel::Capture => "capture",
+            LinkRel::Authorize => "authorize",
+            LinkRel::Reauthorize => "reauthorize",
+            LinkRel::Void => "void",
+            LinkRel::Refund => "refund",
+            LinkRel::Up => "up",
+            LinkRel::Update => "update",
+            LinkRel::Edit => "edit",
+            LinkRel::Replace => "replace",
+            LinkRel::Delete => "delete",
+            LinkRel::Cancel => "cancel",
+            LinkRel::Send => "send",
+            LinkRel::Remind => "remind",
+            LinkRel::RecordPayment => "record-payment",
+            LinkRel::RecordRefund => "record-refund",
+            LinkRel::PayerView => "payer-view",
+            LinkRel::InvoicerView => "invoicer-view",
+            LinkRel::Activate => "activate",
+            LinkRel::Suspend => "suspend",
+            LinkRel::Deactivate => "deactivate",
+            LinkRel::ActionUrl => "action_url",
+            LinkRel::First => "first",
+            LinkRel::Previous => "prev",
+            LinkRel::Next => "next",
+            LinkRel::Last => "last",
+            LinkRel::Other(rel) => rel,
+        }
+    }
+}
+
+impl From<&str> for LinkRel {
+    fn from(rel: &str) -> Self {
+        match rel {
+            "self" => LinkRel::SelfLink,
+            "approve" => LinkRel::Approve,
+            "payer-action" => LinkRel::PayerAction,
+            "capture" => LinkRel::Capture,
+            "authorize" => LinkRel::Authorize,
+            "reauthorize" => LinkRel::Reauthorize,
+            "void" => LinkRel::Void,
+            "refund" => LinkRel::Refund,
+            "up" => LinkRel::Up,
+            "update" => LinkRel::Update,
+            "edit" => LinkRel::Edit,
+            "replace" => LinkRel::Replace,
+            "delete" => LinkRel::Delete,
+            "cancel" => LinkRel::Cancel,
+            "send" => LinkRel::Send,
+            "remind" => LinkRel::Remind,
+            "record-payment" => LinkRel::RecordPayment,
+            "record-refund" => LinkRel::RecordRefund,
+            "payer-view" => LinkRel::PayerView,
+            "invoicer-view" => LinkRel::InvoicerView,
+            "activate" => LinkRel::Activate,
+            "suspend" => LinkRel::Suspend,
+            "deactivate" => LinkRel::Deactivate,
+            "action_url" => LinkRel::ActionUrl,
+            "first" => LinkRel::First,
+            "prev" | "previous" => LinkRel::Previous,
+            "next" => LinkRel::Next,
+            "last" => LinkRel::Last,
+            rel => LinkRel::Other(rel.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for LinkRel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for LinkRel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LinkRel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(LinkRel::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
+
 /// A HTOAES link
 #[skip_serializing_none]
 #[derive(Debug, Default, Serialize, Deserialize, Clone, Eq, PartialEq)]
@@ -148,13 +735,170 @@ pub struct LinkDescription {
     /// The complete target URL.
     pub href: String,
     /// The link relation type, which serves as an ID for a link that unambiguously describes the semantics of the link.
-    pub rel: Option<String>,
+    pub rel: Option<LinkRel>,
     /// The HTTP method required to make the related call.
     pub method: Option<LinkMethod>,
 }
 
+impl LinkDescription {
+    /// The path and query of the target URL, or None if it isn't a valid URL.
+    pub fn path(&self) -> Option<String> {
+        if self.href.starts_with('/') {
+            return Some(self.href.clone());
+        }
+
+        let url = reqwest::Url::parse(&self.href).ok()?;
+        Some(match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        })
+    }
+}
+
+/// The operation to perform in a [Patch].
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum PatchOperation {
+    /// Adds the value at the path, inserting it if the path is an array index.
+    Add,
+    /// Removes the value at the path.
+    Remove,
+    /// Replaces the value at the path.
+    Replace,
+    /// Moves the value at `from` to the path.
+    Move,
+    /// Copies the value at `from` to the path.
+    Copy,
+    /// Checks the value at the path is the given one, failing the whole update otherwise.
+    Test,
+}
+
+/// A JSON patch operation, used to partially update a resource.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc6902>
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Patch {
+    /// The operation.
+    pub op: PatchOperation,
+    /// The JSON Pointer to the target document location at which to complete the operation.
+    pub path: Option<String>,
+    /// The value to apply. The remove operation does not require a value.
+    pub value: Option<serde_json::Value>,
+    /// The JSON Pointer to the target document location from which to move the value. Required for the move operation.
+    pub from: Option<String>,
+}
+
+impl Patch {
+    fn new(op: PatchOperation, path: impl Into<String>, value: Option<serde_json::Value>) -> Self {
+        Self {
+            op,
+            path: Some(path.into()),
+            value,
+            from: None,
+        }
+    }
+
+    fn to_value(value: impl Serialize) -> Option<serde_json::Value> {
+        Some(serde_json::to_value(value).expect("serialize the patch value correctly"))
+    }
+
+    /// Adds the value at the path.
+    ///
+    /// # Panics
+    ///
+    /// If the value can't be serialized to json, like a map with non-string keys.
+    pub fn add(path: impl Into<String>, value: impl Serialize) -> Self {
+        Self::new(PatchOperation::Add, path, Self::to_value(value))
+    }
+
+    /// Replaces the value at the path.
+    ///
+    /// # Panics
+    ///
+    /// If the value can't be serialized to json, like a map with non-string keys.
+    pub fn replace(path: impl Into<String>, value: impl Serialize) -> Self {
+        Self::new(PatchOperation::Replace, path, Self::to_value(value))
+    }
+
+    /// Removes the value at the path.
+    pub fn remove(path: impl Into<String>) -> Self {
+        Self::new(PatchOperation::Remove, path, None)
+    }
+
+    /// Checks the value at the path is the given one, failing the whole update otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the value can't be serialized to json, like a map with non-string keys.
+    pub fn test(path: impl Into<String>, value: impl Serialize) -> Self {
+        Self::new(PatchOperation::Test, path, Self::to_value(value))
+    }
+
+    /// Moves the value at `from` to the path.
+    pub fn move_from(from: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            from: Some(from.into()),
+            ..Self::new(PatchOperation::Move, path, None)
+        }
+    }
+
+    /// Copies the value at `from` to the path.
+    pub fn copy_from(from: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            from: Some(from.into()),
+            ..Self::new(PatchOperation::Copy, path, None)
+        }
+    }
+
+    /// Builds a JSON Pointer from its segments, escaping `~` and `/` in them.
+    ///
+    /// ```
+    /// use paypal_rs::data::common::Patch;
+    ///
+    /// assert_eq!(Patch::pointer(&["links", "0", "a/b"]), "/links/0/a~1b");
+    /// ```
+    pub fn pointer(segments: &[&str]) -> String {
+        segments
+            .iter()
+            .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+            .collect()
+    }
+
+    /// The path of a field of the purchase unit with the given reference id, `default` for an order with a
+    /// single purchase unit without reference id.
+    ///
+    /// Fails if the reference id contains a `'`, which would end the quoted id early.
+    ///
+    /// ```
+    /// use paypal_rs::data::{common::Patch, orders::Amount};
+    ///
+    /// let patch = Patch::replace(Patch::purchase_unit_path("default", "amount").unwrap(), Amount::usd("15.00"));
+    /// assert_eq!(patch.path.as_deref(), Some("/purchase_units/@reference_id=='default'/amount"));
+    /// assert!(Patch::purchase_unit_path("o'brien", "amount").is_err());
+    /// ```
+    pub fn purchase_unit_path(reference_id: &str, field: &str) -> Result<String, InvalidReferenceIdError> {
+        if reference_id.contains('\'') {
+            return Err(InvalidReferenceIdError(reference_id.to_string()));
+        }
+        Ok(format!("/purchase_units/@reference_id=='{}'/{}", reference_id, field))
+    }
+
+    /// Replaces the `custom_id` of a resource like a subscription.
+    pub fn custom_id(custom_id: impl Into<String>) -> Self {
+        Self::replace("/custom_id", custom_id.into())
+    }
+
+    /// Replaces the url of a webhook.
+    pub fn webhook_url(url: impl Into<String>) -> Self {
+        Self::replace("/url", url.into())
+    }
+}
+
 /// ISO-4217 currency codes.
-#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+///
+/// Lists the currencies supported by PayPal, other codes deserialize to [Currency::Other].
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
 pub enum Currency {
     /// Australian dollar
     AUD,
@@ -209,17 +953,67 @@ pub enum Currency {
     THB,
     /// United States dollar
     USD,
+    /// A currency unknown to this crate, with its three-letter code.
+    Other(String),
+}
+
+impl Currency {
+    /// The three-letter code of the currency.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Currency::AUD => "AUD",
+            Currency::BRL => "BRL",
+            Currency::CAD => "CAD",
+            Currency::CNY => "CNY",
+            Currency::CZK => "CZK",
+            Currency::DKK => "DKK",
+            Currency::EUR => "EUR",
+            Currency::HKD => "HKD",
+            Currency::HUF => "HUF",
+            Currency::INR => "INR",
+            Currency::ILS => "ILS",
+            Currency::JPY => "JPY",
+            Currency::MYR => "MYR",
+            Currency::MXN => "MXN",
+            Currency::TWD => "TWD",
+            Currency::NZD => "NZD",
+            Currency::NOK => "NOK",
+            Currency::PHP => "PHP",
+            Currency::PLN => "PLN",
+            Currency::GBP => "GBP",
+            Currency::RUB => "RUB",
+            Currency::SGD => "SGD",
+            Currency::SEK => "SEK",
+            Currency::CHF => "CHF",
+            Currency::THB => "THB",
+            Currency::USD => "USD",
+            Currency::Other(code) => code,
+        }
+    }
+
+    /// The number of decimal places of the amounts in this currency.
+    pub fn decimal_places(&self) -> u32 {
+        match self {
+            Currency::HUF | Currency::JPY | Currency::TWD => 0,
+            // The ISO-4217 currencies subdivided into thousandths, which PayPal doesn't list.
+            Currency::Other(code) if matches!(code.as_str(), "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND") => {
+                3
+            }
+            _ => 2,
+        }
+    }
 }
 
 impl std::fmt::Display for Currency {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Debug::fmt(&self, f)
+        f.write_str(self.as_str())
     }
 }
 
 impl FromStr for Currency {
     type Err = InvalidCurrencyError;
 
+    /// Parses a three-letter uppercase code, codes unknown to this crate are [Currency::Other].
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "AUD" => Ok(Self::AUD),
@@ -236,40 +1030,68 @@ impl FromStr for Currency {
             "JPY" => Ok(Self::JPY),
             "MYR" => Ok(Self::MYR),
             "MXN" => Ok(Self::MXN),
+            "TWD" => Ok(Self::TWD),
+            "NZD" => Ok(Self::NZD),
             "NOK" => Ok(Self::NOK),
             "PHP" => Ok(Self::PHP),
             "PLN" => Ok(Self::PLN),
             "GBP" => Ok(Self::GBP),
             "RUB" => Ok(Self::RUB),
             "SGD" => Ok(Self::SGD),
-            "SEK" => Ok(Self::SGD),
+            "SEK" => Ok(Self::SEK),
             "CHF" => Ok(Self::CHF),
             "THB" => Ok(Self::THB),
             "USD" => Ok(Self::USD),
+            cur if cur.len() == 3 && cur.bytes().all(|b| b.is_ascii_uppercase()) => Ok(Self::Other(cur.to_owned())),
             cur => Err(InvalidCurrencyError(cur.to_owned())),
         }
     }
 }
 
+impl TryFrom<&str> for Currency {
+    type Error = InvalidCurrencyError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    /// Never fails on a string, so responses with unexpected currencies are still readable.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Ok(code.parse().unwrap_or(Currency::Other(code)))
+    }
+}
+
 /// Details about the status of the authorization.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct AuthorizationStatusDetails {
     /// The reason why the authorized status is PENDING.
     pub reason: AuthorizationStatusDetailsReason,
 }
 
 /// Authorization status reason.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AuthorizationStatusDetailsReason {
     /// Authorization is pending manual review.
     PendingReview,
     /// Risk Filter set by the payee failed for the transaction.
     DeclinedByRiskFraudFilters,
+    /// A reason unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Indicates whether the transaction is eligible for seller protection.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SellerProtectionStatus {
     /// Your PayPal balance remains intact if the customer claims that they did not receive an item or the account holder claims that they did not authorize the payment.
@@ -278,16 +1100,22 @@ pub enum SellerProtectionStatus {
     PartiallyEligible,
     /// This transaction is not eligible for seller protection.
     NotEligible,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The condition that is covered for the transaction.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum DisputeCategory {
     /// The payer paid for an item that they did not receive.
     ItemNotReceived,
     /// The payer did not authorize the payment.
     UnauthorizedTransaction,
+    /// A value unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The level of protection offered as defined by PayPal Seller Protection for Merchants.
@@ -298,3 +1126,149 @@ pub struct SellerProtection {
     /// An array of conditions that are covered for the transaction.
     pub dispute_categories: Vec<DisputeCategory>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency() {
+        assert_eq!("SEK".parse::<Currency>().unwrap(), Currency::SEK);
+        assert_eq!(Currency::try_from("TND").unwrap(), Currency::Other("TND".to_string()));
+        assert!("usd".parse::<Currency>().is_err());
+        assert_eq!(Currency::Other("TND".to_string()).to_string(), "TND");
+
+        let money: Money = serde_json::from_str(r#"{"currency_code":"XTS","value":"1.00"}"#).unwrap();
+        assert_eq!(money.currency_code, Currency::Other("XTS".to_string()));
+        assert_eq!(
+            serde_json::to_string(&money).unwrap(),
+            r#"{"currency_code":"XTS","value":"1.00"}"#
+        );
+        assert_eq!(serde_json::to_string(&Currency::JPY).unwrap(), r#""JPY""#);
+    }
+
+    #[test]
+    fn test_address_country() {
+        let address: Address = serde_json::from_str(r#"{"country_code":"GB"}"#).unwrap();
+        assert_eq!(address.country_code, Some(Country::GB));
+        assert!(serde_json::from_str::<Address>(r#"{"country_code":"UK"}"#).is_err());
+        assert_eq!(Country::try_from("UK").unwrap_err().0, "UK");
+    }
+
+    #[test]
+    fn test_item_upc() {
+        assert!(ItemUpc::upc_a("036000291452").is_ok());
+        assert!(ItemUpc::upc_a("03600029145").is_err());
+        assert!(ItemUpc::upc_a("03600029145A").is_err());
+        assert!(ItemUpc::upc_e("04252614").is_ok());
+        assert!(ItemUpc::upc_e("04252615").is_err());
+        assert!(ItemUpc::upc_e("24252614").is_err());
+        assert!(ItemUpc::new(UpcType::Upc2, "03600029145212").is_ok());
+        assert_eq!(
+            ItemUpc::new(UpcType::Upc2, "036000291452"),
+            Err(InvalidUpcError {
+                upc_type: UpcType::Upc2,
+                value: "036000291452".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_build_checked_address() {
+        let builder = || {
+            let mut builder = AddressBuilder::default();
+            builder
+                .address_line_1(" 2211 N First Street ")
+                .admin_area_2("San Jose")
+                .admin_area_1("ca")
+                .postal_code("95131-1234")
+                .country_code(Country::US);
+            builder
+        };
+
+        assert_eq!(
+            builder().build_checked(AddressValidation::Strict).unwrap_err(),
+            InvalidAddressError::Invalid {
+                field: "admin_area_1",
+                reason: "expected a state code like CA, got \"ca\"".to_string()
+            }
+        );
+        let address = builder().build_checked(AddressValidation::Lenient).unwrap();
+        assert_eq!(address.address_line_1.as_deref(), Some("2211 N First Street"));
+        assert_eq!(address.admin_area_1.as_deref(), Some("CA"));
+
+        assert!(
+            builder()
+                .admin_area_1("CA")
+                .build_checked(AddressValidation::Strict)
+                .is_ok()
+        );
+        assert!(matches!(
+            builder()
+                .admin_area_1("CA")
+                .postal_code("9513")
+                .build_checked(AddressValidation::Strict),
+            Err(InvalidAddressError::Invalid {
+                field: "postal_code",
+                ..
+            })
+        ));
+        assert_eq!(
+            builder()
+                .postal_code("  ")
+                .build_checked(AddressValidation::Lenient)
+                .unwrap_err(),
+            InvalidAddressError::Missing("postal_code")
+        );
+
+        // Only the lengths are checked in the other countries.
+        let address = AddressBuilder::default()
+            .address_line_1("x".repeat(301))
+            .country_code(Country::PT)
+            .build_checked(AddressValidation::Lenient)
+            .unwrap();
+        assert_eq!(address.address_line_1.unwrap().len(), 300);
+    }
+
+    #[test]
+    fn test_normalize_money() {
+        let normalized = |money: Money| money.normalize().map(|money| money.value);
+
+        assert_eq!(normalized(Money::usd("007")), Ok("7.00".to_string()));
+        assert_eq!(normalized(Money::usd(".5")), Ok("0.50".to_string()));
+        assert_eq!(normalized(Money::usd("-1.10")), Ok("-1.10".to_string()));
+        assert_eq!(normalized(Money::usd("1.000")), Ok("1.00".to_string()));
+        assert_eq!(normalized(Money::jpy("0")), Ok("0".to_string()));
+
+        for value in ["", "-", "1.", "1,00", "1.2.3", "abc"] {
+            assert_eq!(
+                normalized(Money::usd(value)),
+                Err(InvalidMoneyError::Malformed(value.to_string()))
+            );
+        }
+        assert_eq!(Money::usd("-0.05").to_minor_units(), Ok(-5));
+        assert_eq!(Money::from_minor_units(Currency::USD, -5).value, "-0.05");
+        assert_eq!(Money::from_minor_units(Currency::USD, 1234).value, "12.34");
+        assert_eq!(Money::from_minor_units(Currency::JPY, 1234).value, "1234");
+
+        let tnd = |value: &str| Money {
+            currency_code: Currency::Other("TND".to_string()),
+            value: value.to_string(),
+        };
+        assert_eq!(normalized(tnd("1.005")), Ok("1.005".to_string()));
+        assert_eq!(normalized(tnd("2.5")), Ok("2.500".to_string()));
+        assert_eq!(tnd("1.005").to_minor_units(), Ok(1005));
+        assert_eq!(
+            Money::from_minor_units(Currency::Other("TND".to_string()), 1005).value,
+            "1.005"
+        );
+
+        assert!(matches!(
+            normalized(Money::usd("1.005")),
+            Err(InvalidMoneyError::TooManyDecimals {
+                currency: Currency::USD,
+                ..
+            })
+        ));
+    }
+}
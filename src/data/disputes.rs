@@ -0,0 +1,585 @@
+//! Paypal object definitions used by the disputes api.
+
+use crate::data::common::{Address, LinkDescription, Money};
+use crate::errors::InvalidMimeTypeError;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The evidence type.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EvidenceType {
+    /// Proof that the item was shipped or the service was provided.
+    ProofOfFulfillment,
+    /// Proof that the merchant refunded the buyer.
+    ProofOfRefund,
+    /// Proof of the delivery signature.
+    ProofOfDeliverySignature,
+    /// A copy of the receipt.
+    ProofOfReceiptCopy,
+    /// The merchant return policy.
+    ReturnPolicy,
+    /// The billing agreement.
+    BillingAgreement,
+    /// Proof that the item was reshipped.
+    ProofOfReshipment,
+    /// The item description.
+    ItemDescription,
+    /// A police report.
+    PoliceReport,
+    /// An affidavit.
+    Affidavit,
+    /// Proof that the buyer paid with another method.
+    PaidWithOtherMethod,
+    /// A copy of the contract.
+    CopyOfContract,
+    /// The terminal or ATM receipt.
+    TerminalAtmReceipt,
+    /// The reason for the price difference.
+    PriceDifferenceReason,
+    /// The source conversion rate.
+    SourceConversionRate,
+    /// A bank statement.
+    BankStatement,
+    /// The reason why the credit is due.
+    CreditDueReason,
+    /// The request for a credit receipt.
+    RequestCreditReceipt,
+    /// Proof that the item was returned.
+    ProofOfReturn,
+    /// The evidence was created.
+    Create,
+    /// The reason for the change.
+    ChangeReason,
+    /// Proof that the merchant refunded the buyer outside of PayPal.
+    ProofOfRefundOutsidePaypal,
+    /// A receipt of the merchandise.
+    ReceiptOfMerchandise,
+    /// A customs document.
+    CustomsDocument,
+    /// A customs fee receipt.
+    CustomsFeeReceipt,
+    /// Information about the resolution.
+    InformationOnResolution,
+    /// Additional information about the item.
+    AdditionalInformationOfItem,
+    /// Details of the purchase.
+    DetailsOfPurchase,
+    /// Proof that the item is significantly different from its description.
+    ProofOfSignificantDifference,
+    /// Proof that the software or service was not as described.
+    ProofOfSoftwareOrServiceNotAsDescribed,
+    /// Proof that the item was confiscated.
+    ProofOfConfiscation,
+    /// Proof that the item was damaged.
+    ProofOfDamage,
+    /// A copy of the law enforcement agency report.
+    CopyOfLawEnforcementAgencyReport,
+    /// Additional proof that the item was shipped.
+    AdditionalProofOfShipment,
+    /// Proof that the carrier denied the delivery.
+    ProofOfDenialByCarrier,
+    /// A valid supporting document.
+    ValidSupportingDocument,
+    /// A legible supporting document.
+    LegibleSupportingDocument,
+    /// The tracking information of the return.
+    ReturnTrackingInformation,
+    /// A delivery receipt.
+    DeliveryReceipt,
+    /// Proof of the in-store receipt.
+    ProofOfInstoreReceipt,
+    /// Additional tracking information.
+    AdditionalTrackingInformation,
+    /// Proof of the shipment postage.
+    ProofOfShipmentPostage,
+    /// The online tracking information.
+    OnlineTrackingInformation,
+    /// Proof of the in-store refund.
+    ProofOfInstoreRefund,
+    /// Proof that the software or service was delivered.
+    ProofForSoftwareOrServiceDelivered,
+    /// The return address for shipping.
+    ReturnAddressForShipping,
+    /// An affidavit used on appeals.
+    AppealAffidavit,
+    /// A receipt of the replacement.
+    ReceiptOfReplacement,
+    /// The merchant response.
+    MerchantResponse,
+    /// Other evidence.
+    Other,
+}
+
+/// A merchant or customer-submitted tracking information.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct EvidenceTrackingInfo {
+    /// The name of the carrier for the shipment of the transaction for this dispute.
+    pub carrier_name: Option<String>,
+    /// The name of the carrier when `carrier_name` is `OTHER`.
+    pub carrier_name_other: Option<String>,
+    /// The URL to track the dispute-related transaction shipment.
+    pub tracking_url: Option<String>,
+    /// The number to track the dispute-related transaction shipment.
+    pub tracking_number: Option<String>,
+}
+
+/// A refund id referenced as evidence.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct EvidenceRefundId {
+    /// The ID of the refunded transaction.
+    pub refund_id: String,
+}
+
+/// The evidence-related information.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct EvidenceInfo {
+    /// An array of relevant tracking information for the transaction involved in this dispute.
+    pub tracking_info: Option<Vec<EvidenceTrackingInfo>>,
+    /// An array of refund IDs for the transaction involved in this dispute.
+    pub refund_ids: Option<Vec<EvidenceRefundId>>,
+}
+
+/// A document attached to a piece of evidence or message.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct Document {
+    /// The document name.
+    pub name: Option<String>,
+    /// The downloadable URL for the document.
+    pub url: Option<String>,
+}
+
+/// A piece of evidence submitted for a dispute.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct Evidence {
+    /// The evidence type.
+    pub evidence_type: EvidenceType,
+    /// The evidence-related information.
+    #[builder(default)]
+    pub evidence_info: Option<EvidenceInfo>,
+    /// An array of evidence documents.
+    #[builder(default)]
+    pub documents: Option<Vec<Document>>,
+    /// Any evidence-related notes.
+    #[builder(default)]
+    pub notes: Option<String>,
+    /// The item ID. If the merchant provides multiple pieces of evidence and the transaction has multiple item IDs,
+    /// the merchant can use this value to associate a piece of evidence with an item ID.
+    #[builder(default)]
+    pub item_id: Option<String>,
+}
+
+/// The json part of the multipart request used to submit evidences.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct EvidencePayload {
+    /// An array of evidences for the dispute.
+    pub evidences: Vec<Evidence>,
+}
+
+/// A file uploaded along with the evidences of a dispute.
+///
+/// Supported file types are JPG, GIF, PNG and PDF, each file must be smaller than 10MB.
+#[derive(Debug, Clone)]
+pub struct EvidenceFile {
+    /// The file name, as it will be shown in the dispute.
+    pub file_name: String,
+    mime_type: String,
+    /// The contents of the file.
+    pub content: Vec<u8>,
+}
+
+impl EvidenceFile {
+    /// New constructor, fails if the mime type can't be parsed.
+    pub fn new(
+        file_name: impl ToString,
+        mime_type: impl ToString,
+        content: Vec<u8>,
+    ) -> Result<Self, InvalidMimeTypeError> {
+        let mime_type = mime_type.to_string();
+        if reqwest::multipart::Part::bytes(Vec::new())
+            .mime_str(&mime_type)
+            .is_err()
+        {
+            return Err(InvalidMimeTypeError(mime_type));
+        }
+        Ok(Self {
+            file_name: file_name.to_string(),
+            mime_type,
+            content,
+        })
+    }
+
+    /// The mime type of the file, for example `application/pdf`.
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+}
+
+/// The type of acknowledgement the merchant gives for an item returned by the customer.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AcknowledgementType {
+    /// The merchant received the item back.
+    ItemReceived,
+    /// The merchant did not receive the item back.
+    ItemNotReceived,
+    /// The item was returned damaged.
+    Damaged,
+    /// The package was empty or contained a different item.
+    EmptyPackageOrDifferent,
+    /// Some of the items are missing.
+    MissingItems,
+}
+
+/// The payload used to acknowledge that the customer returned an item.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct AcknowledgeReturnedItemPayload {
+    /// Merchant provided notes.
+    pub note: Option<String>,
+    /// The type of acknowledgement. Required when the item was not received back as expected.
+    pub acknowledgement_type: Option<AcknowledgementType>,
+    /// An array of evidences supporting the acknowledgement.
+    pub evidences: Option<Vec<Evidence>>,
+}
+
+/// The type of offer the merchant makes to resolve a dispute.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OfferType {
+    /// The merchant must refund the customer without any item replacement or return.
+    Refund,
+    /// The customer must return the item to the merchant and then the merchant refunds the money.
+    RefundWithReturn,
+    /// The merchant must do a refund and send a replacement item to the customer.
+    RefundWithReplacement,
+    /// The merchant must send a replacement item to the customer with no additional refund.
+    ReplacementWithoutRefund,
+}
+
+/// The payload used to make an offer to the customer to resolve a dispute.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct MakeOfferPayload {
+    /// The merchant's notes about the offer.
+    pub note: String,
+    /// The amount proposed to resolve the dispute.
+    #[builder(default)]
+    pub offer_amount: Option<Money>,
+    /// The return address for the item. Required when the offer type is `REFUND_WITH_RETURN`.
+    #[builder(default)]
+    pub return_shipping_address: Option<Address>,
+    /// The merchant-provided ID of the invoice for the refund.
+    #[builder(default)]
+    pub invoice_id: Option<String>,
+    /// The merchant-proposed offer type for the dispute.
+    pub offer_type: OfferType,
+}
+
+/// A payload that only carries a note, used by several dispute actions.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct NotePayload {
+    /// The merchant's notes.
+    pub note: Option<String>,
+}
+
+impl NotePayload {
+    /// New constructor.
+    pub fn new(note: impl ToString) -> Self {
+        Self {
+            note: Some(note.to_string()),
+        }
+    }
+}
+
+/// The payload used to send a message to the other party of a dispute.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct MessagePayload {
+    /// The message to send.
+    pub message: String,
+}
+
+/// The party who posted a dispute message.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MessagePoster {
+    /// The customer posted the message.
+    Buyer,
+    /// The merchant posted the message.
+    Seller,
+    /// The PayPal agent posted the message.
+    Arbiter,
+    /// A value unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A message in the thread of a dispute.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DisputeMessage {
+    /// The party who posted the message.
+    pub posted_by: Option<MessagePoster>,
+    /// The date and time when the message was posted.
+    pub time_posted: Option<chrono::DateTime<chrono::Utc>>,
+    /// The message text.
+    pub content: Option<String>,
+    /// An array of documents attached to the message.
+    pub documents: Option<Vec<Document>>,
+}
+
+/// The reason for the item-level dispute.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum DisputeReason {
+    /// The customer did not receive the merchandise or service.
+    MerchandiseOrServiceNotReceived,
+    /// The customer reports that the merchandise or service is not as described.
+    MerchandiseOrServiceNotAsDescribed,
+    /// The customer did not authorize purchase of the merchandise or service.
+    Unauthorised,
+    /// The refund or credit was not processed for the customer.
+    CreditNotProcessed,
+    /// The transaction was a duplicate.
+    DuplicateTransaction,
+    /// The customer was charged an incorrect amount.
+    IncorrectAmount,
+    /// The customer paid for the transaction through other means.
+    PaymentByOtherMeans,
+    /// The customer was being charged for a subscription or a recurring transaction that was canceled.
+    CanceledRecurringBilling,
+    /// A problem occurred with the remittance.
+    ProblemWithRemittance,
+    /// Other.
+    Other,
+    /// A reason unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The status of the dispute.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum DisputeStatus {
+    /// The dispute is open.
+    Open,
+    /// The dispute is waiting for a response from the customer.
+    WaitingForBuyerResponse,
+    /// The dispute is waiting for a response from the merchant.
+    WaitingForSellerResponse,
+    /// The dispute is under review with PayPal.
+    UnderReview,
+    /// The dispute is resolved.
+    Resolved,
+    /// The default status if the dispute does not have one of the other statuses.
+    Other,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The stage in the dispute lifecycle.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum DisputeLifeCycleStage {
+    /// A customer and merchant interact in an attempt to resolve a dispute without escalation to PayPal.
+    Inquiry,
+    /// A customer or merchant escalates an inquiry to a claim, which authorizes PayPal to investigate the case and make a determination.
+    Chargeback,
+    /// The first appeal stage for merchants.
+    PreArbitration,
+    /// The second appeal stage for merchants.
+    Arbitration,
+    /// A value unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The channel where the customer created the dispute.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum DisputeChannel {
+    /// The customer contacts PayPal to file a dispute with the merchant.
+    Internal,
+    /// The customer contacts their card issuer or bank to request a refund.
+    External,
+    /// An alert raised through a card network, which can be resolved before it becomes a chargeback.
+    Alert,
+    /// A value unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The outcome of a resolved dispute.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum DisputeOutcomeCode {
+    /// The dispute was resolved in the customer's favor.
+    ResolvedBuyerFavour,
+    /// The dispute was resolved in the merchant's favor.
+    ResolvedSellerFavour,
+    /// PayPal provided the merchant or customer with protection and the case is resolved.
+    ResolvedWithPayout,
+    /// The customer canceled the dispute.
+    CanceledByBuyer,
+    /// The dispute was accepted.
+    Accepted,
+    /// The dispute was denied.
+    Denied,
+    /// A dispute was created for the same transaction ID.
+    #[serde(rename = "NONE")]
+    NoOutcome,
+    /// A value unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The customer involved in a disputed transaction.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct DisputeBuyer {
+    /// The customer's name.
+    pub name: Option<String>,
+}
+
+/// The merchant involved in a disputed transaction.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct DisputeSeller {
+    /// The email address of the merchant's PayPal account.
+    pub email: Option<String>,
+    /// The PayPal account ID of the merchant.
+    pub merchant_id: Option<String>,
+    /// The name of the merchant.
+    pub name: Option<String>,
+}
+
+/// A transaction for which the dispute was created.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct DisputedTransaction {
+    /// The ID, as seen by the customer, for this transaction.
+    pub buyer_transaction_id: Option<String>,
+    /// The ID, as seen by the merchant, for this transaction.
+    pub seller_transaction_id: Option<String>,
+    /// The date and time when the transaction was created.
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The transaction status.
+    pub transaction_status: Option<String>,
+    /// The gross amount of the transaction.
+    pub gross_amount: Option<Money>,
+    /// The ID of the invoice for the payment.
+    pub invoice_number: Option<String>,
+    /// A free-text field that is entered by the merchant during checkout.
+    pub custom: Option<String>,
+    /// The customer involved in the transaction.
+    pub buyer: Option<DisputeBuyer>,
+    /// The merchant involved in the transaction.
+    pub seller: Option<DisputeSeller>,
+}
+
+/// The outcome of a resolved dispute.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DisputeOutcome {
+    /// The outcome of a resolved dispute.
+    pub outcome_code: Option<DisputeOutcomeCode>,
+    /// The amount that either the merchant or PayPal refunds the customer.
+    pub amount_refunded: Option<Money>,
+}
+
+/// A customer dispute.
+///
+/// Definition: <https://developer.paypal.com/docs/api/customer-disputes/v1/#disputes_get>
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Dispute {
+    /// The ID of the dispute.
+    pub dispute_id: String,
+    /// The date and time when the dispute was created.
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the dispute was last updated.
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of transactions for which disputes were created.
+    pub disputed_transactions: Option<Vec<DisputedTransaction>>,
+    /// The reason for the item-level dispute.
+    pub reason: Option<DisputeReason>,
+    /// The status of the dispute.
+    pub status: Option<DisputeStatus>,
+    /// The amount in the transaction that the customer originally disputed.
+    pub dispute_amount: Option<Money>,
+    /// The outcome of a dispute.
+    pub dispute_outcome: Option<DisputeOutcome>,
+    /// The stage in the dispute lifecycle.
+    pub dispute_life_cycle_stage: Option<DisputeLifeCycleStage>,
+    /// The channel where the customer created the dispute.
+    pub dispute_channel: Option<DisputeChannel>,
+    /// The thread of messages between the parties of the dispute.
+    #[serde(default)]
+    pub messages: Vec<DisputeMessage>,
+    /// An array of evidence documents.
+    pub evidences: Option<Vec<Evidence>>,
+    /// The date and time by when the merchant must respond to the dispute.
+    pub seller_response_due_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time by when the customer must respond to the dispute.
+    pub buyer_response_due_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The party from which evidence is requested, used in the sandbox to move a dispute forward.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RequireEvidenceAction {
+    /// Moves the dispute to the `WAITING_FOR_BUYER_RESPONSE` status.
+    BuyerEvidence,
+    /// Moves the dispute to the `WAITING_FOR_SELLER_RESPONSE` status.
+    SellerEvidence,
+}
+
+/// The payload used to request evidence for a dispute in the sandbox.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RequireEvidencePayload {
+    /// The party from which evidence is requested.
+    pub action: RequireEvidenceAction,
+}
+
+/// The response of dispute actions, which only contains the links to follow up.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct SubsequentAction {
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_codes() {
+        let code: DisputeOutcomeCode = serde_json::from_value(serde_json::json!("NONE")).unwrap();
+        assert_eq!(code, DisputeOutcomeCode::NoOutcome);
+        assert_eq!(serde_json::to_value(&code).unwrap(), "NONE");
+
+        let code: DisputeOutcomeCode = serde_json::from_value(serde_json::json!("RESOLVED_BY_NETWORK")).unwrap();
+        assert_eq!(code, DisputeOutcomeCode::Unknown("RESOLVED_BY_NETWORK".to_string()));
+    }
+}
@@ -0,0 +1,108 @@
+//! Paypal object definitions used by the identity api.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Accepts both a json boolean and the `"true"`/`"false"` strings PayPal returns for some flags.
+fn bool_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flag {
+        Bool(bool),
+        String(String),
+    }
+
+    match Option::<Flag>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Flag::Bool(flag)) => Ok(Some(flag)),
+        Some(Flag::String(flag)) => flag.parse().map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+/// The query of the user info endpoint.
+#[derive(Debug, Serialize, Clone)]
+pub struct UserInfoQuery {
+    /// The schema used to return the user profile, only `openid` is supported.
+    pub schema: String,
+}
+
+impl Default for UserInfoQuery {
+    fn default() -> Self {
+        Self {
+            schema: "openid".to_string(),
+        }
+    }
+}
+
+/// An email address of a PayPal user.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UserEmail {
+    /// The email address.
+    pub value: String,
+    /// Whether this is the primary email address of the user.
+    pub primary: Option<bool>,
+    /// Whether the user confirmed this email address.
+    pub confirmed: Option<bool>,
+}
+
+/// The address of a PayPal user.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct UserAddress {
+    /// The full street address.
+    pub street_address: Option<String>,
+    /// The city or locality.
+    pub locality: Option<String>,
+    /// The state, province or region.
+    pub region: Option<String>,
+    /// The postal code.
+    pub postal_code: Option<String>,
+    /// The two-character ISO 3166-1 country code.
+    pub country: Option<String>,
+}
+
+/// The profile of a PayPal user, as shared through Log In with PayPal.
+///
+/// Which fields are present depends on the scopes the user consented to.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct UserInfo {
+    /// The Log In with PayPal ID of the user.
+    pub user_id: Option<String>,
+    /// The subject identifier of the user.
+    pub sub: Option<String>,
+    /// The full name of the user.
+    pub name: Option<String>,
+    /// The given, or first, name of the user.
+    pub given_name: Option<String>,
+    /// The surname or family name of the user.
+    pub family_name: Option<String>,
+    /// The PayPal payer ID of the user.
+    pub payer_id: Option<String>,
+    /// The address of the user.
+    pub address: Option<UserAddress>,
+    /// Whether the PayPal account of the user is verified.
+    #[serde(default, deserialize_with = "bool_or_string")]
+    pub verified_account: Option<bool>,
+    /// The email addresses of the user.
+    #[serde(default)]
+    pub emails: Vec<UserEmail>,
+}
+
+/// The payload used to generate a client token.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct GenerateTokenPayload {
+    /// The ID of the customer in the vault, to let the JS SDK show the payment methods saved for them.
+    pub customer_id: Option<String>,
+}
+
+/// A client token, used to initialize the JS SDK for advanced card payments.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClientToken {
+    /// The client token.
+    pub client_token: String,
+    /// Seconds until it expires.
+    pub expires_in: u64,
+}
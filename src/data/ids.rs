@@ -0,0 +1,163 @@
+//! Typed ids of the PayPal resources, so the id of a capture can't be passed where the id of an order is expected.
+//!
+//! The ids convert from strings, so the endpoints still accept the ids stored as such:
+//!
+//! ```
+//! use paypal_rs::{api::orders::ShowOrderDetails, data::ids::OrderId};
+//!
+//! let order_id: OrderId = "5O190127TN364715T".parse().unwrap();
+//! assert_eq!(ShowOrderDetails::new(&order_id).order_id, "5O190127TN364715T");
+//! assert_eq!(ShowOrderDetails::new("5O190127TN364715T").order_id, order_id);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::str::FromStr;
+
+macro_rules! id {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Creates an id from its string.
+            pub fn new(id: impl Into<String>) -> Self {
+                Self(id.into())
+            }
+
+            /// Returns the id as a string.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Returns the string of the id.
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::new(s))
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self::new(id)
+            }
+        }
+
+        impl From<&String> for $name {
+            fn from(id: &String) -> Self {
+                Self::new(id.as_str())
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(id: &$name) -> Self {
+                id.clone()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl std::borrow::Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<String> for $name {
+            fn eq(&self, other: &String) -> bool {
+                &self.0 == other
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+id!(
+    /// The id of an order.
+    OrderId
+);
+id!(
+    /// The id of an authorized payment.
+    AuthorizationId
+);
+id!(
+    /// The id of a captured payment.
+    CaptureId
+);
+id!(
+    /// The id of a refund.
+    RefundId
+);
+id!(
+    /// The id of an invoice, like `INV2-Z56S-5LLA-Q52L-CPZ5`.
+    InvoiceId
+);
+id!(
+    /// The id of a tracker, formatted as `{transaction_id}-{tracking_number}`.
+    TrackerId
+);
+
+impl TrackerId {
+    /// Builds the id of the tracker of the given tracking number of a transaction.
+    pub fn from_parts(transaction_id: &str, tracking_number: &str) -> Self {
+        Self(format!("{}-{}", transaction_id, tracking_number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ids() {
+        let capture_id: CaptureId = serde_json::from_value(serde_json::json!("2GG279541U471931P")).unwrap();
+        assert_eq!(capture_id, "2GG279541U471931P");
+        assert_eq!(capture_id.to_string(), "2GG279541U471931P");
+        assert_eq!(serde_json::to_value(&capture_id).unwrap(), "2GG279541U471931P");
+
+        let tracker_id = TrackerId::from_parts("8MC585209K746392H", "443844607820");
+        assert_eq!(tracker_id.as_str(), "8MC585209K746392H-443844607820");
+    }
+}
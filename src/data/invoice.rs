@@ -1,6 +1,6 @@
 //! Paypal object definitions used in the invoice api.
 
-use crate::{data::common::LinkDescription, data::common::*};
+use crate::{data::common::LinkDescription, data::common::*, data::ids::InvoiceId};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -29,19 +29,28 @@ pub enum PaymentTermType {
     /// The payment for the invoice is due on the date specified in the invoice.
     DueOnDateSpecified,
     /// The payment for the invoice is due in 10 days.
+    #[serde(rename = "NET_10")]
     Net10,
     /// The payment for the invoice is due in 15 days.
+    #[serde(rename = "NET_15")]
     Net15,
     /// The payment for the invoice is due in 30 days.
+    #[serde(rename = "NET_30")]
     Net30,
     /// The payment for the invoice is due in 45 days.
+    #[serde(rename = "NET_45")]
     Net45,
     /// The payment for the invoice is due in 60 days.
+    #[serde(rename = "NET_60")]
     Net60,
     /// The payment for the invoice is due in 90 days.
+    #[serde(rename = "NET_90")]
     Net90,
     /// The invoice has no payment due date.
     NoDueDate,
+    /// A term unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The payment due date for the invoice.
@@ -64,6 +73,9 @@ pub enum FlowType {
     Batch,
     /// The regular invoice sent to single recipient.
     RegularSingle,
+    /// A flow unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Metadata about a resource
@@ -77,11 +89,11 @@ pub struct Metadata {
     /// The date and time when the resource was last edited
     pub last_update_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The email address of the account that last edited the resource.
-    pub last_updated_by: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_updated_by: Option<String>,
     /// The date and time when the resource was canceled
     pub cancel_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The actor who canceled the resource.
-    pub cancelled_by: Option<chrono::DateTime<chrono::Utc>>,
+    pub cancelled_by: Option<String>,
     /// The date and time when the resource was first sent
     pub first_sent_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The date and time when the resource was last sent
@@ -170,6 +182,8 @@ pub struct InvoicerInfo {
     pub business_name: Option<String>,
     /// The first and Last name of the recipient.
     pub name: Option<Name>,
+    /// The invoicer's address.
+    pub address: Option<Address>,
     /// The invoicer email address, which must be listed in the user's PayPal profile.
     /// If you omit this value, notifications are sent from and to the primary email address but do not appear on the invoice.
     pub email_address: Option<String>,
@@ -189,8 +203,8 @@ pub struct InvoicerInfo {
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct BillingInfo {
-    /// Required. The business name of the party.
-    pub business_name: String,
+    /// The business name of the party.
+    pub business_name: Option<String>,
     /// The first and Last name of the recipient.
     pub name: Option<Name>,
     /// The address of the recipient.
@@ -209,8 +223,8 @@ pub struct BillingInfo {
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ContactInformation {
-    /// Required. The business name of the party.
-    pub business_name: String,
+    /// The business name of the party.
+    pub business_name: Option<String>,
     /// The first and Last name of the recipient.
     pub name: Option<Name>,
     /// The address of the recipient.
@@ -240,6 +254,7 @@ pub struct Tax {
 }
 
 /// Discount information
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Builder, Default)]
 #[builder(setter(strip_option, into), default)]
 pub struct Discount {
@@ -259,6 +274,9 @@ pub enum UnitOfMeasure {
     Hours,
     /// The unit of measure is amount. This invoice template is typically used when only amount is required.
     Amount,
+    /// A unit unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Item information
@@ -334,6 +352,7 @@ pub struct AggregatedDiscount {
 }
 
 /// The shipping fee
+#[skip_serializing_none]
 #[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct ShippingCost {
@@ -402,17 +421,20 @@ impl Amount {
 }
 
 /// The payment type in an invoicing flow
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PaymentType {
     /// The payment type is PayPal.
     Paypal,
     /// The payment type is an external cash or a check payment.
     External,
+    /// A type unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The payment mode or method through which the invoicer can accept the payment.
-#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PaymentMethod {
     /// Payments can be received through bank transfers.
@@ -432,6 +454,9 @@ pub enum PaymentMethod {
     WireTransfer,
     /// Payments can be received through other modes.
     Other,
+    /// A method unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Payment detail
@@ -509,7 +534,7 @@ pub struct Refunds {
 }
 
 /// The status of the invoice
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Status {
     ///  The invoice is in draft state. It is not yet sent to the payer.
@@ -536,6 +561,9 @@ pub enum Status {
     Unpaid,
     /// The invoicer is yet to receive the payment for the invoice. It is under pending review.
     PaymentPending,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// An invoice payload
@@ -570,7 +598,7 @@ pub struct InvoicePayload {
 #[builder(setter(strip_option, into))]
 pub struct Invoice {
     /// The ID of the invoice.
-    pub id: String,
+    pub id: InvoiceId,
     /// The parent ID to an invoice that defines the group invoice to which the invoice is related.
     #[builder(default)]
     pub parent_id: Option<String>,
@@ -613,6 +641,39 @@ pub struct Invoice {
     pub links: Option<Vec<LinkDescription>>,
 }
 
+impl Invoice {
+    /// Returns the URL where the payer can view and pay the invoice on paypal.com.
+    ///
+    /// Uses the view URL from the invoice metadata, which is only available after the invoice is sent,
+    /// falling back to the `payer-view` HATEOAS link.
+    pub fn payer_view_url(&self) -> Option<&str> {
+        self.detail
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.recipient_view_url.as_deref())
+            .or_else(|| self.link(LinkRel::PayerView))
+    }
+
+    /// Returns the URL where the merchant can view the invoice on paypal.com.
+    ///
+    /// Uses the view URL from the invoice metadata, falling back to the `invoicer-view` HATEOAS link.
+    pub fn merchant_view_url(&self) -> Option<&str> {
+        self.detail
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.invoicer_view_url.as_deref())
+            .or_else(|| self.link(LinkRel::InvoicerView))
+    }
+
+    fn link(&self, rel: LinkRel) -> Option<&str> {
+        self.links
+            .iter()
+            .flatten()
+            .find(|link| link.rel.as_ref() == Some(&rel))
+            .map(|link| link.href.as_str())
+    }
+}
+
 /// A invoice list
 #[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[builder(setter(into))]
@@ -697,3 +758,68 @@ pub struct SendInvoicePayload {
     /// The subject of the email that is sent as a notification to the recipient.
     pub subject: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invoice(metadata: serde_json::Value, links: serde_json::Value) -> Invoice {
+        serde_json::from_value(serde_json::json!({
+            "id": "INV2-Z56S-5LLA-Q52L-CPZ5",
+            "status": "SENT",
+            "detail": { "currency_code": "USD", "metadata": metadata },
+            "amount": { "currency_code": "USD", "value": "10.00" },
+            "links": links,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_view_urls_from_metadata() {
+        let invoice = invoice(
+            serde_json::json!({
+                "recipient_view_url": "https://www.paypal.com/invoice/p/#INV2-Z56S-5LLA-Q52L-CPZ5",
+                "invoicer_view_url": "https://www.paypal.com/invoice/details/INV2-Z56S-5LLA-Q52L-CPZ5"
+            }),
+            serde_json::json!([
+                { "href": "https://www.paypal.com/invoice/p/#link", "rel": "payer-view", "method": "GET" },
+                { "href": "https://www.paypal.com/invoice/details/link", "rel": "invoicer-view", "method": "GET" }
+            ]),
+        );
+
+        assert_eq!(
+            invoice.payer_view_url(),
+            Some("https://www.paypal.com/invoice/p/#INV2-Z56S-5LLA-Q52L-CPZ5")
+        );
+        assert_eq!(
+            invoice.merchant_view_url(),
+            Some("https://www.paypal.com/invoice/details/INV2-Z56S-5LLA-Q52L-CPZ5")
+        );
+    }
+
+    #[test]
+    fn test_view_urls_from_links() {
+        let invoice = invoice(
+            serde_json::json!({}),
+            serde_json::json!([
+                { "href": "https://api-m.paypal.com/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5", "rel": "self", "method": "GET" },
+                { "href": "https://www.paypal.com/invoice/p/#link", "rel": "payer-view", "method": "GET" },
+                { "href": "https://www.paypal.com/invoice/details/link", "rel": "invoicer-view", "method": "GET" }
+            ]),
+        );
+
+        assert_eq!(invoice.payer_view_url(), Some("https://www.paypal.com/invoice/p/#link"));
+        assert_eq!(
+            invoice.merchant_view_url(),
+            Some("https://www.paypal.com/invoice/details/link")
+        );
+    }
+
+    #[test]
+    fn test_view_urls_missing() {
+        let invoice = invoice(serde_json::Value::Null, serde_json::Value::Null);
+
+        assert_eq!(invoice.payer_view_url(), None);
+        assert_eq!(invoice.merchant_view_url(), None);
+    }
+}
@@ -1,8 +1,15 @@
 //! This module contains the data structures used in the api endpoints.
 
 pub mod common;
+pub mod disputes;
+pub mod identity;
+pub mod ids;
 pub mod invoice;
 pub mod orders;
+pub mod partner_referrals;
 pub mod payment;
 pub mod shipment_carrier;
 pub mod tracking;
+pub mod transactions;
+pub mod vault;
+pub mod webhooks;
@@ -1,13 +1,16 @@
 //! Paypal object definitions used by the orders api.
 
 use super::common::*;
+use crate::data::ids::{AuthorizationId, CaptureId, OrderId, RefundId};
 use crate::data::tracking::ShipmentItem;
+use crate::data::vault::{CardExperienceContext, PaymentSourceAttributes, PaymentSourceVault, WalletExperienceContext};
+use crate::errors::{InvalidAmountError, InvalidPhoneNumberError};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 /// The intent to either capture payment immediately or authorize a payment for an order after order creation.
-#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Intent {
     /// The merchant intends to capture payment immediately after the customer makes a payment.
@@ -19,6 +22,9 @@ pub enum Intent {
     /// You must make a separate request to capture payments on demand.
     /// This intent is not supported when you have more than one `purchase_unit` within your order.
     Authorize,
+    /// An intent unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Represents a payer name.
@@ -47,6 +53,75 @@ pub struct PhoneNumber {
     pub national_number: String,
 }
 
+/// The two-digit country calling codes, the others are one digit long for 1 and 7, and three digits long otherwise.
+const TWO_DIGIT_CALLING_CODES: &[&str] = &[
+    "20", "27", "30", "31", "32", "33", "34", "36", "39", "40", "41", "43", "44", "45", "46", "47", "48", "49", "51",
+    "52", "53", "54", "55", "56", "57", "58", "60", "61", "62", "63", "64", "65", "66", "81", "82", "84", "86", "90",
+    "91", "92", "93", "94", "95", "98",
+];
+
+impl PhoneNumber {
+    /// Parses an international phone number, like `+49 151 23456789`, splitting the country calling code from the national number.
+    ///
+    /// Spaces, dashes, dots and parentheses are ignored. The number must start with `+` and have at most 15 digits.
+    ///
+    /// ```
+    /// use paypal_rs::data::orders::PhoneNumber;
+    ///
+    /// let phone = PhoneNumber::parse("+4915123456789").unwrap();
+    /// assert_eq!(phone.country_code.as_deref(), Some("49"));
+    /// assert_eq!(phone.national_number, "15123456789");
+    /// assert_eq!(phone.to_string(), "+4915123456789");
+    /// ```
+    pub fn parse(number: &str) -> Result<Self, InvalidPhoneNumberError> {
+        let invalid = || InvalidPhoneNumberError(number.to_owned());
+
+        let digits: String = number
+            .trim()
+            .strip_prefix('+')
+            .ok_or_else(invalid)?
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+            .collect();
+        if !digits.bytes().all(|b| b.is_ascii_digit()) || digits.len() > 15 || digits.starts_with('0') {
+            return Err(invalid());
+        }
+
+        let code_len = match digits.get(..2) {
+            _ if digits.starts_with('1') || digits.starts_with('7') => 1,
+            Some(prefix) if TWO_DIGIT_CALLING_CODES.contains(&prefix) => 2,
+            _ => 3,
+        };
+        if digits.len() <= code_len {
+            return Err(invalid());
+        }
+
+        let (country_code, national_number) = digits.split_at(code_len);
+        Ok(Self {
+            country_code: Some(country_code.to_owned()),
+            national_number: national_number.to_owned(),
+        })
+    }
+}
+
+impl std::str::FromStr for PhoneNumber {
+    type Err = InvalidPhoneNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl std::fmt::Display for PhoneNumber {
+    /// Formats the number in E.164, like `+4915123456789`, or just the national number without a country code.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.country_code {
+            Some(country_code) => write!(f, "+{}{}", country_code, self.national_number),
+            None => f.write_str(&self.national_number),
+        }
+    }
+}
+
 /// The phone number of the customer. Available only when you enable the
 /// Contact Telephone Number option in the Profile & Settings for the merchant's PayPal account.
 #[skip_serializing_none]
@@ -60,14 +135,57 @@ pub struct Phone {
 }
 
 /// The customer's tax ID type. Supported for the PayPal payment method only.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+///
+/// PayPal introduces the types of other markets over time, use [TaxIdType::Other] for those not listed here.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
 #[allow(non_camel_case_types)]
+#[non_exhaustive]
 pub enum TaxIdType {
     /// The individual tax ID type.
     BR_CPF,
     /// The business tax ID type.
     BR_CNPJ,
+    /// Another tax ID type, as sent to PayPal.
+    Other(String),
+}
+
+impl TaxIdType {
+    /// The tax ID type as sent to PayPal.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TaxIdType::BR_CPF => "BR_CPF",
+            TaxIdType::BR_CNPJ => "BR_CNPJ",
+            TaxIdType::Other(tax_id_type) => tax_id_type,
+        }
+    }
+}
+
+impl From<&str> for TaxIdType {
+    fn from(tax_id_type: &str) -> Self {
+        match tax_id_type {
+            "BR_CPF" => TaxIdType::BR_CPF,
+            "BR_CNPJ" => TaxIdType::BR_CNPJ,
+            tax_id_type => TaxIdType::Other(tax_id_type.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for TaxIdType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for TaxIdType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for TaxIdType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(TaxIdType::from(String::deserialize(deserializer)?.as_str()))
+    }
 }
 
 /// The tax information of the payer.
@@ -127,6 +245,51 @@ pub struct Breakdown {
     pub discount: Option<Money>,
 }
 
+impl Breakdown {
+    /// Computes the item and tax totals of the given items, so they match what PayPal expects.
+    ///
+    /// The other amounts, like shipping, are left empty.
+    pub fn from_items(items: &[Item]) -> Result<Self, InvalidAmountError> {
+        let Some(first) = items.first() else {
+            return Ok(Self::default());
+        };
+        let currency = &first.unit_amount.currency_code;
+
+        let mut item_total = 0;
+        let mut tax_total = None;
+        for item in items {
+            let quantity: i128 = item
+                .quantity
+                .trim()
+                .parse()
+                .ok()
+                .filter(|quantity| *quantity > 0)
+                .ok_or_else(|| InvalidAmountError::InvalidQuantity(item.quantity.clone()))?;
+            item_total += minor_units(&item.unit_amount, currency)? * quantity;
+            if let Some(tax) = &item.tax {
+                *tax_total.get_or_insert(0) += minor_units(tax, currency)? * quantity;
+            }
+        }
+
+        Ok(Self {
+            item_total: Some(Money::from_minor_units(currency.clone(), item_total)),
+            tax_total: tax_total.map(|tax_total| Money::from_minor_units(currency.clone(), tax_total)),
+            ..Default::default()
+        })
+    }
+}
+
+/// Returns the value of the money in minor units, if it is in the given currency.
+fn minor_units(money: &Money, currency: &Currency) -> Result<i128, InvalidAmountError> {
+    if money.currency_code != *currency {
+        return Err(InvalidAmountError::CurrencyMismatch {
+            expected: currency.clone(),
+            found: money.currency_code.clone(),
+        });
+    }
+    Ok(money.to_minor_units()?)
+}
+
 /// Represents an amount of money.
 #[skip_serializing_none]
 #[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
@@ -198,6 +361,7 @@ pub struct PlatformFee {
 
 /// The funds that are held on behalf of the merchant
 #[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum DisbursementMode {
     /// The funds are released to the merchant immediately.
     #[default]
@@ -206,6 +370,9 @@ pub enum DisbursementMode {
     /// You can release the funds through a referenced payout.
     /// Otherwise, the funds disbursed automatically after the specified duration.
     Delayed,
+    /// A mode unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 /// Any additional payment instructions for PayPal Commerce Platform customers.
 #[skip_serializing_none]
@@ -224,7 +391,7 @@ pub struct PaymentInstruction {
 }
 
 /// The item category type.
-#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ItemCategoryType {
     /// Goods that are stored, delivered, and used in their electronic format.
@@ -237,6 +404,9 @@ pub enum ItemCategoryType {
 
     /// A contribution or gift for which no good or service is exchanged, usually to a not for profit organization.
     Donation,
+    /// A category unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 /// The name of the person to whom to ship the items.
 #[skip_serializing_none]
@@ -247,7 +417,7 @@ pub struct ShippingDetailName {
 }
 
 /// Method of purchase fulfillment
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ShippingType {
     /// The payer intends to receive the items at a specified address.
@@ -262,10 +432,15 @@ pub enum ShippingType {
     /// Also termed as BOPIP, "Buy Online, Pick-up in Person".
     /// Seller protection is not available, since the payer is receiving the item from the payee in person, and can validate the item prior to payment.
     PickupFromPerson,
+    /// A type unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The status of the item shipment.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+///
+/// The orders api only uses `CANCELLED` and `SHIPPED`, the rest are used by the shipping api.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TrackerStatus {
     /// The shipment was cancelled and the tracking number no longer applies.
@@ -274,6 +449,27 @@ pub enum TrackerStatus {
     /// This does not correspond to the carrier's actual status for the shipment.
     /// The latest status of the parcel must be retrieved from the carrier.
     Shipped,
+    /// The item was already delivered when the tracking number was uploaded.
+    Delivered,
+    /// Either the buyer physically picked up the item or the seller delivered the item in person without involving any couriers or postal companies.
+    LocalPickup,
+    /// The item is on hold. Its shipment was temporarily stopped due to bad weather conditions or other reasons.
+    OnHold,
+    /// The shipment was created but not yet handed over to the carrier.
+    ShipmentCreated,
+    /// The item was dropped off at the carrier.
+    DroppedOff,
+    /// The item is in transit.
+    InTransit,
+    /// The item was returned.
+    Returned,
+    /// The shipping label was printed.
+    LabelPrinted,
+    /// The delivery failed.
+    DeliveryFailed,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// trackers for a transaction.
@@ -368,7 +564,7 @@ pub struct Item {
 }
 
 /// The status of the payment authorization.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AuthorizationStatus {
     /// The authorized payment is created. No captured payments have been made for this authorized payment.
@@ -387,10 +583,13 @@ pub enum AuthorizationStatus {
     Voided,
     /// The created authorization is in pending state. For more information, see status.details.
     Pending,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Seller Protection Status
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SellerProtectionStatus {
     /// Your PayPal balance remains intact if the customer claims that
@@ -400,6 +599,9 @@ pub enum SellerProtectionStatus {
     PartiallyEligible,
     /// This transaction is not eligible for seller protection.
     NotEligible,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Seller Protection Data
@@ -431,15 +633,356 @@ pub struct NetworkTransactionReference {
     pub network: Option<String>,
 }
 
+/// The address verification (AVS) result of a card payment.
+///
+/// <https://developer.paypal.com/docs/api/orders/v2/#definition-processor_response>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Hash)]
+pub enum AvsCode {
+    /// The address matches, the postal code doesn't.
+    A,
+    /// International: the address matches, the postal code isn't verified.
+    B,
+    /// International: neither the address nor the postal code match.
+    C,
+    /// International: the address and the postal code match.
+    D,
+    /// Not allowed for mail or telephone orders and website transactions.
+    E,
+    /// UK: the address and the postal code match.
+    F,
+    /// Global: address verification is unavailable.
+    G,
+    /// International: address verification is unavailable.
+    I,
+    /// The address and the postal code match.
+    M,
+    /// Neither the address nor the postal code match.
+    N,
+    /// The postal code matches, the address doesn't.
+    P,
+    /// Address verification is unavailable, retry later.
+    R,
+    /// Address verification is not supported by the issuer.
+    S,
+    /// Address verification is unavailable.
+    U,
+    /// The nine-digit ZIP code matches, the address doesn't.
+    W,
+    /// The address and the nine-digit ZIP code match.
+    X,
+    /// The address and the five-digit ZIP code match.
+    Y,
+    /// The five-digit ZIP code matches, the address doesn't.
+    Z,
+    /// No address verification was performed.
+    Null,
+    /// Maestro: all the address information matches.
+    #[serde(rename = "0")]
+    MaestroMatch,
+    /// Maestro: none of the address information matches.
+    #[serde(rename = "1")]
+    MaestroNoMatch,
+    /// Maestro: part of the address information matches.
+    #[serde(rename = "2")]
+    MaestroPartialMatch,
+    /// Maestro: the merchant did not send the address information.
+    #[serde(rename = "3")]
+    MaestroNotProvided,
+    /// Maestro: address verification is unavailable.
+    #[serde(rename = "4")]
+    MaestroUnavailable,
+    /// A code unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The card verification value (CVV) result of a card payment.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Hash)]
+pub enum CvvCode {
+    /// The verification failed with an error.
+    E,
+    /// The CVV is invalid, or wasn't sent.
+    I,
+    /// The CVV matches.
+    M,
+    /// The CVV doesn't match.
+    N,
+    /// The CVV wasn't processed.
+    P,
+    /// CVV verification is not supported.
+    S,
+    /// The issuer can't verify the CVV.
+    U,
+    /// CVV verification is unavailable.
+    X,
+    /// Maestro: the CVV matches.
+    #[serde(rename = "0")]
+    MaestroMatch,
+    /// Maestro: the CVV doesn't match.
+    #[serde(rename = "1")]
+    MaestroNoMatch,
+    /// Maestro: the merchant doesn't support CVV verification.
+    #[serde(rename = "2")]
+    MaestroNotSupported,
+    /// Maestro: the CVV isn't on the card.
+    #[serde(rename = "3")]
+    MaestroNotPresent,
+    /// Maestro: CVV verification is unavailable.
+    #[serde(rename = "4")]
+    MaestroUnavailable,
+    /// A code unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The response code of the processor of a card payment.
+///
+/// Only the most common codes are listed, the others are [ProcessorResponseCode::Unknown].
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Hash)]
+pub enum ProcessorResponseCode {
+    /// Approved.
+    #[serde(rename = "0000")]
+    Approved,
+    /// Referral, the issuer asks the merchant to call.
+    #[serde(rename = "0100")]
+    Referral,
+    /// Account not found.
+    #[serde(rename = "0390")]
+    AccountNotFound,
+    /// Declined by the issuer.
+    #[serde(rename = "0500")]
+    DoNotHonor,
+    /// Unauthorized transaction.
+    #[serde(rename = "0580")]
+    UnauthorizedTransaction,
+    /// Bad response reversal required.
+    #[serde(rename = "0800")]
+    BadResponseReversalRequired,
+    /// Crypto failure.
+    #[serde(rename = "0880")]
+    CryptographicFailure,
+    /// Unacceptable PIN.
+    #[serde(rename = "0890")]
+    UnacceptablePin,
+    /// System malfunction.
+    #[serde(rename = "0960")]
+    SystemMalfunction,
+    /// Partial authorization.
+    #[serde(rename = "1000")]
+    PartialAuthorization,
+    /// Invalid data format.
+    #[serde(rename = "1300")]
+    InvalidDataFormat,
+    /// Invalid amount.
+    #[serde(rename = "1310")]
+    InvalidAmount,
+    /// Invalid transaction card or issuer.
+    #[serde(rename = "1312")]
+    InvalidTransactionCardIssuer,
+    /// Invalid capture date.
+    #[serde(rename = "1317")]
+    InvalidCaptureDate,
+    /// Invalid currency code.
+    #[serde(rename = "1320")]
+    InvalidCurrencyCode,
+    /// Invalid account.
+    #[serde(rename = "1330")]
+    InvalidAccount,
+    /// Invalid account recurring.
+    #[serde(rename = "1335")]
+    InvalidAccountRecurring,
+    /// Invalid terminal.
+    #[serde(rename = "1340")]
+    InvalidTerminal,
+    /// Invalid merchant.
+    #[serde(rename = "1350")]
+    InvalidMerchant,
+    /// Bad processing code.
+    #[serde(rename = "1360")]
+    BadProcessingCode,
+    /// Invalid merchant category code.
+    #[serde(rename = "1370")]
+    InvalidMcc,
+    /// Invalid expiration date.
+    #[serde(rename = "1380")]
+    InvalidExpiration,
+    /// Invalid card verification value.
+    #[serde(rename = "1382")]
+    InvalidCardVerificationValue,
+    /// Invalid life cycle of the transaction.
+    #[serde(rename = "1384")]
+    InvalidLifeCycleOfTransaction,
+    /// Invalid order.
+    #[serde(rename = "1390")]
+    InvalidOrder,
+    /// The transaction cannot be completed.
+    #[serde(rename = "1393")]
+    TransactionCannotBeCompleted,
+    /// Generic decline.
+    #[serde(rename = "5100")]
+    GenericDecline,
+    /// The CVV2 check failed.
+    #[serde(rename = "5110")]
+    Cvv2Failure,
+    /// Insufficient funds.
+    #[serde(rename = "5120")]
+    InsufficientFunds,
+    /// Invalid PIN.
+    #[serde(rename = "5130")]
+    InvalidPin,
+    /// Declined, contact the issuer.
+    #[serde(rename = "5135")]
+    DeclinedContactIssuer,
+    /// The card is closed.
+    #[serde(rename = "5140")]
+    CardClosed,
+    /// Pickup card, special conditions.
+    #[serde(rename = "5150")]
+    PickupCardSpecialConditions,
+    /// The account is restricted to this merchant.
+    #[serde(rename = "5160")]
+    UnauthorizedUser,
+    /// The address verification failed.
+    #[serde(rename = "5170")]
+    AvsFailure,
+    /// Invalid or restricted card.
+    #[serde(rename = "5180")]
+    InvalidOrRestrictedCard,
+    /// Soft address verification decline.
+    #[serde(rename = "5190")]
+    SoftAvs,
+    /// The payment was already processed.
+    #[serde(rename = "5200")]
+    DuplicateTransaction,
+    /// Invalid transaction.
+    #[serde(rename = "5210")]
+    InvalidTransaction,
+    /// The card is expired.
+    #[serde(rename = "5400")]
+    ExpiredCard,
+    /// Incorrect PIN, re-enter it.
+    #[serde(rename = "5500")]
+    IncorrectPinReenter,
+    /// Declined, strong customer authentication is required.
+    #[serde(rename = "5650")]
+    DeclinedScaRequired,
+    /// Transaction not permitted.
+    #[serde(rename = "5700")]
+    TransactionNotPermitted,
+    /// Too many transaction attempts.
+    #[serde(rename = "5710")]
+    TxAttemptsExceedLimit,
+    /// Reversal rejected.
+    #[serde(rename = "5800")]
+    ReversalRejected,
+    /// Invalid issue.
+    #[serde(rename = "5900")]
+    InvalidIssue,
+    /// The issuer is not available.
+    #[serde(rename = "5910")]
+    IssuerNotAvailableNotRetriable,
+    /// The issuer is not available, retry later.
+    #[serde(rename = "5920")]
+    IssuerNotAvailableRetriable,
+    /// The card was not activated.
+    #[serde(rename = "5930")]
+    CardNotActivated,
+    /// Declined due to an updated account.
+    #[serde(rename = "5950")]
+    DeclinedDueToUpdatedAccount,
+    /// The account is not on file.
+    #[serde(rename = "6300")]
+    AccountNotOnFile,
+    /// Invalid account number.
+    #[serde(rename = "7600")]
+    InvalidAccountNumber,
+    /// Error processing the transaction.
+    #[serde(rename = "7700")]
+    ErrorProcessing,
+    /// Lost or stolen card.
+    #[serde(rename = "7800")]
+    LostOrStolen,
+    /// Declined, retry the payment.
+    #[serde(rename = "9100")]
+    DeclinedPleaseRetry,
+    /// Suspected fraud.
+    #[serde(rename = "9500")]
+    SuspectedFraud,
+    /// Security violation.
+    #[serde(rename = "9510")]
+    SecurityViolation,
+    /// Lost or stolen card.
+    #[serde(rename = "9520")]
+    LostOrStolenCard,
+    /// Held, call the issuer.
+    #[serde(rename = "9530")]
+    HoldCallCenter,
+    /// Refused card.
+    #[serde(rename = "9540")]
+    RefusedCard,
+    /// Unrecognized response code.
+    #[serde(rename = "9600")]
+    UnrecognizedResponseCode,
+    /// The card verification number (CVV) doesn't match.
+    #[serde(rename = "PCVV")]
+    CvvFailure,
+    /// The card is expired.
+    #[serde(rename = "PPEX")]
+    ExpiryDate,
+    /// Declined by PayPal for this merchant.
+    #[serde(rename = "PPMD")]
+    PpMd,
+    /// Retry the request.
+    #[serde(rename = "PPRR")]
+    ReattemptRequest,
+    /// A code unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Advice on retrying a declined card payment, mostly for Mastercard.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Hash)]
+pub enum PaymentAdviceCode {
+    /// Get the new account information before retrying.
+    #[serde(rename = "01")]
+    NewAccountInformation,
+    /// Retry later.
+    #[serde(rename = "02")]
+    TryAgainLater,
+    /// Don't retry the payment.
+    #[serde(rename = "03")]
+    DoNotTryAgain,
+    /// The payer cancelled the recurring payments, don't retry them.
+    #[serde(rename = "21")]
+    RecurringPaymentCancelled,
+    /// A code unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The processor information of a card payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ProcessorResponse {
+    /// The address verification result.
+    pub avs_code: Option<AvsCode>,
+    /// The card verification value result.
+    pub cvv_code: Option<CvvCode>,
+    /// The response code of the processor.
+    pub response_code: Option<ProcessorResponseCode>,
+    /// The advice on retrying a declined payment.
+    pub payment_advice_code: Option<PaymentAdviceCode>,
+}
+
 /// A payment authorization.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct AuthorizationWithData {
     /// The status for the authorized payment.
     pub status: AuthorizationStatus,
     /// The details of the authorized order pending status.
-    pub status_details: AuthorizationStatusDetails,
+    pub status_details: Option<AuthorizationStatusDetails>,
     /// The PayPal-generated ID for the authorized payment.
-    pub id: Option<String>,
+    pub id: Option<AuthorizationId>,
     /// The API caller-provided external invoice number for this order.
     /// Appears in both the payer's transaction history and the emails that the payer receives.
     pub invoice_id: Option<String>,
@@ -460,11 +1003,11 @@ pub struct AuthorizationWithData {
     /// The date and time when the transaction was last updated, in Internet date and time format.
     pub update_time: Option<chrono::DateTime<chrono::Utc>>,
     ///The processor response information for payment requests, such as direct credit card transactions.
-    pub processor_response: Option<serde_json::Value>,
+    pub processor_response: Option<ProcessorResponse>,
 }
 
 /// The capture status.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CaptureStatus {
     /// The funds for this captured payment were credited to the payee's PayPal account.
@@ -477,10 +1020,13 @@ pub enum CaptureStatus {
     Pending,
     /// An amount greater than or equal to this captured payment's amount was refunded to the payer.
     Refunded,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Capture status reason.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CaptureStatusDetailsReason {
     /// The payer initiated a dispute for this captured payment with PayPal.
@@ -509,10 +1055,15 @@ pub enum CaptureStatusDetailsReason {
     Unilateral,
     /// The payee's PayPal account is not verified.
     VerificationRequired,
+    /// Risk Filter set by the payee failed for the transaction.
+    DeclinedByRiskFraudFilters,
+    /// A reason unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Details about the captured payment status.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct CaptureStatusDetails {
     /// The reason why the captured payment status is PENDING or DENIED.
     pub reason: CaptureStatusDetailsReason,
@@ -531,6 +1082,7 @@ pub struct ExchangeRateDetail {
 }
 
 /// Seller Receivable Breakdown
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SellerReceivableBreakdown {
     /// An array of platform or partner fees, commissions, or brokerage fees that associated with the captured payment.
@@ -564,7 +1116,7 @@ pub struct Capture {
     /// The date and time when the transaction was last updated, in Internet date and time format.
     pub update_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The PayPal-generated ID for the captured payment.
-    pub id: Option<String>,
+    pub id: Option<CaptureId>,
     /// The API caller-provided external invoice number for this order.
     /// Appears in both the payer's transaction history and the emails that the payer receives.
     pub invoice_id: Option<String>,
@@ -593,11 +1145,11 @@ pub struct Capture {
     /// The funds that are held on behalf of the merchant.
     pub disbursement_mode: Option<DisbursementMode>,
     ///An object that provides additional processor information for a direct credit card transaction.
-    pub processor_response: Option<serde_json::Value>,
+    pub processor_response: Option<ProcessorResponse>,
 }
 
 /// The status of the refund
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RefundStatus {
     /// The refund was cancelled.
@@ -608,18 +1160,24 @@ pub enum RefundStatus {
     Completed,
     /// The refund could not be processed.
     Failed,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Refund status reason.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RefundStatusDetailsReason {
     /// The customer's account is funded through an eCheck, which has not yet cleared.
     Echeck,
+    /// A reason unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Details about the status of the refund.
-#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct RefundStatusDetails {
     /// The reason why the refund has the PENDING or FAILED status.
     pub reason: RefundStatusDetailsReason,
@@ -648,6 +1206,7 @@ pub struct NetAmountBreakdown {
 }
 
 /// The breakdown of the refund.
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[builder(setter(strip_option))]
 pub struct SellerPayableBreakdown {
@@ -670,6 +1229,7 @@ pub struct SellerPayableBreakdown {
 }
 
 /// A refund
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[builder(setter(strip_option))]
 pub struct Refund {
@@ -678,7 +1238,7 @@ pub struct Refund {
     /// The details of the refund status.
     pub status_details: Option<RefundStatusDetails>,
     /// The PayPal-generated ID for the refund.
-    pub id: String,
+    pub id: RefundId,
     /// The API caller-provided external invoice number for this order. Appears in both the payer's transaction history and the emails that the payer receives.
     pub invoice_id: Option<String>,
     /// The API caller-provided external ID. Used to reconcile API caller-initiated transactions with PayPal transactions. Appears in transaction and settlement reports.
@@ -707,13 +1267,13 @@ pub struct Refund {
 #[builder(setter(strip_option))]
 pub struct PaymentCollection {
     /// An array of authorized payments for a purchase unit. A purchase unit can have zero or more authorized payments.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub authorizations: Vec<AuthorizationWithData>,
     /// An array of captured payments for a purchase unit. A purchase unit can have zero or more captured payments.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub captures: Vec<Capture>,
     /// An array of refunds for a purchase unit. A purchase unit can have zero or more refunds.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub refunds: Vec<Refund>,
 }
 /// Supplementary customer struct.
@@ -732,16 +1292,27 @@ pub struct SupplementaryRisk {
     pub customer: Option<SupplementaryCustomer>,
 }
 
+/// The level 2 card processing data, used by corporate and purchasing cards to qualify for lower interchange rates.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct Level2CardProcessingData {
+    /// The merchant-provided invoice number, up to 127 characters.
+    pub invoice_id: Option<String>,
+    /// The total tax amount of the transaction, must not exceed the transaction amount.
+    pub tax_total: Option<Money>,
+}
+
 /// Supplementary data about this payment.
 #[skip_serializing_none]
 #[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
 pub struct SupplementaryData {
-    // todo: add support for card 2 and card 3
+    // todo: add support for card 3
     /// The level 2 card processing data collections.
     /// If your merchant account has been configured for
     /// Level 2 processing this field will be passed to the processor on your behalf.
     /// Please contact your PayPal Technical Account Manager to define level 2 data for your business.
-    pub level_2: Option<serde_json::Value>,
+    pub level_2: Option<Level2CardProcessingData>,
     /// The level 3 card processing data collections,
     /// If your merchant account has been configured for
     /// Level 3 processing this field will be passed to the processor on your behalf.
@@ -806,6 +1377,75 @@ impl PurchaseUnit {
             ..Default::default()
         }
     }
+
+    /// Checks that the amount equals the sum of its breakdown, and that the item and tax totals match the items,
+    /// which PayPal would otherwise reject.
+    ///
+    /// ```
+    /// use paypal_rs::data::common::Money;
+    /// use paypal_rs::data::orders::{Amount, Breakdown, Item, PurchaseUnit};
+    ///
+    /// let items = vec![Item {
+    ///     name: "Socks".to_string(),
+    ///     quantity: "2".to_string(),
+    ///     unit_amount: Money::usd("4.50"),
+    ///     ..Default::default()
+    /// }];
+    /// let mut breakdown = Breakdown::from_items(&items).unwrap();
+    /// breakdown.shipping = Some(Money::usd("1"));
+    ///
+    /// let mut unit = PurchaseUnit::new(Amount::usd("10.00"));
+    /// unit.amount.breakdown = Some(breakdown);
+    /// unit.items = Some(items);
+    /// assert!(unit.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), InvalidAmountError> {
+        let currency = &self.amount.currency_code;
+        let amount = Money {
+            currency_code: currency.clone(),
+            value: self.amount.value.clone(),
+        };
+        let breakdown = self.amount.breakdown.clone().unwrap_or_default();
+        let total = |money: &Option<Money>| money.as_ref().map_or(Ok(0), |money| minor_units(money, currency));
+        let check = |field, expected: i128, found: i128| {
+            if expected == found {
+                Ok(())
+            } else {
+                Err(InvalidAmountError::Mismatch {
+                    field,
+                    expected: Money::from_minor_units(currency.clone(), expected),
+                    found: Money::from_minor_units(currency.clone(), found),
+                })
+            }
+        };
+
+        let amount = amount.to_minor_units()?;
+        if self.amount.breakdown.is_some() {
+            let sum = total(&breakdown.item_total)?
+                + total(&breakdown.tax_total)?
+                + total(&breakdown.shipping)?
+                + total(&breakdown.handling)?
+                + total(&breakdown.insurance)?
+                - total(&breakdown.shipping_discount)?
+                - total(&breakdown.discount)?;
+            check("amount", sum, amount)?;
+        }
+
+        if let Some(items) = &self.items
+            && !items.is_empty()
+        {
+            let expected = Breakdown::from_items(items)?;
+            check(
+                "item_total",
+                total(&expected.item_total)?,
+                total(&breakdown.item_total)?,
+            )?;
+            if expected.tax_total.is_some() {
+                check("tax_total", total(&expected.tax_total)?, total(&breakdown.tax_total)?)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// The type of landing page to show on the PayPal site for customer checkout.
@@ -899,19 +1539,276 @@ pub struct ApplicationContext {
     pub cancel_url: Option<String>,
 }
 
+/// The Electronic Commerce Indicator of a network token payment, the outcome of its 3D Secure authentication.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EciFlag {
+    /// Mastercard payment without 3D Secure authentication.
+    #[serde(rename = "MASTERCARD_NON_3D_SECURE_TRANSACTION")]
+    MastercardNon3dSecureTransaction,
+    /// Payment without 3D Secure authentication.
+    #[serde(rename = "NON_3D_SECURE_TRANSACTION")]
+    Non3dSecureTransaction,
+    /// Payment with a successful 3D Secure authentication.
+    FullyAuthenticatedTransaction,
+    /// Payment with an attempted 3D Secure authentication.
+    AttemptedAuthenticationTransaction,
+    /// A flag unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A network token, which replaces the card number with a token issued by the card network.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct NetworkToken {
+    /// The network token number.
+    pub number: String,
+    /// The network token expiration year and month, in `YYYY-MM` format.
+    pub expiry: String,
+    /// The single use cryptogram generated by the card network for this payment.
+    pub cryptogram: Option<String>,
+    /// The Electronic Commerce Indicator, when the payment was authenticated.
+    pub eci_flag: Option<EciFlag>,
+    /// The ID of the token requestor assigned by the card network.
+    pub token_requestor_id: Option<String>,
+}
+
 /// A card used in payment sources.
+///
+/// Either the card details or the `vault_id` of a saved card must be set.
 #[skip_serializing_none]
 #[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
-#[builder(setter(into))]
+#[builder(setter(strip_option, into), default)]
 pub struct PaymentCard {
     /// The card number.
-    pub number: String,
+    pub number: Option<String>,
     /// The expiry date.
-    pub expiry: String,
+    pub expiry: Option<String>,
+    /// The three- or four-digit security code of the card, verified with the payment.
+    pub security_code: Option<String>,
     /// The card owner name.
-    pub name: String,
+    pub name: Option<String>,
     /// The billing address.
-    pub billing_address: Address,
+    pub billing_address: Option<Address>,
+    /// The ID of a saved card, to charge it without the card details.
+    pub vault_id: Option<String>,
+    /// The attributes of the card, for example to save it in the vault or to verify it with 3D Secure.
+    pub attributes: Option<PaymentSourceAttributes>,
+    /// The stored credential, for payments with a card saved by the merchant.
+    pub stored_credential: Option<StoredCredential>,
+    /// The network token replacing the card number.
+    pub network_token: Option<NetworkToken>,
+    /// The customization of the 3D Secure verification experience.
+    pub experience_context: Option<CardExperienceContext>,
+}
+
+/// A PayPal wallet used in payment sources.
+///
+/// Set the `vault_id` of a saved wallet to charge it without the payer being present.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PaypalPaymentSource {
+    /// The ID of a saved PayPal wallet.
+    pub vault_id: Option<String>,
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The customization of the payer experience.
+    pub experience_context: Option<WalletExperienceContext>,
+    /// The attributes of the wallet, for example to save it in the vault.
+    pub attributes: Option<PaymentSourceAttributes>,
+}
+
+/// The kind of cryptogram in the decrypted Apple Pay token.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub enum ApplePayPaymentDataType {
+    /// A 3D Secure cryptogram, the most common.
+    #[serde(rename = "3DSECURE")]
+    ThreeDSecure,
+    /// EMV payment data, for China UnionPay cards.
+    #[serde(rename = "EMV")]
+    Emv,
+    /// A payment data type unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The card of a decrypted Apple Pay token.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ApplePayTokenizedCard {
+    /// The card holder's name as it appears on the card.
+    pub name: Option<String>,
+    /// The device account number of the card.
+    pub number: Option<String>,
+    /// The card expiration year and month, in `YYYY-MM` format.
+    pub expiry: Option<String>,
+    /// The card brand or network.
+    pub card_type: Option<CardBrand>,
+    /// The payment card type.
+    #[serde(rename = "type")]
+    pub kind: Option<CardType>,
+    /// The billing address of the card.
+    pub billing_address: Option<Address>,
+}
+
+/// The cryptogram of a decrypted Apple Pay token.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ApplePayPaymentData {
+    /// The online payment cryptogram, for 3D Secure payment data.
+    pub cryptogram: Option<String>,
+    /// The ECI indicator, for 3D Secure payment data.
+    pub eci_indicator: Option<String>,
+    /// The encoded EMV payment structure, for EMV payment data.
+    pub emv_data: Option<String>,
+    /// The encrypted PIN of the card, for EMV payment data.
+    pub pin: Option<String>,
+}
+
+/// An Apple Pay token decrypted by the merchant.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct ApplePayDecryptedToken {
+    /// The amount of the transaction the token was created for.
+    #[builder(default)]
+    pub transaction_amount: Option<Money>,
+    /// The card of the token.
+    pub tokenized_card: ApplePayTokenizedCard,
+    /// The identifier of the device manufacturer.
+    #[builder(default)]
+    pub device_manufacturer_id: Option<String>,
+    /// The kind of cryptogram of the token.
+    #[builder(default)]
+    pub payment_data_type: Option<ApplePayPaymentDataType>,
+    /// The cryptogram of the token.
+    #[builder(default)]
+    pub payment_data: Option<ApplePayPaymentData>,
+}
+
+/// An Apple Pay wallet used in payment sources.
+///
+/// Either the `decrypted_token`, or the `vault_id` of a saved wallet must be set,
+/// unless the token is sent encrypted by the PayPal JS SDK.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ApplePayRequest {
+    /// The PayPal-generated ID for the Apple Pay payment.
+    pub id: Option<String>,
+    /// The name of the payer.
+    pub name: Option<String>,
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The phone number of the payer.
+    pub phone_number: Option<PhoneNumber>,
+    /// The token decrypted by the merchant.
+    pub decrypted_token: Option<ApplePayDecryptedToken>,
+    /// The stored credential, for merchant initiated payments.
+    pub stored_credential: Option<StoredCredential>,
+    /// The ID of a saved Apple Pay wallet.
+    pub vault_id: Option<String>,
+    /// The attributes of the wallet, for example to save it in the vault.
+    pub attributes: Option<PaymentSourceAttributes>,
+}
+
+/// How the Google Pay payer was authenticated.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GooglePayAuthenticationMethod {
+    /// The card number stored in the Google account, which requires a 3D Secure authentication.
+    PanOnly,
+    /// A device token with a 3D Secure cryptogram.
+    #[serde(rename = "CRYPTOGRAM_3DS")]
+    Cryptogram3ds,
+    /// An authentication method unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The card of a Google Pay payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct GooglePayCard {
+    /// The card holder's name as it appears on the card.
+    pub name: Option<String>,
+    /// The card number or device token, only in a decrypted token.
+    pub number: Option<String>,
+    /// The card expiration year and month, in `YYYY-MM` format, only in a decrypted token.
+    pub expiry: Option<String>,
+    /// The last digits of the card number.
+    pub last_digits: Option<String>,
+    /// The payment card type.
+    #[serde(rename = "type")]
+    pub card_type: Option<CardType>,
+    /// The card brand or network.
+    pub brand: Option<CardBrand>,
+    /// The billing address of the card.
+    pub billing_address: Option<Address>,
+}
+
+/// A Google Pay token decrypted by the merchant.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct GooglePayDecryptedToken {
+    /// The unique identifier of the message.
+    #[builder(default)]
+    pub message_id: Option<String>,
+    /// The expiration of the message, in milliseconds since epoch.
+    #[builder(default)]
+    pub message_expiration: Option<String>,
+    /// The payment method, `CARD`.
+    #[builder(default = "\"CARD\".to_string()")]
+    pub payment_method: String,
+    /// The card of the token.
+    pub card: GooglePayCard,
+    /// How the payer was authenticated.
+    pub authentication_method: GooglePayAuthenticationMethod,
+    /// The 3D Secure cryptogram, for [GooglePayAuthenticationMethod::Cryptogram3ds].
+    #[builder(default)]
+    pub cryptogram: Option<String>,
+    /// The ECI indicator, for [GooglePayAuthenticationMethod::Cryptogram3ds].
+    #[builder(default)]
+    pub eci_indicator: Option<String>,
+}
+
+/// The verifications Google made on the payer and the card.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct GooglePayAssuranceDetails {
+    /// Whether Google verified the possession of the card.
+    pub account_verified: Option<bool>,
+    /// Whether Google authenticated the card holder.
+    pub card_holder_authenticated: Option<bool>,
+}
+
+/// A Google Pay wallet used in payment sources.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct GooglePayRequest {
+    /// The name of the payer.
+    pub name: Option<String>,
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The phone number of the payer.
+    pub phone_number: Option<PhoneNumber>,
+    /// The card, when the token is sent encrypted by the PayPal JS SDK.
+    pub card: Option<GooglePayCard>,
+    /// The token decrypted by the merchant.
+    pub decrypted_token: Option<GooglePayDecryptedToken>,
+    /// The verifications Google made, sent along with a decrypted token.
+    pub assurance_details: Option<GooglePayAssuranceDetails>,
+    /// The attributes of the wallet, for example to save it in the vault.
+    pub attributes: Option<PaymentSourceAttributes>,
 }
 
 /// A transaction reference.
@@ -925,17 +1822,63 @@ pub struct TransactionReference {
     pub network: String,
 }
 
+/// Who initiates a payment with a stored credential.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentInitiator {
+    /// The payer initiates the payment, while present.
+    #[default]
+    Customer,
+    /// The merchant initiates the payment, without the payer being present.
+    Merchant,
+    /// An initiator unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The schedule of the payments with a stored credential.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StoredPaymentType {
+    /// A single payment.
+    #[default]
+    OneTime,
+    /// One of a series of payments at fixed intervals, like a subscription.
+    Recurring,
+    /// A payment which isn't on a fixed schedule, like a top-up.
+    Unscheduled,
+    /// A type unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Whether the stored credential is used for the first time.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StoredCredentialUsage {
+    /// PayPal derives the usage from the previous payments.
+    #[default]
+    Derived,
+    /// The credential is stored with this payment.
+    First,
+    /// The credential was stored by a previous payment.
+    Subsequent,
+    /// A usage unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
 /// A stored credential.
 #[skip_serializing_none]
 #[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
 #[builder(setter(into))]
 pub struct StoredCredential {
-    /// The payment initiator, e.g "MERCHANT"
-    pub payment_initiator: String,
-    /// The payment type, e.g "RECURRING"
-    pub payment_type: String,
-    /// The stored credential usage, e.g: SUBSEQUENT
-    pub usage: String,
+    /// The payment initiator.
+    pub payment_initiator: PaymentInitiator,
+    /// The payment type.
+    pub payment_type: StoredPaymentType,
+    /// The stored credential usage.
+    pub usage: StoredCredentialUsage,
     /// The billing address.
     pub previous_network_transaction_reference: TransactionReference,
 }
@@ -948,7 +1891,17 @@ pub struct StoredCredential {
 #[builder(setter(strip_option))]
 pub struct OrderPaymentSource {
     /// The card used in the payment.
-    pub card: PaymentCard,
+    #[builder(default)]
+    pub card: Option<PaymentCard>,
+    /// The PayPal wallet used in the payment.
+    #[builder(default)]
+    pub paypal: Option<PaypalPaymentSource>,
+    /// The Apple Pay wallet used in the payment.
+    #[builder(default)]
+    pub apple_pay: Option<ApplePayRequest>,
+    /// The Google Pay wallet used in the payment.
+    #[builder(default)]
+    pub google_pay: Option<GooglePayRequest>,
     /// A stored credential.
     // TODO: figure out what is this.
     #[builder(default)]
@@ -977,7 +1930,7 @@ pub struct OrderPayload {
 }
 
 /// The card brand or network.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CardBrand {
     /// Visa card.
@@ -1012,16 +1965,36 @@ pub enum CardBrand {
     Cetelem,
     /// China union pay credit card.
     ChinaUnionPay,
+    /// Diners Club card.
+    Diners,
+    /// Elo card.
+    Elo,
+    /// Hiper card.
+    Hiper,
+    /// Hipercard card.
+    Hipercard,
+    /// RuPay card.
+    Rupay,
+    /// GE card.
+    Ge,
+    /// Synchrony card.
+    Synchrony,
+    /// eftpos card.
+    Eftpos,
+    /// The card brand is unknown, or unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[allow(missing_docs)]
 pub enum CardType {
     Credit,
     Debit,
     Prepaid,
-    Unknown,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The payment card to use to fund a payment.
@@ -1054,7 +2027,237 @@ pub struct PaypalPaymentSourceResponse {
     pub account_id: String,
 }
 
+/// The attributes of the payment source used, like the outcome of saving it in the vault.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct PaymentSourceResponseAttributes {
+    /// The outcome of saving the payment method in the vault, when it was requested.
+    pub vault: Option<PaymentSourceVault>,
+}
+
+/// The details of the bank identification number (BIN) of a card.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct BinDetails {
+    /// The bank identification number, the first six to eight digits of the card number.
+    pub bin: Option<String>,
+    /// The issuer of the card.
+    pub issuing_bank: Option<String>,
+    /// The two-character ISO 3166-1 code of the country of the issuer.
+    pub bin_country_code: Option<String>,
+    /// The card products of the issuer for this BIN.
+    #[serde(default)]
+    pub products: Vec<String>,
+}
+
+/// The card details as sent in the request, to compare them with the ones PayPal resolved.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct CardFromRequest {
+    /// The last digits of the card number sent.
+    pub last_digits: Option<String>,
+    /// The expiration year and month sent, in `YYYY-MM` format.
+    pub expiry: Option<String>,
+}
+
+/// The card used to fund the payment.
+///
+/// <https://developer.paypal.com/docs/api/orders/v2/#definition-card_response>
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct CardResponseDetailed {
+    /// The card holder's name as it appears on the card.
+    pub name: Option<String>,
+    /// The last digits of the card number.
+    pub last_digits: Option<String>,
+    /// The card brand or network.
+    pub brand: Option<CardBrand>,
+    /// The payment card type.
+    #[serde(rename = "type")]
+    pub card_type: Option<CardType>,
+    /// The card expiration year and month, in `YYYY-MM` format.
+    pub expiry: Option<String>,
+    /// The networks the card can be processed with, for co-branded cards.
+    #[serde(default)]
+    pub available_networks: Vec<CardBrand>,
+    /// The details of the bank identification number of the card.
+    pub bin_details: Option<BinDetails>,
+    /// The result of the authentication of the card, like 3D Secure.
+    pub authentication_result: Option<AuthenticationResult>,
+    /// The billing address of the card.
+    pub billing_address: Option<Address>,
+    /// The card details as sent in the request.
+    pub from_request: Option<CardFromRequest>,
+    /// The attributes of the card, like the outcome of saving it in the vault.
+    pub attributes: Option<PaymentSourceResponseAttributes>,
+}
+
+/// Whether the PayPal account of the payer is verified.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalAccountStatus {
+    /// The account is verified.
+    Verified,
+    /// The account is not verified.
+    Unverified,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The PayPal wallet used to fund the payment.
+///
+/// <https://developer.paypal.com/docs/api/orders/v2/#definition-paypal_wallet_response>
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct PaypalWalletResponse {
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The PayPal-assigned ID for the PayPal account holder.
+    pub account_id: Option<String>,
+    /// Whether the PayPal account of the payer is verified.
+    pub account_status: Option<PaypalAccountStatus>,
+    /// The name of the payer.
+    pub name: Option<PayerName>,
+    /// The phone type, available only with the Contact Telephone Number option of the merchant's profile.
+    pub phone_type: Option<PhoneType>,
+    /// The phone number of the payer.
+    pub phone_number: Option<PhoneNumber>,
+    /// The birth date of the payer in YYYY-MM-DD format.
+    pub birth_date: Option<String>,
+    /// The tax information of the payer, only for Brazilian payers.
+    pub tax_info: Option<TaxInfo>,
+    /// The address of the payer.
+    pub address: Option<Address>,
+    /// The attributes of the wallet, like the outcome of saving it in the vault.
+    pub attributes: Option<PaymentSourceResponseAttributes>,
+}
+
+/// The Venmo wallet used to fund the payment.
+///
+/// <https://developer.paypal.com/docs/api/orders/v2/#definition-venmo_wallet_response>
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct VenmoResponse {
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The Venmo-assigned ID for the Venmo account holder.
+    pub account_id: Option<String>,
+    /// The Venmo username, as chosen by the user.
+    pub user_name: Option<String>,
+    /// The name of the payer.
+    pub name: Option<PayerName>,
+    /// The phone number of the payer.
+    pub phone_number: Option<PhoneNumber>,
+    /// The address of the payer.
+    pub address: Option<Address>,
+    /// The attributes of the wallet, like the outcome of saving it in the vault.
+    pub attributes: Option<PaymentSourceResponseAttributes>,
+}
+
+/// The Apple Pay wallet used to fund the payment.
+///
+/// <https://developer.paypal.com/docs/api/orders/v2/#definition-apple_pay>
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct ApplePayResponse {
+    /// The PayPal-generated ID for the Apple Pay payment.
+    pub id: Option<String>,
+    /// The Apple Pay token.
+    pub token: Option<String>,
+    /// The name of the payer.
+    pub name: Option<String>,
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The phone number of the payer.
+    pub phone_number: Option<PhoneNumber>,
+    /// The card of the wallet.
+    pub card: Option<CardResponseDetailed>,
+    /// The attributes of the wallet, like the outcome of saving it in the vault.
+    pub attributes: Option<PaymentSourceResponseAttributes>,
+}
+
+/// The Google Pay wallet used to fund the payment.
+///
+/// <https://developer.paypal.com/docs/api/orders/v2/#definition-google_pay_wallet_response>
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct GooglePayResponse {
+    /// The name of the payer.
+    pub name: Option<String>,
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The phone number of the payer.
+    pub phone_number: Option<PhoneNumber>,
+    /// The card of the wallet.
+    pub card: Option<CardResponseDetailed>,
+}
+
+/// The details shared by the alternative payment methods, like iDEAL or Sofort.
+///
+/// Each method only sets some of them, for example eps and giropay have no IBAN.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct ApmDetails {
+    /// The name of the account holder.
+    pub name: Option<String>,
+    /// The two-character ISO 3166-1 code of the country of the account.
+    pub country_code: Option<String>,
+    /// The business identification code (BIC) of the bank of the account.
+    pub bic: Option<String>,
+    /// The last characters of the IBAN of the account.
+    pub iban_last_chars: Option<String>,
+    /// The email address of the account holder.
+    pub email: Option<String>,
+}
+
+/// The Bancontact account used to fund the payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct BancontactResponse {
+    /// The account details.
+    #[serde(flatten)]
+    pub details: ApmDetails,
+    /// The last digits of the Bancontact card.
+    pub card_last_digits: Option<String>,
+}
+
+/// The one click details of a BLIK payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct BlikOneClickResponse {
+    /// The merchant generated identifier of the consumer, used for their next one click payments.
+    pub consumer_reference: Option<String>,
+}
+
+/// The BLIK account used to fund the payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct BlikResponse {
+    /// The account details.
+    #[serde(flatten)]
+    pub details: ApmDetails,
+    /// The one click details, when the payer enabled it.
+    pub one_click: Option<BlikOneClickResponse>,
+}
+
+/// The Przelewy24 account used to fund the payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct P24Response {
+    /// The account details.
+    #[serde(flatten)]
+    pub details: ApmDetails,
+    /// The P24 generated payment description.
+    pub payment_descriptor: Option<String>,
+    /// The numeric identifier of the payment scheme or bank used.
+    pub method_id: Option<String>,
+    /// The name of the payment scheme or bank used.
+    pub method_description: Option<String>,
+}
+
 /// The payment source used to fund the payment.
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Builder, Default, Clone)]
 #[builder(setter(strip_option), default)]
 pub struct PaymentSourceResponse {
@@ -1065,50 +2268,201 @@ pub struct PaymentSourceResponse {
     // /// The paypal account used to fund the transaction.
     // pub paypal: Option<PaypalPaymentSourceResponse>,
     /// The payment card to use to fund a payment. Card can be a credit or debit card.
-    pub card: Option<serde_json::Value>,
+    pub card: Option<CardResponseDetailed>,
 
     /// Information used to pay Bancontact.
-    pub bancontact: Option<serde_json::Value>,
+    pub bancontact: Option<BancontactResponse>,
 
     /// Information used to pay using BLIK.
-    pub blik: Option<serde_json::Value>,
+    pub blik: Option<BlikResponse>,
 
     /// Information used to pay using eps.
-    pub eps: Option<serde_json::Value>,
+    pub eps: Option<ApmDetails>,
 
     /// Information needed to pay using giropay.
-    pub giropay: Option<serde_json::Value>,
+    pub giropay: Option<ApmDetails>,
 
     /// Information used to pay using iDEAL.
-    pub ideal: Option<serde_json::Value>,
+    pub ideal: Option<ApmDetails>,
 
     /// Information used to pay using MyBank.
-    pub mybank: Option<serde_json::Value>,
+    pub mybank: Option<ApmDetails>,
 
     /// Information used to pay using P24(Przelewy24).
-    pub p24: Option<serde_json::Value>,
+    pub p24: Option<P24Response>,
 
     /// Information used to pay using Sofort.
-    pub sofort: Option<serde_json::Value>,
+    pub sofort: Option<ApmDetails>,
 
     /// Information needed to pay using Trustly.
-    pub trustly: Option<serde_json::Value>,
+    pub trustly: Option<ApmDetails>,
 
     /// Venmo wallet response.
-    pub venmo: Option<serde_json::Value>,
+    pub venmo: Option<VenmoResponse>,
 
     /// The PayPal Wallet response.
-    pub paypal: Option<serde_json::Value>,
+    pub paypal: Option<PaypalWalletResponse>,
 
     /// Information needed to pay using ApplePay.
-    pub apple_pay: Option<serde_json::Value>,
+    pub apple_pay: Option<ApplePayResponse>,
 
     /// Google Pay Wallet payment data.
-    pub google_pay: Option<serde_json::Value>,
+    pub google_pay: Option<GooglePayResponse>,
+}
+
+impl PaymentSourceResponse {
+    /// Returns the outcome of saving the payment method in the vault, when it was requested in the order.
+    ///
+    /// The vault details are found in the `attributes.vault` of the payment source used.
+    pub fn vault(&self) -> Option<PaymentSourceVault> {
+        let card = self.card.as_ref().and_then(|card| card.attributes.as_ref());
+        let paypal = self.paypal.as_ref().and_then(|paypal| paypal.attributes.as_ref());
+        let venmo = self.venmo.as_ref().and_then(|venmo| venmo.attributes.as_ref());
+        let apple_pay = self
+            .apple_pay
+            .as_ref()
+            .and_then(|apple_pay| apple_pay.attributes.as_ref());
+        card.or(paypal)
+            .or(venmo)
+            .or(apple_pay)
+            .and_then(|attributes| attributes.vault.clone())
+    }
+
+    /// Returns the result of the 3D Secure authentication of the card, if the card was authenticated.
+    ///
+    /// Looks at the card payment source, then at the cards of the Google Pay and Apple Pay wallets.
+    pub fn authentication_result(&self) -> Option<AuthenticationResult> {
+        let google_pay = self.google_pay.as_ref().and_then(|google_pay| google_pay.card.as_ref());
+        let apple_pay = self.apple_pay.as_ref().and_then(|apple_pay| apple_pay.card.as_ref());
+        self.card
+            .as_ref()
+            .or(google_pay)
+            .or(apple_pay)
+            .and_then(|card| card.authentication_result.clone())
+    }
+}
+
+/// Whether the liability for a fraudulent chargeback shifted to the card issuer.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LiabilityShift {
+    /// Liability might shift to the card issuer.
+    Possible,
+    /// Liability is with the merchant.
+    No,
+    /// The authentication system is not available.
+    Unknown,
+    /// Liability has shifted to the card issuer.
+    Yes,
+    /// A liability shift unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Whether the card is enrolled in 3D Secure.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub enum EnrollmentStatus {
+    /// Card type and issuing bank are ready to complete a 3D Secure authentication.
+    #[serde(rename = "Y")]
+    Ready,
+    /// Card type and issuing bank are not ready to complete a 3D Secure authentication.
+    #[serde(rename = "N")]
+    NotReady,
+    /// System is unavailable at this time.
+    #[serde(rename = "U")]
+    Unavailable,
+    /// System has bypassed authentication.
+    #[serde(rename = "B")]
+    Bypassed,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The outcome of the 3D Secure authentication.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub enum AuthenticationStatus {
+    /// Successful authentication.
+    #[serde(rename = "Y")]
+    Successful,
+    /// Failed authentication, or the account was not verified.
+    #[serde(rename = "N")]
+    Failed,
+    /// Rejected by the issuer.
+    #[serde(rename = "R")]
+    Rejected,
+    /// Attempted authentication.
+    #[serde(rename = "A")]
+    Attempted,
+    /// Unable to complete authentication.
+    #[serde(rename = "U")]
+    Unable,
+    /// Challenge required for authentication.
+    #[serde(rename = "C")]
+    ChallengeRequired,
+    /// Challenge required, the payer was informed.
+    #[serde(rename = "I")]
+    Info,
+    /// Decoupled authentication, the payer is authenticated outside of the checkout.
+    #[serde(rename = "D")]
+    Decoupled,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The 3D Secure details of an authentication.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ThreeDSecureResult {
+    /// Whether the card is enrolled in 3D Secure.
+    pub enrollment_status: Option<EnrollmentStatus>,
+    /// The outcome of the authentication.
+    pub authentication_status: Option<AuthenticationStatus>,
+}
+
+/// The result of the authentication of a card, for example with 3D Secure.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct AuthenticationResult {
+    /// Whether the liability shifted to the card issuer.
+    pub liability_shift: Option<LiabilityShift>,
+    /// The 3D Secure details.
+    pub three_d_secure: Option<ThreeDSecureResult>,
+}
+
+impl AuthenticationResult {
+    /// Whether the payment should not continue, following the PayPal recommended actions.
+    ///
+    /// That is when the issuer failed or rejected the authentication of an enrolled card.
+    pub fn is_rejected(&self) -> bool {
+        self.liability_shift == Some(LiabilityShift::No)
+            && self.three_d_secure.as_ref().is_some_and(|result| {
+                result.enrollment_status == Some(EnrollmentStatus::Ready)
+                    && matches!(
+                        result.authentication_status,
+                        Some(AuthenticationStatus::Failed | AuthenticationStatus::Rejected)
+                    )
+            })
+    }
+}
+
+/// A strong customer authentication (SCA) step that blocks an order, see [Order::sca_contingency].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScaContingency {
+    /// Redirect the payer to the url to authenticate, then retry the capture or authorization once they are back.
+    RedirectPayer {
+        /// The url the payer must be redirected to.
+        url: String,
+    },
+    /// The authentication system was unavailable, the payer should retry the authentication.
+    RetryAuthentication(AuthenticationResult),
+    /// The authentication failed or was rejected, the payment should not continue.
+    Rejected(AuthenticationResult),
 }
 
 /// The status of an order.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderStatus {
     /// The order was created with the specified context.
@@ -1122,6 +2476,12 @@ pub enum OrderStatus {
     Voided,
     /// The payment was authorized or the authorized payment was captured for the order.
     Completed,
+    /// The order requires an action from the payer (e.g. 3DS authentication).
+    /// Redirect the payer to the "rel":"payer-action" HATEOAS link returned as part of the response prior to authorizing or capturing the order.
+    PayerActionRequired,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// An order represents a payment between two or more parties.
@@ -1134,7 +2494,7 @@ pub struct Order {
     /// The date and time when the transaction was last updated.
     pub update_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The ID of the order.
-    pub id: String,
+    pub id: OrderId,
     /// An array of purchase units. Each purchase unit establishes a contract between a customer and merchant.
     /// Each purchase unit represents either a full or partial order that the customer intends to purchase from the merchant.
     pub purchase_units: Option<Vec<PurchaseUnit>>,
@@ -1150,9 +2510,430 @@ pub struct Order {
     pub status: OrderStatus,
 }
 
+impl Order {
+    /// Returns the payer action link, used to redirect the payer for example to complete a 3D Secure authentication.
+    pub fn payer_action_url(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.rel == Some(LinkRel::PayerAction))
+            .map(|link| link.href.as_str())
+    }
+
+    /// Returns the approve link, where the payer approves a created order.
+    pub fn approve_url(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.rel == Some(LinkRel::Approve))
+            .map(|link| link.href.as_str())
+    }
+
+    /// Returns the URL the payer must be redirected to before the order can be authorized or captured.
+    ///
+    /// That is the `payer-action` link of an order in the [OrderStatus::PayerActionRequired] status, as returned for
+    /// 3D Secure and the payment methods with an experience context, or the `approve` link of a created order.
+    pub fn redirect_url(&self) -> Option<&str> {
+        match self.status {
+            OrderStatus::PayerActionRequired => self.payer_action_url(),
+            OrderStatus::Created => self.approve_url(),
+            _ => None,
+        }
+    }
+
+    /// Detects whether strong customer authentication (3D Secure) blocks this order.
+    ///
+    /// Returns `None` when the order can be authorized or captured as is.
+    pub fn sca_contingency(&self) -> Option<ScaContingency> {
+        if let Some(url) = self.payer_action_url() {
+            return Some(ScaContingency::RedirectPayer { url: url.to_string() });
+        }
+
+        let result = self.payment_source.as_ref()?.authentication_result()?;
+
+        if result.liability_shift == Some(LiabilityShift::Unknown) {
+            Some(ScaContingency::RetryAuthentication(result))
+        } else if result.is_rejected() {
+            Some(ScaContingency::Rejected(result))
+        } else {
+            None
+        }
+    }
+}
+
 /// An invoice number.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct InvoiceNumber {
     /// The invoice number.
     pub invoice_number: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::vault::{CardVerification, VerificationMethod};
+
+    #[test]
+    fn test_venmo_payment_source() {
+        let source: PaymentSourceResponse = serde_json::from_value(serde_json::json!({
+            "venmo": {
+                "email_address": "venmo@example.com",
+                "account_id": "QYR5Z8XDVJNXQ",
+                "user_name": "johndoe",
+                "name": { "given_name": "John", "surname": "Doe" },
+                "phone_number": { "national_number": "4085551234" },
+                "address": { "country_code": "US", "postal_code": "95131" },
+                "attributes": {
+                    "vault": { "id": "8kk8451t", "status": "VAULTED" }
+                }
+            }
+        }))
+        .unwrap();
+
+        let venmo = source.venmo.as_ref().unwrap();
+        assert_eq!(venmo.user_name.as_deref(), Some("johndoe"));
+        assert_eq!(venmo.phone_number.as_ref().unwrap().national_number, "4085551234");
+        assert_eq!(source.vault().unwrap().id.as_deref(), Some("8kk8451t"));
+    }
+
+    #[test]
+    fn test_google_pay_payment_source() {
+        let card = GooglePayCardBuilder::default()
+            .number("4111111111111111")
+            .expiry("2028-12")
+            .build()
+            .unwrap();
+        let token = GooglePayDecryptedTokenBuilder::default()
+            .card(card)
+            .authentication_method(GooglePayAuthenticationMethod::Cryptogram3ds)
+            .cryptogram("AAAAAAAAAAAAAAAAAAAAAAAAAAA=")
+            .build()
+            .unwrap();
+        let source = OrderPaymentSourceBuilder::default()
+            .google_pay(
+                GooglePayRequestBuilder::default()
+                    .decrypted_token(token)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&source).unwrap();
+        assert_eq!(json["google_pay"]["decrypted_token"]["payment_method"], "CARD");
+        assert_eq!(
+            json["google_pay"]["decrypted_token"]["authentication_method"],
+            "CRYPTOGRAM_3DS"
+        );
+
+        let source: PaymentSourceResponse = serde_json::from_value(serde_json::json!({
+            "google_pay": {
+                "name": "John Doe",
+                "card": { "last_digits": "1111", "type": "CREDIT", "brand": "VISA" }
+            }
+        }))
+        .unwrap();
+        let card = source.google_pay.and_then(|google_pay| google_pay.card).unwrap();
+        assert_eq!(card.brand, Some(CardBrand::Visa));
+    }
+
+    #[test]
+    fn test_wallet_authentication_result() {
+        let source: PaymentSourceResponse = serde_json::from_value(serde_json::json!({
+            "google_pay": {
+                "card": {
+                    "last_digits": "1111",
+                    "authentication_result": {
+                        "liability_shift": "NO",
+                        "three_d_secure": {
+                            "enrollment_status": "Y",
+                            "authentication_status": "R"
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let result = source.authentication_result().unwrap();
+        assert_eq!(result.liability_shift, Some(LiabilityShift::No));
+        assert!(result.is_rejected());
+    }
+
+    #[test]
+    fn test_unknown_wallet_values() {
+        let shift: LiabilityShift = serde_json::from_value(serde_json::json!("UNKNOWN")).unwrap();
+        assert_eq!(shift, LiabilityShift::Unknown);
+        let shift: LiabilityShift = serde_json::from_value(serde_json::json!("PARTIAL")).unwrap();
+        assert_eq!(shift, LiabilityShift::Other("PARTIAL".to_string()));
+
+        let data_type: ApplePayPaymentDataType = serde_json::from_value(serde_json::json!("TOKEN")).unwrap();
+        assert_eq!(data_type, ApplePayPaymentDataType::Other("TOKEN".to_string()));
+
+        let method: GooglePayAuthenticationMethod = serde_json::from_value(serde_json::json!("PASSKEY")).unwrap();
+        assert_eq!(method, GooglePayAuthenticationMethod::Other("PASSKEY".to_string()));
+        assert_eq!(serde_json::to_value(&method).unwrap(), serde_json::json!("PASSKEY"));
+    }
+
+    #[test]
+    fn test_processor_response() {
+        let response: ProcessorResponse = serde_json::from_value(serde_json::json!({
+            "avs_code": "Y",
+            "cvv_code": "N",
+            "response_code": "5110",
+            "payment_advice_code": "03"
+        }))
+        .unwrap();
+        assert_eq!(response.avs_code, Some(AvsCode::Y));
+        assert_eq!(response.cvv_code, Some(CvvCode::N));
+        assert_eq!(response.response_code, Some(ProcessorResponseCode::Cvv2Failure));
+        assert_eq!(response.payment_advice_code, Some(PaymentAdviceCode::DoNotTryAgain));
+
+        let response: ProcessorResponse =
+            serde_json::from_value(serde_json::json!({ "avs_code": "0", "response_code": "PPXX" })).unwrap();
+        assert_eq!(response.avs_code, Some(AvsCode::MaestroMatch));
+        assert_eq!(
+            response.response_code,
+            Some(ProcessorResponseCode::Unknown("PPXX".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_apm_payment_sources() {
+        let source: PaymentSourceResponse = serde_json::from_value(serde_json::json!({
+            "ideal": {
+                "name": "John Doe",
+                "country_code": "NL",
+                "bic": "INGBNL2A",
+                "iban_last_chars": "7890"
+            },
+            "bancontact": {
+                "name": "John Doe",
+                "country_code": "BE",
+                "card_last_digits": "4321"
+            }
+        }))
+        .unwrap();
+
+        let ideal = source.ideal.unwrap();
+        assert_eq!(ideal.bic.as_deref(), Some("INGBNL2A"));
+        assert_eq!(ideal.iban_last_chars.as_deref(), Some("7890"));
+
+        let bancontact = source.bancontact.unwrap();
+        assert_eq!(bancontact.details.country_code.as_deref(), Some("BE"));
+        assert_eq!(bancontact.card_last_digits.as_deref(), Some("4321"));
+    }
+
+    #[test]
+    fn test_validate_purchase_unit() {
+        let item = |quantity: &str, unit_amount: &str, tax: &str| Item {
+            name: "Socks".to_string(),
+            quantity: quantity.to_string(),
+            unit_amount: Money::usd(unit_amount),
+            tax: Some(Money::usd(tax)),
+            ..Default::default()
+        };
+        let items = vec![item("3", "0.10", "0.01"), item("1", "5", "0.50")];
+
+        let breakdown = Breakdown::from_items(&items).unwrap();
+        assert_eq!(breakdown.item_total, Some(Money::usd("5.30")));
+        assert_eq!(breakdown.tax_total, Some(Money::usd("0.53")));
+
+        let mut unit = PurchaseUnit::new(Amount::usd("5.83"));
+        unit.amount.breakdown = Some(breakdown);
+        unit.items = Some(items);
+        assert_eq!(unit.validate(), Ok(()));
+
+        unit.amount.value = "5.84".to_string();
+        assert_eq!(
+            unit.validate(),
+            Err(InvalidAmountError::Mismatch {
+                field: "amount",
+                expected: Money::usd("5.83"),
+                found: Money::usd("5.84"),
+            })
+        );
+
+        unit.amount.value = "5.83".to_string();
+        unit.items.as_mut().unwrap().push(item("1", "1.00", "0"));
+        assert!(matches!(
+            unit.validate(),
+            Err(InvalidAmountError::Mismatch {
+                field: "item_total",
+                ..
+            })
+        ));
+
+        unit.items.as_mut().unwrap()[2].unit_amount = Money::eur("1.00");
+        assert_eq!(
+            unit.validate(),
+            Err(InvalidAmountError::CurrencyMismatch {
+                expected: Currency::USD,
+                found: Currency::EUR,
+            })
+        );
+    }
+
+    #[test]
+    fn test_status_details_reasons() {
+        let details: CaptureStatusDetails =
+            serde_json::from_value(serde_json::json!({ "reason": "PENDING_REVIEW" })).unwrap();
+        assert_eq!(details.reason, CaptureStatusDetailsReason::PendingReview);
+
+        let details: CaptureStatusDetails =
+            serde_json::from_value(serde_json::json!({ "reason": "SOME_NEW_REASON" })).unwrap();
+        assert_eq!(
+            details.reason,
+            CaptureStatusDetailsReason::Unknown("SOME_NEW_REASON".to_string())
+        );
+
+        let details: AuthorizationStatusDetails =
+            serde_json::from_value(serde_json::json!({ "reason": "DECLINED_BY_RISK_FRAUD_FILTERS" })).unwrap();
+        assert_eq!(
+            details.reason,
+            AuthorizationStatusDetailsReason::DeclinedByRiskFraudFilters
+        );
+    }
+
+    #[test]
+    fn test_parse_phone_number() {
+        let phone = PhoneNumber::parse(" +1 (408) 555-0100").unwrap();
+        assert_eq!(phone.country_code.as_deref(), Some("1"));
+        assert_eq!(phone.national_number, "4085550100");
+
+        let phone: PhoneNumber = "+353.1.234.5678".parse().unwrap();
+        assert_eq!(phone.country_code.as_deref(), Some("353"));
+        assert_eq!(phone.to_string(), "+35312345678");
+
+        for number in [
+            "4915123456789",
+            "+49",
+            "+0123456",
+            "+49 151 2345 6789 012",
+            "+49 151 ABC",
+        ] {
+            assert_eq!(
+                PhoneNumber::parse(number),
+                Err(InvalidPhoneNumberError(number.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_network_token_card() {
+        let card = PaymentCardBuilder::default()
+            .security_code("123")
+            .network_token(
+                NetworkTokenBuilder::default()
+                    .number("4895370012003478")
+                    .expiry("2030-12")
+                    .eci_flag(EciFlag::Non3dSecureTransaction)
+                    .build()
+                    .unwrap(),
+            )
+            .attributes(PaymentSourceAttributes {
+                verification: Some(CardVerification {
+                    method: VerificationMethod::ThreeDSecure,
+                }),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&card).unwrap(),
+            serde_json::json!({
+                "security_code": "123",
+                "attributes": { "verification": { "method": "3D_SECURE" } },
+                "network_token": {
+                    "number": "4895370012003478",
+                    "expiry": "2030-12",
+                    "eci_flag": "NON_3D_SECURE_TRANSACTION"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_tax_id_type() {
+        let tax_info: TaxInfo =
+            serde_json::from_value(serde_json::json!({ "tax_id": "000.000.000-00", "tax_id_type": "BR_CPF" })).unwrap();
+        assert_eq!(tax_info.tax_id_type, TaxIdType::BR_CPF);
+
+        let tax_info: TaxInfo =
+            serde_json::from_value(serde_json::json!({ "tax_id": "22AAAAA0000A1Z5", "tax_id_type": "IN_GST" }))
+                .unwrap();
+        assert_eq!(tax_info.tax_id_type, TaxIdType::Other("IN_GST".to_string()));
+        assert_eq!(serde_json::to_value(&tax_info).unwrap()["tax_id_type"], "IN_GST");
+    }
+
+    #[test]
+    fn test_level_2_data() {
+        let data = SupplementaryData {
+            level_2: Some(
+                Level2CardProcessingDataBuilder::default()
+                    .invoice_id("INV-001")
+                    .tax_total(Money::usd("1.50"))
+                    .build()
+                    .unwrap(),
+            ),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&data).unwrap(),
+            serde_json::json!({
+                "level_2": {
+                    "invoice_id": "INV-001",
+                    "tax_total": { "currency_code": "USD", "value": "1.50" }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_redirect_url() {
+        let order = |status: &str| -> Order {
+            serde_json::from_value(serde_json::json!({
+                "id": "5O190127TN364715T",
+                "status": status,
+                "links": [
+                    { "href": "https://www.paypal.com/checkoutnow?token=5O190127TN364715T", "rel": "approve", "method": "GET" },
+                    { "href": "https://www.paypal.com/checkoutnow?token=5O190127TN364715T&flow=3ds", "rel": "payer-action", "method": "GET" }
+                ]
+            }))
+            .unwrap()
+        };
+
+        assert!(
+            order("CREATED")
+                .redirect_url()
+                .unwrap()
+                .ends_with("token=5O190127TN364715T")
+        );
+        assert!(
+            order("PAYER_ACTION_REQUIRED")
+                .redirect_url()
+                .unwrap()
+                .ends_with("flow=3ds")
+        );
+        assert_eq!(order("APPROVED").redirect_url(), None);
+    }
+
+    #[test]
+    fn test_unknown_values() {
+        let order: Order = serde_json::from_value(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "SOME_NEW_STATUS",
+            "intent": "SOME_NEW_INTENT",
+            "payment_source": { "card": { "brand": "NEW_BRAND", "type": "STORED_VALUE" } },
+            "links": []
+        }))
+        .unwrap();
+        assert_eq!(order.status, OrderStatus::Unknown("SOME_NEW_STATUS".to_string()));
+        assert_eq!(order.intent, Some(Intent::Unknown("SOME_NEW_INTENT".to_string())));
+        let card = order.payment_source.unwrap().card.unwrap();
+        assert_eq!(card.brand, Some(CardBrand::Unknown("NEW_BRAND".to_string())));
+        assert_eq!(card.card_type, Some(CardType::Unknown("STORED_VALUE".to_string())));
+
+        let refund_status: RefundStatus = serde_json::from_value(serde_json::json!("REVERSED")).unwrap();
+        assert_eq!(refund_status, RefundStatus::Unknown("REVERSED".to_string()));
+        assert_eq!(serde_json::to_value(&refund_status).unwrap(), "REVERSED");
+    }
+}
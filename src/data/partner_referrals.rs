@@ -0,0 +1,322 @@
+//! Paypal object definitions used by the partner referrals api.
+
+use crate::data::common::{LinkDescription, LinkRel};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Overrides the partner configuration for a single referral.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PartnerConfigOverride {
+    /// The partner logo URL to display in the customer's onboarding flow.
+    pub partner_logo_url: Option<String>,
+    /// The URL to which to redirect the customer upon completion of the onboarding process.
+    pub return_url: Option<String>,
+    /// The description of the return URL.
+    pub return_url_description: Option<String>,
+    /// If `renew_action_url` expires, the URL where the customer is redirected to get a new action URL.
+    pub action_renewal_url: Option<String>,
+    /// Whether the customer is shown the option to add a credit card during onboarding.
+    pub show_add_credit_card: Option<bool>,
+}
+
+/// The type of an operation the partner performs on behalf of the seller.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OperationType {
+    /// Integrates the seller account with the partner through the api.
+    ApiIntegration,
+    /// Adds a bank account to the seller account.
+    BankAddition,
+    /// Sets up a billing agreement with the seller.
+    BillingAgreement,
+    /// Asks the seller for consent to contextual marketing.
+    ContextualMarketingConsent,
+    /// An operation unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// How the seller integrates with PayPal.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IntegrationMethod {
+    /// Through Braintree.
+    Braintree,
+    /// Through PayPal.
+    Paypal,
+    /// A method unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Who makes the api calls for the seller.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IntegrationType {
+    /// The seller makes the calls with their own credentials, shared with the partner.
+    FirstParty,
+    /// The partner makes the calls on behalf of the seller.
+    ThirdParty,
+    /// A type unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A permission the seller grants to the partner.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Feature {
+    /// Capture payments for the seller.
+    Payment,
+    /// Refund payments for the seller.
+    Refund,
+    /// Make future payments for the seller.
+    FuturePayment,
+    /// Process card payments directly.
+    DirectPayment,
+    /// Collect a partner fee on the seller payments.
+    PartnerFee,
+    /// Delay the disbursement of funds to the seller.
+    DelayFundsDisbursement,
+    /// Read the seller disputes.
+    ReadSellerDispute,
+    /// Update the seller disputes.
+    UpdateSellerDispute,
+    /// Read the disputes raised by the seller as a buyer.
+    DisputeReadBuyer,
+    /// Update the customer disputes.
+    UpdateCustomerDisputes,
+    /// Search the seller transactions.
+    AdvancedTransactionsSearch,
+    /// Sweep funds to an external sink.
+    SweepFundsExternalSink,
+    /// Access the seller information.
+    AccessMerchantInformation,
+    /// Add tracking information for the seller shipments.
+    TrackingShipmentReadwrite,
+    /// Manage the seller invoices.
+    InvoiceReadWrite,
+    /// Save payment methods for the seller.
+    Vault,
+    /// Manage billing agreements for the seller.
+    BillingAgreement,
+    /// A feature unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The details of a first party integration.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FirstPartyDetails {
+    /// The permissions granted.
+    pub features: Vec<Feature>,
+    /// The nonce used to exchange the shared credentials.
+    pub seller_nonce: String,
+}
+
+/// The details of a third party integration.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ThirdPartyDetails {
+    /// The permissions granted to the partner.
+    pub features: Vec<Feature>,
+}
+
+/// The preferences of a rest api integration.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RestApiIntegration {
+    /// How the seller integrates with PayPal.
+    pub integration_method: IntegrationMethod,
+    /// Who makes the api calls for the seller.
+    pub integration_type: IntegrationType,
+    /// The details of a first party integration.
+    pub first_party_details: Option<FirstPartyDetails>,
+    /// The details of a third party integration.
+    pub third_party_details: Option<ThirdPartyDetails>,
+}
+
+/// The preferences of an api integration.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ApiIntegrationPreference {
+    /// The preferences of a rest api integration.
+    pub rest_api_integration: Option<RestApiIntegration>,
+}
+
+/// An operation the partner performs on behalf of the seller.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Operation {
+    /// The operation.
+    pub operation: OperationType,
+    /// The preferences of the api integration, when the operation is `API_INTEGRATION`.
+    pub api_integration_preference: Option<ApiIntegrationPreference>,
+}
+
+impl Operation {
+    /// An api integration where the partner makes the calls on behalf of the seller, with the given permissions.
+    pub fn third_party(features: Vec<Feature>) -> Self {
+        Self {
+            operation: OperationType::ApiIntegration,
+            api_integration_preference: Some(ApiIntegrationPreference {
+                rest_api_integration: Some(RestApiIntegration {
+                    integration_method: IntegrationMethod::Paypal,
+                    integration_type: IntegrationType::ThirdParty,
+                    first_party_details: None,
+                    third_party_details: Some(ThirdPartyDetails { features }),
+                }),
+            }),
+        }
+    }
+}
+
+/// A product the seller is onboarded to.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Product {
+    /// PayPal Checkout.
+    ExpressCheckout,
+    /// PayPal Plus.
+    Ppplus,
+    /// Website Payments Pro.
+    WebsitePaymentPro,
+    /// PayPal Complete Payments, including advanced card processing.
+    Ppcp,
+    /// Alternative payment methods.
+    PaymentMethods,
+    /// Saving payment methods.
+    AdvancedVaulting,
+    /// A product unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The type of a consent given by the seller.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LegalConsentType {
+    /// The seller consents to share their data with the partner.
+    ShareDataConsent,
+    /// A type unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A consent given by the seller.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LegalConsent {
+    /// The type of consent.
+    #[serde(rename = "type")]
+    pub consent_type: LegalConsentType,
+    /// Whether the seller granted the consent.
+    pub granted: bool,
+}
+
+impl LegalConsent {
+    /// The consent to share the seller data with the partner, required for third party integrations.
+    pub fn share_data() -> Self {
+        Self {
+            consent_type: LegalConsentType::ShareDataConsent,
+            granted: true,
+        }
+    }
+}
+
+/// A webhook event the partner subscribes to for the seller.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WebhookEventType {
+    /// The name of the event, for example `MERCHANT.ONBOARDING.COMPLETED`.
+    pub name: String,
+}
+
+/// The webhook notified about the seller onboarding.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WebhookInfo {
+    /// The URL of the webhook.
+    pub webhook_url: String,
+    /// The events the webhook is notified about.
+    #[serde(default)]
+    pub webhook_event_types: Vec<WebhookEventType>,
+}
+
+/// The data used to onboard a seller.
+///
+/// ```
+/// use paypal_rs::data::partner_referrals::*;
+///
+/// let payload = PartnerReferralPayloadBuilder::default()
+///     .tracking_id("seller-1234")
+///     .operations(vec![Operation::third_party(vec![Feature::Payment, Feature::Refund])])
+///     .products(vec![Product::ExpressCheckout])
+///     .legal_consents(vec![LegalConsent::share_data()])
+///     .build()
+///     .unwrap();
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PartnerReferralPayload {
+    /// The partner's unique identifier for the seller.
+    pub tracking_id: Option<String>,
+    /// The email address of the seller, used to prefill the onboarding flow.
+    pub email: Option<String>,
+    /// The preferred language of the seller, for example `en-US`.
+    pub preferred_language_code: Option<String>,
+    /// Overrides the partner configuration for this referral.
+    pub partner_config_override: Option<PartnerConfigOverride>,
+    /// The operations the partner performs on behalf of the seller.
+    #[serde(default)]
+    pub operations: Vec<Operation>,
+    /// The products to onboard the seller to.
+    #[serde(default)]
+    pub products: Vec<Product>,
+    /// The consents given by the seller.
+    #[serde(default)]
+    pub legal_consents: Vec<LegalConsent>,
+    /// The webhook notified about the seller onboarding.
+    pub webhook_info: Option<WebhookInfo>,
+}
+
+/// The links returned for a created referral.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PartnerReferral {
+    /// The `self` link to the referral data and the `action_url` link for the seller.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl PartnerReferral {
+    /// Returns the URL where the seller is redirected to onboard, from the `action_url` HATEOAS link.
+    pub fn action_url(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.rel == Some(LinkRel::ActionUrl))
+            .map(|link| link.href.as_str())
+    }
+
+    /// Returns the ID of the referral, the last segment of the `self` HATEOAS link, used to show the referral data.
+    pub fn referral_id(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.rel == Some(LinkRel::SelfLink))
+            .and_then(|link| link.href.rsplit('/').next())
+    }
+}
+
+/// The data of a referral.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ReferralData {
+    /// The ID of the referral.
+    pub partner_referral_id: String,
+    /// The payer ID of the partner who created the referral.
+    pub submitter_payer_id: Option<String>,
+    /// The data the referral was created with.
+    pub referral_data: PartnerReferralPayload,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
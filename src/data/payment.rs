@@ -1,11 +1,14 @@
 //! Paypal object definitions used by the payments api.
 
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 use super::common::{AuthorizationStatusDetails, LinkDescription, Money, SellerProtection};
+use super::ids::AuthorizationId;
 
 /// Payment Status
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PaymentStatus {
     /// The authorized payment is created. No captured payments have been made for this authorized payment.
@@ -24,6 +27,9 @@ pub enum PaymentStatus {
     Voided,
     /// The created authorization is in pending state.
     Pending,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The authorized payment details.
@@ -32,9 +38,9 @@ pub struct AuthorizedPaymentDetails {
     /// The status for the authorized payment.
     pub status: PaymentStatus,
     /// The details of the authorized order pending status.
-    pub status_details: AuthorizationStatusDetails,
+    pub status_details: Option<AuthorizationStatusDetails>,
     /// The PayPal-generated ID for the authorized payment.
-    pub id: String,
+    pub id: AuthorizationId,
     /// The amount for this authorized payment.
     pub amount: Money,
     /// The API caller-provided external invoice number for this order. Appears in both the payer's transaction history and the emails that the payer receives.
@@ -52,3 +58,19 @@ pub struct AuthorizedPaymentDetails {
     /// The date and time when the transaction was last updated
     pub update_time: chrono::DateTime<chrono::Utc>,
 }
+
+/// The payload used to refund a captured payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct RefundRequest {
+    /// The amount to refund. To refund a portion of the captured amount, specify an amount.
+    /// If amount is not specified, an amount equal to captured amount - previous refunds is refunded.
+    pub amount: Option<Money>,
+    /// The API caller-provided external ID. Used to reconcile API caller-initiated transactions with PayPal transactions.
+    pub custom_id: Option<String>,
+    /// The API caller-provided external invoice ID for this order.
+    pub invoice_id: Option<String>,
+    /// The reason for the refund. Appears in both the payer's transaction history and the emails that the payer receives.
+    pub note_to_payer: Option<String>,
+}
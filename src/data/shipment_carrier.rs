@@ -1386,3 +1386,123 @@ pub enum ShipmentCarrier {
     #[default]
     Other,
 }
+
+impl ShipmentCarrier {
+    /// Makes a best-effort guess of the carrier from the format of a tracking number.
+    ///
+    /// Only the formats used by UPS, USPS, FedEx and DHL are recognized, and some formats are shared
+    /// between carriers, so the result should be treated as a hint. Spaces and dashes are ignored.
+    ///
+    /// ```
+    /// use paypal_rs::data::shipment_carrier::ShipmentCarrier;
+    ///
+    /// assert_eq!(ShipmentCarrier::detect("1Z 999 AA1 01 2345 6784"), Some(ShipmentCarrier::Ups));
+    /// assert_eq!(ShipmentCarrier::detect("not a tracking number"), None);
+    /// ```
+    pub fn detect(tracking_number: &str) -> Option<ShipmentCarrier> {
+        let number: String = tracking_number
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        if !number.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+
+        let digits = number.chars().all(|c| c.is_ascii_digit());
+        let bytes = number.as_bytes();
+
+        if number.len() == 18 && number.starts_with("1Z") {
+            return Some(ShipmentCarrier::Ups);
+        }
+
+        // UPU S10 international format, e.g. EC123456789US.
+        if number.len() == 13
+            && number.ends_with("US")
+            && bytes[..2].iter().all(u8::is_ascii_alphabetic)
+            && bytes[2..11].iter().all(u8::is_ascii_digit)
+        {
+            return Some(ShipmentCarrier::Usps);
+        }
+
+        if number.starts_with("JJD") && number.len() >= 12 && bytes[3..].iter().all(u8::is_ascii_digit) {
+            return Some(ShipmentCarrier::Dhl);
+        }
+
+        if !digits {
+            return None;
+        }
+
+        match number.len() {
+            10 => Some(ShipmentCarrier::Dhl),
+            12 | 15 => Some(ShipmentCarrier::Fedex),
+            22 if number.starts_with("96") => Some(ShipmentCarrier::Fedex),
+            20 | 22 => Some(ShipmentCarrier::Usps),
+            _ => None,
+        }
+    }
+
+    /// Returns the explicit carrier when there is one, otherwise falls back to [ShipmentCarrier::detect].
+    ///
+    /// ```
+    /// use paypal_rs::data::shipment_carrier::ShipmentCarrier;
+    ///
+    /// assert_eq!(ShipmentCarrier::guess("1Z999AA10123456784", None), Some(ShipmentCarrier::Ups));
+    /// assert_eq!(
+    ///     ShipmentCarrier::guess("1Z999AA10123456784", Some(ShipmentCarrier::UpsFreight)),
+    ///     Some(ShipmentCarrier::UpsFreight)
+    /// );
+    /// ```
+    pub fn guess(tracking_number: &str, explicit: Option<ShipmentCarrier>) -> Option<ShipmentCarrier> {
+        explicit.or_else(|| Self::detect(tracking_number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShipmentCarrier;
+
+    #[test]
+    fn test_detect_carrier() {
+        assert_eq!(
+            ShipmentCarrier::detect("1Z999AA10123456784"),
+            Some(ShipmentCarrier::Ups)
+        );
+        assert_eq!(
+            ShipmentCarrier::detect("1z999aa10123456784"),
+            Some(ShipmentCarrier::Ups)
+        );
+        assert_eq!(
+            ShipmentCarrier::detect("9400 1000 0000 0000 0000 00"),
+            Some(ShipmentCarrier::Usps)
+        );
+        assert_eq!(ShipmentCarrier::detect("EC123456789US"), Some(ShipmentCarrier::Usps));
+        assert_eq!(
+            ShipmentCarrier::detect("12345678901234567890"),
+            Some(ShipmentCarrier::Usps)
+        );
+        assert_eq!(ShipmentCarrier::detect("123456789012"), Some(ShipmentCarrier::Fedex));
+        assert_eq!(
+            ShipmentCarrier::detect("9611020987654312345672"),
+            Some(ShipmentCarrier::Fedex)
+        );
+        assert_eq!(ShipmentCarrier::detect("1234567890"), Some(ShipmentCarrier::Dhl));
+        assert_eq!(ShipmentCarrier::detect("JJD0099999999"), Some(ShipmentCarrier::Dhl));
+        assert_eq!(ShipmentCarrier::detect("12345"), None);
+        assert_eq!(ShipmentCarrier::detect("ABC-#123"), None);
+    }
+
+    #[test]
+    fn test_guess_carrier() {
+        assert_eq!(
+            ShipmentCarrier::guess("1Z999AA10123456784", Some(ShipmentCarrier::UpsFreight)),
+            Some(ShipmentCarrier::UpsFreight)
+        );
+        assert_eq!(
+            ShipmentCarrier::guess("123456789012", None),
+            Some(ShipmentCarrier::Fedex)
+        );
+        assert_eq!(ShipmentCarrier::guess("ABC", None), None);
+    }
+}
@@ -1,7 +1,10 @@
 //! This module contains the defined for tracking schema.
 
-use crate::data::common::ItemUpc;
+use crate::data::common::{ItemUpc, LinkDescription};
+use crate::data::ids::TrackerId;
+use crate::data::orders::TrackerStatus;
 use crate::data::shipment_carrier::ShipmentCarrier;
+use crate::errors::PaypalError;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -32,6 +35,19 @@ pub struct OrderTracking {
     pub items: Option<Vec<ShipmentItem>>,
 }
 
+/// Decides which trackers notify the payer when several are added to the same order.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NotifyPayerPolicy {
+    /// The payer is not notified.
+    #[default]
+    Never,
+    /// The payer is notified for every tracker.
+    Always,
+    /// The payer is notified only for the first tracker successfully added, avoiding duplicated emails.
+    FirstOnly,
+}
+
 /// item in the shipment.
 #[skip_serializing_none]
 #[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
@@ -51,3 +67,81 @@ pub struct ShipmentItem {
     /// The Universal Product Code of the item.
     pub upc: Option<ItemUpc>,
 }
+
+/// The tracking information of a transaction, used by the shipping api.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct Tracker {
+    /// The PayPal transaction ID.
+    pub transaction_id: String,
+    /// The tracking number for the shipment.
+    #[builder(default)]
+    pub tracking_number: Option<String>,
+    /// The status of the item shipment.
+    pub status: TrackerStatus,
+    /// The carrier for the shipment.
+    #[builder(default)]
+    pub carrier: Option<ShipmentCarrier>,
+    /// The name of the carrier, when the carrier is `OTHER`.
+    #[builder(default)]
+    pub carrier_name_other: Option<String>,
+    /// The date when the shipment was sent.
+    #[builder(default)]
+    pub shipment_date: Option<chrono::NaiveDate>,
+    /// Whether PayPal sends an email notification to the buyer.
+    #[builder(default)]
+    pub notify_buyer: Option<bool>,
+    /// The date and time when the tracking information was last updated.
+    #[builder(default)]
+    #[serde(skip_serializing)]
+    pub last_updated_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of request-related HATEOAS links.
+    #[builder(default)]
+    #[serde(default, skip_serializing)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl Tracker {
+    /// The id of the tracker, as used by the shipping api paths.
+    pub fn id(&self) -> TrackerId {
+        TrackerId::from_parts(
+            &self.transaction_id,
+            self.tracking_number.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
+/// A batch of trackers to add.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrackersBatch {
+    /// The trackers.
+    pub trackers: Vec<Tracker>,
+}
+
+/// Identifies a tracker that was added.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrackerIdentifier {
+    /// The PayPal transaction ID.
+    pub transaction_id: String,
+    /// The tracking number for the shipment.
+    pub tracking_number: Option<String>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The outcome of adding a batch of trackers, the trackers that failed don't prevent the rest from being added.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrackersBatchResponse {
+    /// The trackers that were added.
+    #[serde(default)]
+    pub tracker_identifiers: Vec<TrackerIdentifier>,
+    /// The errors of the trackers that could not be added.
+    #[serde(default)]
+    pub errors: Vec<PaypalError>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
@@ -0,0 +1,494 @@
+//! Paypal object definitions used by the transaction search api.
+
+use crate::data::common::{Currency, LinkDescription, Money};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_with::skip_serializing_none;
+
+/// The status of a transaction.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub enum TransactionStatus {
+    /// PayPal or merchant rules denied the transaction.
+    #[serde(rename = "D")]
+    Denied,
+    /// The transaction is pending. The transaction was created but waits for another payment process to complete.
+    #[serde(rename = "P")]
+    Pending,
+    /// The transaction successfully completed without a denial and after any pending statuses.
+    #[serde(rename = "S")]
+    Success,
+    /// A successful transaction was reversed and funds were refunded to the original sender.
+    #[serde(rename = "V")]
+    Reversed,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The type of payment instrument used in a transaction.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PaymentInstrumentType {
+    /// A credit card.
+    CreditCard,
+    /// A debit card.
+    DebitCard,
+    /// A type unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The group of fields to include in each transaction detail.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionField {
+    /// The transaction information.
+    TransactionInfo,
+    /// The payer information.
+    PayerInfo,
+    /// The shipping information.
+    ShippingInfo,
+    /// The auction information.
+    AuctionInfo,
+    /// The cart information.
+    CartInfo,
+    /// The incentive information.
+    IncentiveInfo,
+    /// The store information.
+    StoreInfo,
+    /// All the fields.
+    All,
+}
+
+impl TransactionField {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::TransactionInfo => "transaction_info",
+            Self::PayerInfo => "payer_info",
+            Self::ShippingInfo => "shipping_info",
+            Self::AuctionInfo => "auction_info",
+            Self::CartInfo => "cart_info",
+            Self::IncentiveInfo => "incentive_info",
+            Self::StoreInfo => "store_info",
+            Self::All => "all",
+        }
+    }
+}
+
+fn serialize_fields<S: Serializer>(fields: &Option<Vec<TransactionField>>, serializer: S) -> Result<S::Ok, S::Error> {
+    match fields {
+        Some(fields) => {
+            let fields: Vec<_> = fields.iter().map(TransactionField::as_str).collect();
+            serializer.serialize_str(&fields.join(","))
+        }
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_flag<S: Serializer>(flag: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error> {
+    match flag {
+        Some(true) => serializer.serialize_str("Y"),
+        Some(false) => serializer.serialize_str("N"),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// A gross transaction amount range, in the lower denomination of the currency.
+///
+/// For example, to search for transactions from $5.00 to $10.05, use `AmountRange::new(500, 1005)`.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct AmountRange {
+    /// The lower limit of the range.
+    pub from: i64,
+    /// The upper limit of the range.
+    pub to: i64,
+}
+
+impl AmountRange {
+    /// New constructor.
+    pub fn new(from: i64, to: i64) -> Self {
+        Self { from, to }
+    }
+}
+
+impl Serialize for AmountRange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{} TO {}", self.from, self.to))
+    }
+}
+
+/// The query used to search transactions.
+///
+/// The date range can't be longer than 31 days.
+///
+/// ```
+/// use paypal_rs::data::transactions::{TransactionField, TransactionSearchQueryBuilder};
+///
+/// let query = TransactionSearchQueryBuilder::default()
+///     .start_date(chrono::Utc::now() - chrono::Duration::days(1))
+///     .end_date(chrono::Utc::now())
+///     .fields(vec![TransactionField::TransactionInfo, TransactionField::PayerInfo])
+///     .build()
+///     .unwrap();
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder, Clone)]
+#[builder(setter(strip_option, into))]
+pub struct TransactionSearchQuery {
+    /// Filters the transactions in the response by a PayPal transaction ID.
+    #[builder(default)]
+    pub transaction_id: Option<String>,
+    /// Filters the transactions in the response by a PayPal transaction event code.
+    #[builder(default)]
+    pub transaction_type: Option<String>,
+    /// Filters the transactions in the response by a PayPal transaction status code.
+    #[builder(default)]
+    pub transaction_status: Option<TransactionStatus>,
+    /// Filters the transactions in the response by a gross transaction amount range.
+    #[builder(default)]
+    pub transaction_amount: Option<AmountRange>,
+    /// Filters the transactions in the response by a currency code.
+    #[builder(default)]
+    pub transaction_currency: Option<String>,
+    /// Filters the transactions in the response by a start date and time.
+    pub start_date: chrono::DateTime<chrono::Utc>,
+    /// Filters the transactions in the response by an end date and time.
+    pub end_date: chrono::DateTime<chrono::Utc>,
+    /// Filters the transactions in the response by a payment instrument type.
+    #[builder(default)]
+    pub payment_instrument_type: Option<PaymentInstrumentType>,
+    /// Filters the transactions in the response by a store ID.
+    #[builder(default)]
+    pub store_id: Option<String>,
+    /// Filters the transactions in the response by a terminal ID.
+    #[builder(default)]
+    pub terminal_id: Option<String>,
+    /// The fields to include in the response. Defaults to the transaction information only.
+    #[builder(default)]
+    #[serde(serialize_with = "serialize_fields")]
+    pub fields: Option<Vec<TransactionField>>,
+    /// Whether the response includes only balance-impacting transactions or all transactions.
+    #[builder(default)]
+    #[serde(serialize_with = "serialize_flag")]
+    pub balance_affecting_records_only: Option<bool>,
+    /// The number of items to return in the response, up to 500.
+    #[builder(default)]
+    pub page_size: Option<i32>,
+    /// The zero-relative start index of the entire list of items that are returned in the response.
+    #[builder(default)]
+    pub page: Option<i32>,
+}
+
+/// The transaction information.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct TransactionInfo {
+    /// The ID of the PayPal account of the counterparty.
+    pub paypal_account_id: Option<String>,
+    /// The PayPal-generated transaction ID.
+    pub transaction_id: Option<String>,
+    /// The PayPal-generated base ID, used to link related transactions such as a capture and its refund.
+    pub paypal_reference_id: Option<String>,
+    /// The type of the reference ID, for example `TXN` or `ODR`.
+    pub paypal_reference_id_type: Option<String>,
+    /// The event code of the transaction, for example `T0006` for a PayPal checkout payment.
+    pub transaction_event_code: Option<String>,
+    /// The date and time when the transaction was processed.
+    pub transaction_initiation_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the transaction was last changed.
+    pub transaction_updated_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The transaction amount.
+    pub transaction_amount: Option<Money>,
+    /// The fee that PayPal charged for the transaction, as a negative amount.
+    pub fee_amount: Option<Money>,
+    /// The discount amount.
+    pub discount_amount: Option<Money>,
+    /// The insurance amount.
+    pub insurance_amount: Option<Money>,
+    /// The sales tax amount.
+    pub sales_tax_amount: Option<Money>,
+    /// The shipping amount.
+    pub shipping_amount: Option<Money>,
+    /// The shipping discount amount.
+    pub shipping_discount_amount: Option<Money>,
+    /// The shipping tax amount.
+    pub shipping_tax_amount: Option<Money>,
+    /// Any other amount, such as a gift wrap.
+    pub other_amount: Option<Money>,
+    /// The tip amount.
+    pub tip_amount: Option<Money>,
+    /// The status of the transaction.
+    pub transaction_status: Option<TransactionStatus>,
+    /// The subject of the payment.
+    pub transaction_subject: Option<String>,
+    /// A note from the payer about the transaction.
+    pub transaction_note: Option<String>,
+    /// The payment tracking ID, for payments made through the Direct Payment API.
+    pub payment_tracking_id: Option<String>,
+    /// The bank reference ID, for bank transfers.
+    pub bank_reference_id: Option<String>,
+    /// The balance of the account after the transaction.
+    pub ending_balance: Option<Money>,
+    /// The available balance of the account after the transaction.
+    pub available_balance: Option<Money>,
+    /// The invoice ID sent by the merchant with the transaction.
+    pub invoice_id: Option<String>,
+    /// The merchant-provided custom text.
+    pub custom_field: Option<String>,
+    /// The seller protection eligibility, `01` when eligible, `02` when not eligible and `03` when partially eligible.
+    pub protection_eligibility: Option<String>,
+    /// The credit term, for transactions paid with PayPal Credit.
+    pub credit_term: Option<String>,
+    /// The fee charged to the merchant for the credit offer.
+    pub credit_transactional_fee: Option<Money>,
+    /// The fee charged to the merchant for the promotional credit offer.
+    pub credit_promotional_fee: Option<Money>,
+    /// The annual percentage rate of the credit offer.
+    pub annual_percentage_rate: Option<String>,
+    /// The payment method used, for example `PUI` for pay upon invoice.
+    pub payment_method_type: Option<String>,
+    /// The type of the instrument used to fund the transaction.
+    pub instrument_type: Option<String>,
+    /// The sub type of the instrument used to fund the transaction.
+    pub instrument_sub_type: Option<String>,
+}
+
+/// The name of a party in a transaction.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct TransactionPartyName {
+    /// The given, or first, name.
+    pub given_name: Option<String>,
+    /// The surname or family name.
+    pub surname: Option<String>,
+    /// The full name, when it can't be split in parts.
+    pub alternate_full_name: Option<String>,
+}
+
+/// A phone number in a transaction.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct TransactionPhone {
+    /// The country calling code.
+    pub country_code: Option<String>,
+    /// The national number.
+    pub national_number: Option<String>,
+    /// The extension number.
+    pub extension_number: Option<String>,
+}
+
+/// A postal address in a transaction.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct TransactionAddress {
+    /// The first line of the address.
+    pub line1: Option<String>,
+    /// The second line of the address.
+    pub line2: Option<String>,
+    /// The city.
+    pub city: Option<String>,
+    /// The state or province.
+    pub state: Option<String>,
+    /// The two-character ISO 3166-1 country code.
+    pub country_code: Option<String>,
+    /// The postal code.
+    pub postal_code: Option<String>,
+}
+
+/// The payer information.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct PayerInfo {
+    /// The PayPal customer account ID.
+    pub account_id: Option<String>,
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The phone number of the payer.
+    pub phone_number: Option<TransactionPhone>,
+    /// The address status of the payer, `Y` when confirmed and `N` otherwise.
+    pub address_status: Option<String>,
+    /// The status of the payer, `Y` when verified and `N` otherwise.
+    pub payer_status: Option<String>,
+    /// The name of the payer.
+    pub payer_name: Option<TransactionPartyName>,
+    /// The two-character ISO 3166-1 country code of the payer.
+    pub country_code: Option<String>,
+    /// The address of the payer.
+    pub address: Option<TransactionAddress>,
+}
+
+/// The shipping information.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct ShippingInfo {
+    /// The recipient's name.
+    pub name: Option<String>,
+    /// The shipping method.
+    pub method: Option<String>,
+    /// The shipping address.
+    pub address: Option<TransactionAddress>,
+    /// The secondary shipping address.
+    pub secondary_shipping_address: Option<TransactionAddress>,
+}
+
+/// A tax amount applied to a cart item.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaxAmount {
+    /// The tax amount.
+    pub tax_amount: Money,
+}
+
+/// The details of an item in a cart.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct ItemDetail {
+    /// The item code.
+    pub item_code: Option<String>,
+    /// The item name.
+    pub item_name: Option<String>,
+    /// The item description.
+    pub item_description: Option<String>,
+    /// The item options.
+    pub item_options: Option<String>,
+    /// The number of purchased units, as a string.
+    pub item_quantity: Option<String>,
+    /// The price of a single unit.
+    pub item_unit_price: Option<Money>,
+    /// The amount for the item, the unit price times the quantity.
+    pub item_amount: Option<Money>,
+    /// The discount amount.
+    pub discount_amount: Option<Money>,
+    /// The adjustment amount.
+    pub adjustment_amount: Option<Money>,
+    /// The gift wrap amount.
+    pub gift_wrap_amount: Option<Money>,
+    /// The tax percentage.
+    pub tax_percentage: Option<String>,
+    /// The taxes applied to the item.
+    #[serde(default)]
+    pub tax_amounts: Vec<TaxAmount>,
+    /// The basic shipping amount.
+    pub basic_shipping_amount: Option<Money>,
+    /// The extended shipping amount.
+    pub extended_shipping_amount: Option<Money>,
+    /// The handling amount.
+    pub handling_amount: Option<Money>,
+    /// The insurance amount.
+    pub insurance_amount: Option<Money>,
+    /// The total amount of the item.
+    pub total_item_amount: Option<Money>,
+    /// The invoice number.
+    pub invoice_number: Option<String>,
+}
+
+/// The cart information.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct CartInfo {
+    /// The items in the cart.
+    #[serde(default)]
+    pub item_details: Vec<ItemDetail>,
+    /// Whether the item amounts include the tax.
+    pub tax_inclusive: Option<bool>,
+    /// The ID of the invoice, for invoicing transactions.
+    pub paypal_invoice_id: Option<String>,
+}
+
+/// The store information.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct StoreInfo {
+    /// The ID of the store.
+    pub store_id: Option<String>,
+    /// The ID of the terminal.
+    pub terminal_id: Option<String>,
+}
+
+/// The details of a transaction, each group is present when requested in the query fields.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct TransactionDetail {
+    /// The transaction information.
+    pub transaction_info: Option<TransactionInfo>,
+    /// The payer information.
+    pub payer_info: Option<PayerInfo>,
+    /// The shipping information.
+    pub shipping_info: Option<ShippingInfo>,
+    /// The cart information.
+    pub cart_info: Option<CartInfo>,
+    /// The store information.
+    pub store_info: Option<StoreInfo>,
+    /// The auction information.
+    pub auction_info: Option<serde_json::Value>,
+    /// The incentive information.
+    pub incentive_info: Option<serde_json::Value>,
+}
+
+/// The transactions matching a search.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TransactionSearchResponse {
+    /// The transaction details.
+    #[serde(default)]
+    pub transaction_details: Vec<TransactionDetail>,
+    /// The merchant account number.
+    pub account_number: Option<String>,
+    /// The start date and time of the search.
+    pub start_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The end date and time of the search.
+    pub end_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the data was last refreshed.
+    pub last_refreshed_datetime: Option<chrono::DateTime<chrono::Utc>>,
+    /// A zero-relative index of transactions.
+    pub page: Option<i32>,
+    /// The total number of transactions.
+    pub total_items: Option<i32>,
+    /// The total number of pages.
+    pub total_pages: Option<i32>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The query used to list balances.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Builder, Clone)]
+#[builder(setter(strip_option, into), default)]
+pub struct BalancesQuery {
+    /// The date and time at which to list the balances. Defaults to the latest balances.
+    pub as_of_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Filters the balances in the response by a currency. Defaults to every currency held by the account.
+    pub currency_code: Option<Currency>,
+}
+
+/// The balance of an account in a currency.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BalanceDetail {
+    /// The currency of the balance.
+    pub currency: Currency,
+    /// Whether this is the primary currency of the account.
+    pub primary: Option<bool>,
+    /// The total amount in the currency.
+    pub total_balance: Money,
+    /// The amount available to spend or withdraw.
+    pub available_balance: Option<Money>,
+    /// The amount on hold, for example because of a pending transaction or a dispute.
+    pub withheld_balance: Option<Money>,
+}
+
+/// The balances of an account.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BalancesResponse {
+    /// The balance per currency.
+    #[serde(default)]
+    pub balances: Vec<BalanceDetail>,
+    /// The PayPal payer ID of the account.
+    pub account_id: Option<String>,
+    /// The date and time at which the balances are reported.
+    pub as_of_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the balances were last refreshed.
+    pub last_refresh_time: Option<chrono::DateTime<chrono::Utc>>,
+}
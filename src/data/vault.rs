@@ -0,0 +1,483 @@
+//! Paypal object definitions used by the vault api.
+
+use crate::data::common::{Address, LinkDescription, LinkRel};
+use crate::data::orders::{CardBrand, PayerName, ShippingDetail, ShippingPreference};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The customer who owns the vaulted payment methods.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct VaultCustomer {
+    /// The PayPal-generated ID of the customer, set when saving more payment methods for an existing customer.
+    pub id: Option<String>,
+    /// The ID of the customer in the merchant system.
+    pub merchant_customer_id: Option<String>,
+}
+
+/// The card verification to run when vaulting a card.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VerificationMethod {
+    /// Runs 3D Secure when it is mandated in the region.
+    ScaWhenRequired,
+    /// Always runs 3D Secure.
+    ScaAlways,
+    /// Runs 3D Secure, for orders only.
+    #[serde(rename = "3D_SECURE")]
+    ThreeDSecure,
+    /// Verifies the address and the security code of the card, for orders only.
+    AvsCvv,
+}
+
+/// The verification of a card paying an order.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+pub struct CardVerification {
+    /// The verification method.
+    pub method: VerificationMethod,
+}
+
+/// The customization of the card vaulting experience.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct CardExperienceContext {
+    /// The label that overrides the business name in the PayPal account on the PayPal site.
+    pub brand_name: Option<String>,
+    /// The locale used to localize any error messages from the 3D Secure verification.
+    pub locale: Option<String>,
+    /// The URL where the customer is redirected after approving the 3D Secure verification.
+    pub return_url: Option<String>,
+    /// The URL where the customer is redirected after canceling the 3D Secure verification.
+    pub cancel_url: Option<String>,
+}
+
+/// A card to save in the vault.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct VaultCard {
+    /// The card holder's name as it appears on the card.
+    pub name: Option<String>,
+    /// The primary account number of the card.
+    pub number: Option<String>,
+    /// The card expiration year and month, in `YYYY-MM` format.
+    pub expiry: Option<String>,
+    /// The three- or four-digit security code of the card.
+    pub security_code: Option<String>,
+    /// The card brand or network.
+    pub brand: Option<CardBrand>,
+    /// The billing address of the card.
+    pub billing_address: Option<Address>,
+    /// The verification to run before vaulting the card.
+    pub verification_method: Option<VerificationMethod>,
+    /// The customization of the vaulting experience.
+    pub experience_context: Option<CardExperienceContext>,
+}
+
+/// The expected pattern of charges to a vaulted wallet.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UsagePattern {
+    /// On-demand instant payments, non-recurring, pre-paid or variable amount.
+    Immediate,
+    /// Pay after use, non-recurring, post-paid or variable amount.
+    Deferred,
+    /// Pay upfront, fixed or variable amount, on a fixed date.
+    RecurringPrepaid,
+    /// Pay after use, fixed amount, on a fixed date.
+    RecurringPostpaid,
+    /// Pay upfront, fixed or variable amount, as a threshold is reached.
+    ThresholdPrepaid,
+    /// Pay after use, fixed or variable amount, as a threshold is reached.
+    ThresholdPostpaid,
+}
+
+/// Who the vaulted payment method is saved for.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UsageType {
+    /// The payment method is saved for the merchant.
+    Merchant,
+    /// The payment method is saved for the platform, to be used across its merchants.
+    Platform,
+    /// A type unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The type of the customer saving a wallet.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CustomerType {
+    /// A consumer.
+    Consumer,
+    /// A business.
+    Business,
+    /// A type unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The customization of the wallet vaulting experience.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct WalletExperienceContext {
+    /// The label that overrides the business name in the PayPal account on the PayPal site.
+    pub brand_name: Option<String>,
+    /// The locale of the pages displayed to the customer, for example `en-US`.
+    pub locale: Option<String>,
+    /// The location from which the shipping address is derived.
+    pub shipping_preference: Option<ShippingPreference>,
+    /// The URL where the customer is redirected after approving the vaulting.
+    pub return_url: Option<String>,
+    /// The URL where the customer is redirected after canceling the vaulting.
+    pub cancel_url: Option<String>,
+}
+
+/// A PayPal or Venmo wallet to save in the vault.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct VaultWallet {
+    /// The description displayed to the customer on the consent approval.
+    pub description: Option<String>,
+    /// The expected pattern of charges.
+    pub usage_pattern: Option<UsagePattern>,
+    /// Who the wallet is saved for.
+    pub usage_type: Option<UsageType>,
+    /// The type of the customer.
+    pub customer_type: Option<CustomerType>,
+    /// Whether to create a new payment token even if the customer already has one for this wallet.
+    pub permit_multiple_payment_tokens: Option<bool>,
+    /// The customization of the vaulting experience.
+    pub experience_context: Option<WalletExperienceContext>,
+}
+
+/// The payment method to save in the vault, only one of them must be set.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct SetupTokenPaymentSource {
+    /// A card.
+    pub card: Option<VaultCard>,
+    /// A PayPal wallet.
+    pub paypal: Option<VaultWallet>,
+    /// A Venmo wallet.
+    pub venmo: Option<VaultWallet>,
+}
+
+/// The payload used to create a setup token.
+///
+/// ```
+/// use paypal_rs::data::vault::*;
+///
+/// let payload = SetupTokenPayloadBuilder::default()
+///     .customer(VaultCustomerBuilder::default().merchant_customer_id("customer-1234").build().unwrap())
+///     .payment_source(
+///         SetupTokenPaymentSourceBuilder::default()
+///             .paypal(
+///                 VaultWalletBuilder::default()
+///                     .usage_type(UsageType::Merchant)
+///                     .usage_pattern(UsagePattern::Immediate)
+///                     .build()
+///                     .unwrap(),
+///             )
+///             .build()
+///             .unwrap(),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct SetupTokenPayload {
+    /// The customer the payment method is saved for. A new customer is created when not set.
+    pub customer: Option<VaultCustomer>,
+    /// The payment method to save.
+    pub payment_source: SetupTokenPaymentSource,
+}
+
+/// The status of a setup token.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SetupTokenStatus {
+    /// The setup token was created.
+    Created,
+    /// The payer must approve the vaulting, by following the `approve` link.
+    PayerActionRequired,
+    /// The payer approved the vaulting, a payment token can be created from the setup token.
+    Approved,
+    /// The payment method was saved.
+    Vaulted,
+    /// The payment method was tokenized.
+    Tokenized,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A setup token, a temporary reference to a payment method pending to be saved.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SetupToken {
+    /// The ID of the setup token.
+    pub id: String,
+    /// The customer the payment method is saved for.
+    pub customer: Option<VaultCustomer>,
+    /// The status of the setup token.
+    pub status: Option<SetupTokenStatus>,
+    /// The payment method to save.
+    pub payment_source: Option<PaymentTokenSource>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl SetupToken {
+    /// Returns the URL where the payer approves saving the payment method, from the `approve` HATEOAS link.
+    pub fn approve_url(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.rel == Some(LinkRel::Approve))
+            .map(|link| link.href.as_str())
+    }
+
+    /// Whether the payer approved saving the payment method, so a payment token can be created.
+    pub fn is_approved(&self) -> bool {
+        self.status == Some(SetupTokenStatus::Approved)
+    }
+}
+
+/// The type of token used as the source of a payment token.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TokenType {
+    /// A setup token.
+    SetupToken,
+    /// A type unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The token from which a payment token is created.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TokenSource {
+    /// The ID of the token.
+    pub id: String,
+    /// The type of the token.
+    #[serde(rename = "type")]
+    pub token_type: TokenType,
+}
+
+/// The source of a payment token.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PaymentTokenPayloadSource {
+    /// The approved token to exchange.
+    pub token: TokenSource,
+}
+
+/// The payload used to create a payment token.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PaymentTokenPayload {
+    /// The customer the payment token is saved for.
+    pub customer: Option<VaultCustomer>,
+    /// The source of the payment token.
+    pub payment_source: PaymentTokenPayloadSource,
+}
+
+impl PaymentTokenPayload {
+    /// Creates the payload exchanging the given approved setup token.
+    pub fn from_setup_token(setup_token_id: impl ToString) -> Self {
+        Self {
+            customer: None,
+            payment_source: PaymentTokenPayloadSource {
+                token: TokenSource {
+                    id: setup_token_id.to_string(),
+                    token_type: TokenType::SetupToken,
+                },
+            },
+        }
+    }
+}
+
+/// A vaulted card.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct VaultCardResponse {
+    /// The card holder's name as it appears on the card.
+    pub name: Option<String>,
+    /// The last digits of the card number.
+    pub last_digits: Option<String>,
+    /// The card brand or network.
+    pub brand: Option<CardBrand>,
+    /// The card expiration year and month, in `YYYY-MM` format.
+    pub expiry: Option<String>,
+    /// The billing address of the card.
+    pub billing_address: Option<Address>,
+}
+
+/// A vaulted PayPal or Venmo wallet.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct VaultWalletResponse {
+    /// The description displayed to the customer on the consent approval.
+    pub description: Option<String>,
+    /// The expected pattern of charges.
+    pub usage_pattern: Option<UsagePattern>,
+    /// The shipping address of the payer.
+    pub shipping: Option<ShippingDetail>,
+    /// Whether multiple payment tokens can be created for this wallet.
+    pub permit_multiple_payment_tokens: Option<bool>,
+    /// Who the wallet is saved for.
+    pub usage_type: Option<UsageType>,
+    /// The type of the customer.
+    pub customer_type: Option<CustomerType>,
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The PayPal payer ID.
+    pub payer_id: Option<String>,
+    /// The name of the payer.
+    pub name: Option<PayerName>,
+    /// The username of the Venmo user.
+    pub user_name: Option<String>,
+}
+
+/// The vaulted payment method, only one of them is set.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct PaymentTokenSource {
+    /// A card.
+    pub card: Option<VaultCardResponse>,
+    /// A PayPal wallet.
+    pub paypal: Option<VaultWalletResponse>,
+    /// A Venmo wallet.
+    pub venmo: Option<VaultWalletResponse>,
+}
+
+/// A payment token, a permanent reference to a vaulted payment method.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PaymentToken {
+    /// The ID of the payment token, used as `vault_id` when charging it.
+    pub id: String,
+    /// The customer the payment method is saved for.
+    pub customer: Option<VaultCustomer>,
+    /// The vaulted payment method.
+    pub payment_source: Option<PaymentTokenSource>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The query used to list the payment tokens of a customer.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Builder, Clone)]
+#[builder(setter(strip_option, into))]
+pub struct PaymentTokensQuery {
+    /// The ID of the customer.
+    pub customer_id: String,
+    /// The number of payment tokens to return in a page.
+    #[builder(default)]
+    pub page_size: Option<i32>,
+    /// The page to return, starting at 1.
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// Whether to include the total number of items and pages in the response.
+    #[builder(default)]
+    pub total_required: Option<bool>,
+}
+
+/// A page of the payment tokens of a customer.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PaymentTokenList {
+    /// The customer the payment tokens belong to.
+    pub customer: Option<VaultCustomer>,
+    /// The payment tokens.
+    #[serde(default)]
+    pub payment_tokens: Vec<PaymentToken>,
+    /// The total number of payment tokens, when requested.
+    pub total_items: Option<i32>,
+    /// The total number of pages, when requested.
+    pub total_pages: Option<i32>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// When to save a payment method used in an order.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StoreInVault {
+    /// Saves the payment method once the payment succeeds.
+    #[default]
+    OnSuccess,
+}
+
+/// The instruction to save the payment method of an order in the vault.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct VaultInstruction {
+    /// When to save the payment method.
+    pub store_in_vault: StoreInVault,
+    /// The description displayed to the customer on the consent approval, for wallets.
+    pub description: Option<String>,
+    /// The expected pattern of charges, for wallets.
+    pub usage_pattern: Option<UsagePattern>,
+    /// Who the payment method is saved for, for wallets.
+    pub usage_type: Option<UsageType>,
+    /// The type of the customer, for wallets.
+    pub customer_type: Option<CustomerType>,
+    /// Whether to create a new payment token even if the customer already has one for this wallet.
+    pub permit_multiple_payment_tokens: Option<bool>,
+}
+
+/// The additional attributes of an order payment source.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PaymentSourceAttributes {
+    /// The customer the payment method is saved for. A new customer is created when not set.
+    pub customer: Option<VaultCustomer>,
+    /// The instruction to save the payment method.
+    pub vault: Option<VaultInstruction>,
+    /// The verification of the card before the payment, for card payment sources only.
+    pub verification: Option<CardVerification>,
+}
+
+/// The status of a payment method saved during an order.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VaultStatus {
+    /// The payment method was saved.
+    Vaulted,
+    /// The vaulting was requested, it completes with the payment.
+    Created,
+    /// The payer approved saving the payment method.
+    Approved,
+    /// A status unknown to this crate, with the value sent by PayPal.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The outcome of saving the payment method of an order.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PaymentSourceVault {
+    /// The ID of the payment token, used as `vault_id` for later charges.
+    pub id: Option<String>,
+    /// The status of the vaulting.
+    pub status: Option<VaultStatus>,
+    /// The customer the payment method is saved for.
+    pub customer: Option<VaultCustomer>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
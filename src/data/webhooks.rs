@@ -0,0 +1,123 @@
+//! Paypal object definitions used by webhooks.
+//!
+//! Webhook notifications share a common envelope, the [WebhookEvent], whose `resource` depends on the event type.
+
+use crate::data::common::LinkDescription;
+use crate::data::disputes::Dispute;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A dispute was created.
+pub const CUSTOMER_DISPUTE_CREATED: &str = "CUSTOMER.DISPUTE.CREATED";
+/// A dispute was resolved.
+pub const CUSTOMER_DISPUTE_RESOLVED: &str = "CUSTOMER.DISPUTE.RESOLVED";
+/// A dispute was updated.
+pub const CUSTOMER_DISPUTE_UPDATED: &str = "CUSTOMER.DISPUTE.UPDATED";
+
+/// A webhook event notification.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WebhookEvent {
+    /// The ID of the webhook event notification.
+    pub id: String,
+    /// The date and time when the webhook event notification was created.
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The name of the resource related to the webhook notification event.
+    pub resource_type: Option<String>,
+    /// The event version in the webhook notification.
+    pub event_version: Option<String>,
+    /// The event that triggered the webhook event notification, for example `CUSTOMER.DISPUTE.CREATED`.
+    pub event_type: String,
+    /// A summary description for the event notification.
+    pub summary: Option<String>,
+    /// The resource version in the webhook notification.
+    pub resource_version: Option<String>,
+    /// The resource that triggered the webhook event notification.
+    pub resource: serde_json::Value,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The typed resource of a [WebhookEvent].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum WebhookResource {
+    /// The resource of a `CUSTOMER.DISPUTE.*` event.
+    Dispute(Box<Dispute>),
+    /// The resource of an event that is not mapped to a type yet.
+    Other(serde_json::Value),
+}
+
+impl WebhookEvent {
+    /// Deserializes the resource into the type matching the event type.
+    pub fn parse_resource(&self) -> Result<WebhookResource, serde_json::Error> {
+        match self.event_type.as_str() {
+            CUSTOMER_DISPUTE_CREATED | CUSTOMER_DISPUTE_RESOLVED | CUSTOMER_DISPUTE_UPDATED => Ok(
+                WebhookResource::Dispute(Box::new(serde_json::from_value(self.resource.clone())?)),
+            ),
+            _ => Ok(WebhookResource::Other(self.resource.clone())),
+        }
+    }
+
+    /// Returns the dispute of a `CUSTOMER.DISPUTE.*` event, or `None` for any other event.
+    pub fn dispute(&self) -> Option<Result<Dispute, serde_json::Error>> {
+        match self.parse_resource() {
+            Ok(WebhookResource::Dispute(dispute)) => Some(Ok(*dispute)),
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::disputes::{DisputeLifeCycleStage, DisputeOutcomeCode, DisputeReason, DisputeStatus};
+
+    #[test]
+    fn test_dispute_resolved_event() {
+        let event: WebhookEvent = serde_json::from_value(serde_json::json!({
+            "id": "WH-6HE329230C693231F-5WV60586YA659351G",
+            "create_time": "2018-06-21T13:36:33.000Z",
+            "resource_type": "dispute",
+            "event_type": "CUSTOMER.DISPUTE.RESOLVED",
+            "summary": "A dispute was resolved with case # PP-000-042-663-135",
+            "resource": {
+                "dispute_id": "PP-000-042-663-135",
+                "reason": "MERCHANDISE_OR_SERVICE_NOT_RECEIVED",
+                "status": "RESOLVED",
+                "dispute_outcome": { "outcome_code": "RESOLVED_BUYER_FAVOUR" },
+                "dispute_life_cycle_stage": "CHARGEBACK",
+                "dispute_channel": "INTERNAL"
+            },
+            "links": []
+        }))
+        .unwrap();
+
+        let dispute = event.dispute().unwrap().unwrap();
+        assert_eq!(dispute.reason, Some(DisputeReason::MerchandiseOrServiceNotReceived));
+        assert_eq!(dispute.status, Some(DisputeStatus::Resolved));
+        assert_eq!(
+            dispute.dispute_life_cycle_stage,
+            Some(DisputeLifeCycleStage::Chargeback)
+        );
+        assert_eq!(
+            dispute.dispute_outcome.and_then(|outcome| outcome.outcome_code),
+            Some(DisputeOutcomeCode::ResolvedBuyerFavour)
+        );
+    }
+
+    #[test]
+    fn test_unmapped_event() {
+        let event: WebhookEvent = serde_json::from_value(serde_json::json!({
+            "id": "WH-2WR32451HC0233532-67976317FL4543714",
+            "event_type": "PAYMENT.CAPTURE.COMPLETED",
+            "resource": { "id": "42311647XV020574X" }
+        }))
+        .unwrap();
+
+        assert!(event.dispute().is_none());
+        assert!(matches!(event.parse_resource().unwrap(), WebhookResource::Other(_)));
+    }
+}
@@ -0,0 +1,199 @@
+//! Storable descriptions of endpoint invocations.
+//!
+//! A [RequestDescriptor] captures everything needed to perform a request (operation name, method, path, headers
+//! and body) in a serializable form. This allows committing a PayPal mutation to a database along with the rest of a
+//! transaction and sending it later, known as the transactional outbox pattern.
+//!
+//! ```
+//! use paypal_rs::{api::orders::CaptureOrder, descriptor::RequestDescriptor};
+//!
+//! let descriptor = RequestDescriptor::from_endpoint(&CaptureOrder::new("5O190127TN364715T"))
+//!     .unwrap()
+//!     .with_request_id("capture-5O190127TN364715T");
+//! assert_eq!(descriptor.operation, "CaptureOrder");
+//!
+//! let stored = serde_json::to_string(&descriptor).unwrap();
+//! let restored: RequestDescriptor = serde_json::from_str(&stored).unwrap();
+//! assert_eq!(descriptor, restored);
+//! ```
+
+use std::borrow::Cow;
+
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    HeaderParams, Prefer,
+    endpoint::{self, Endpoint},
+    errors::DescriptorError,
+};
+
+/// A serializable description of an endpoint invocation, which can be executed later.
+///
+/// The response is returned as a [serde_json::Value], which can be converted to the original
+/// endpoint response type with [serde_json::from_value].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequestDescriptor {
+    /// The name of the operation, see [Endpoint::operation_name].
+    pub operation: String,
+    /// The request method.
+    #[serde(with = "method")]
+    pub method: reqwest::Method,
+    /// The relative path of the request, including the query string.
+    pub path: String,
+    /// The json body of the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<serde_json::Value>,
+    /// The value sent on the `PayPal-Request-Id` header, so that sending the request more than once
+    /// does not perform the operation twice.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// How much of the resource the response contains, see [Endpoint::prefer].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefer: Option<Prefer>,
+    /// The other headers of the endpoint, like the API version headers of [Versioned](crate::endpoint::Versioned).
+    #[serde(default, with = "headers", skip_serializing_if = "HeaderMap::is_empty")]
+    pub headers: HeaderMap,
+}
+
+impl RequestDescriptor {
+    /// Captures the invocation of the given endpoint.
+    ///
+    /// Fails if the body can't be serialized, if the endpoint uploads files, since those can't be stored,
+    /// or if it sets headers other than its request id, [Endpoint::prefer] and [Endpoint::extra_headers].
+    pub fn from_endpoint<E: Endpoint>(endpoint: &E) -> Result<Self, DescriptorError> {
+        if endpoint.multipart().is_some() {
+            return Err(DescriptorError::Multipart);
+        }
+
+        let HeaderParams {
+            merchant_payer_id,
+            client_metadata_id,
+            partner_attribution_id,
+            request_id,
+            content_type,
+            prefer,
+            mock_application_code,
+        } = endpoint.headers();
+        let unsupported = [
+            ("PayPal-Auth-Assertion", merchant_payer_id.is_some()),
+            ("PayPal-Client-Metadata-Id", client_metadata_id.is_some()),
+            ("PayPal-Partner-Attribution-Id", partner_attribution_id.is_some()),
+            ("Content-Type", content_type.is_some()),
+            ("PayPal-Mock-Response", mock_application_code.is_some()),
+        ];
+        if let Some((name, _)) = unsupported.into_iter().find(|(_, set)| *set) {
+            return Err(DescriptorError::Header(name.to_string()));
+        }
+
+        let headers = endpoint.extra_headers();
+        if let Some((name, _)) = headers.iter().find(|(_, value)| value.to_str().is_err()) {
+            return Err(DescriptorError::Header(name.to_string()));
+        }
+
+        let body = endpoint
+            .body()
+            .map(|body| serde_json::to_value(&body))
+            .transpose()
+            .map_err(DescriptorError::Serialize)?;
+
+        Ok(Self {
+            operation: endpoint.operation_name().into_owned(),
+            method: endpoint.method(),
+            path: endpoint::path_and_query(endpoint),
+            body,
+            request_id,
+            prefer,
+            headers,
+        })
+    }
+
+    /// Sets the `PayPal-Request-Id` header used when executing this request.
+    pub fn with_request_id(mut self, request_id: impl ToString) -> Self {
+        self.request_id = Some(request_id.to_string());
+        self
+    }
+}
+
+impl Endpoint for RequestDescriptor {
+    type Query = ();
+
+    type Body = serde_json::Value;
+
+    type Response = serde_json::Value;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.path)
+    }
+
+    fn method(&self) -> reqwest::Method {
+        self.method.clone()
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        self.body.clone()
+    }
+
+    fn request_id(&self) -> Option<Cow<'_, str>> {
+        self.request_id.as_deref().map(Cow::Borrowed)
+    }
+
+    fn prefer(&self) -> Option<Prefer> {
+        self.prefer
+    }
+
+    fn headers(&self) -> HeaderParams {
+        HeaderParams {
+            request_id: self.request_id.clone(),
+            prefer: self.prefer,
+            ..Default::default()
+        }
+    }
+
+    fn extra_headers(&self) -> HeaderMap {
+        self.headers.clone()
+    }
+
+    fn operation_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.operation)
+    }
+}
+
+mod method {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(method: &reqwest::Method, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(method.as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<reqwest::Method, D::Error> {
+        let method = String::deserialize(deserializer)?;
+        reqwest::Method::from_bytes(method.as_bytes()).map_err(D::Error::custom)
+    }
+}
+
+mod headers {
+    use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+    use serde::{Deserialize, Deserializer, Serializer, de::Error, ser};
+    use std::collections::BTreeMap;
+
+    pub fn serialize<S: Serializer>(headers: &HeaderMap, serializer: S) -> Result<S::Ok, S::Error> {
+        let headers = headers
+            .iter()
+            .map(|(name, value)| Ok((name.as_str(), value.to_str().map_err(ser::Error::custom)?)))
+            .collect::<Result<BTreeMap<_, _>, S::Error>>()?;
+        serializer.collect_map(headers)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HeaderMap, D::Error> {
+        BTreeMap::<String, String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(name, value)| {
+                Ok((
+                    HeaderName::try_from(name).map_err(D::Error::custom)?,
+                    HeaderValue::try_from(value).map_err(D::Error::custom)?,
+                ))
+            })
+            .collect()
+    }
+}
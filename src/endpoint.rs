@@ -1,5 +1,7 @@
 //! This module contains the endpoint trait used to implemented api endpoints.
 
+use crate::{HeaderParams, Prefer};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::{Serialize, de::DeserializeOwned};
 use std::borrow::Cow;
 
@@ -10,10 +12,12 @@ pub trait Endpoint {
     /// The serializable body type.
     type Body: Serialize;
     /// The deserializable response type.
+    ///
+    /// Use `()` for endpoints answering without a body, and `Option<T>` for the ones that only sometimes do.
     type Response: DeserializeOwned;
 
     /// The endpoint relative path. Must start with a `/`
-    fn relative_path(&self) -> Cow<str>;
+    fn relative_path(&self) -> Cow<'_, str>;
 
     /// The request method of this endpoint.
     fn method(&self) -> reqwest::Method;
@@ -27,4 +31,290 @@ pub trait Endpoint {
     fn body(&self) -> Option<Self::Body> {
         None
     }
+
+    /// The multipart form to be sent instead of a json body, used by endpoints that upload files.
+    ///
+    /// When this returns `Some`, [Endpoint::body] is ignored.
+    fn multipart(&self) -> Option<reqwest::multipart::Form> {
+        None
+    }
+
+    /// The `PayPal-Request-Id` sent with this endpoint, which makes retrying it safe:
+    /// PayPal performs the operation once and returns the same result to every request with the same id.
+    ///
+    /// See [Idempotent] to add one to any endpoint.
+    fn request_id(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    /// How much of the resource the response contains, overriding the `Prefer` of the
+    /// [Client::default_headers](crate::Client::default_headers).
+    ///
+    /// When neither sets it, [Prefer::Representation] is sent.
+    fn prefer(&self) -> Option<Prefer> {
+        None
+    }
+
+    /// The headers this endpoint requires, like `PayPal-Client-Metadata-Id`.
+    ///
+    /// Defaults to the [Endpoint::request_id] and [Endpoint::prefer] of the endpoint.
+    /// The headers given to [Client::execute_ext](crate::Client::execute_ext) take precedence over these.
+    fn headers(&self) -> HeaderParams {
+        HeaderParams {
+            request_id: self.request_id().map(Cow::into_owned),
+            prefer: self.prefer(),
+            ..Default::default()
+        }
+    }
+
+    /// Other headers sent with this endpoint, like the API version headers of early access features.
+    ///
+    /// These take precedence over the headers set by the client.
+    fn extra_headers(&self) -> HeaderMap {
+        HeaderMap::new()
+    }
+
+    /// A name identifying the operation performed by this endpoint, like `CreateOrder`.
+    ///
+    /// Defaults to the name of the implementing type.
+    fn operation_name(&self) -> Cow<'_, str> {
+        let name = std::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+        Cow::Borrowed(name.rsplit("::").next().unwrap_or(name))
+    }
+}
+
+/// Sends the wrapped endpoint with a `PayPal-Request-Id`, so it can be retried without performing the operation twice.
+///
+/// Keep the request id around until the operation is known to have succeeded, and use the same one when retrying it.
+///
+/// ```
+/// use paypal_rs::{api::orders::CaptureOrder, endpoint::{Endpoint, Idempotent}};
+///
+/// let capture = Idempotent::generate(CaptureOrder::new("5O190127TN364715T"));
+/// assert_eq!(capture.request_id().as_deref(), Some(capture.request_id.as_str()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Idempotent<E> {
+    /// The wrapped endpoint.
+    pub endpoint: E,
+    /// The request id sent with the endpoint.
+    pub request_id: String,
+}
+
+impl<E: Endpoint> Idempotent<E> {
+    /// Sends the endpoint with the given request id.
+    pub fn new(endpoint: E, request_id: impl ToString) -> Self {
+        Self {
+            endpoint,
+            request_id: request_id.to_string(),
+        }
+    }
+
+    /// Sends the endpoint with a new random request id.
+    pub fn generate(endpoint: E) -> Self {
+        Self::new(endpoint, uuid::Uuid::new_v4())
+    }
+}
+
+impl<E: Endpoint> Endpoint for Idempotent<E> {
+    type Query = E::Query;
+
+    type Body = E::Body;
+
+    type Response = E::Response;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        self.endpoint.relative_path()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        self.endpoint.method()
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        self.endpoint.query()
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        self.endpoint.body()
+    }
+
+    fn multipart(&self) -> Option<reqwest::multipart::Form> {
+        self.endpoint.multipart()
+    }
+
+    fn request_id(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(&self.request_id))
+    }
+
+    fn prefer(&self) -> Option<Prefer> {
+        self.endpoint.prefer()
+    }
+
+    fn headers(&self) -> HeaderParams {
+        HeaderParams {
+            request_id: Some(self.request_id.clone()),
+            ..self.endpoint.headers()
+        }
+    }
+
+    fn extra_headers(&self) -> HeaderMap {
+        self.endpoint.extra_headers()
+    }
+
+    fn operation_name(&self) -> Cow<'_, str> {
+        self.endpoint.operation_name()
+    }
+}
+
+/// Sends the wrapped endpoint to another version of the API, or with other headers,
+/// to use early access versions of PayPal APIs without waiting for new endpoints.
+///
+/// ```
+/// use paypal_rs::{api::orders::ShowOrderDetails, endpoint::{Endpoint, Versioned}};
+///
+/// let show = Versioned::new(ShowOrderDetails::new("5O190127TN364715T"))
+///     .version("v3")
+///     .header("PayPal-Api-Version", "2025-01-01");
+/// assert_eq!(show.relative_path(), "/v3/checkout/orders/5O190127TN364715T");
+/// assert_eq!(show.extra_headers()["PayPal-Api-Version"], "2025-01-01");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Versioned<E> {
+    /// The wrapped endpoint.
+    pub endpoint: E,
+    /// The version replacing the one in the path of the endpoint, like `v3`.
+    pub version: Option<String>,
+    /// The headers sent along with the ones of the endpoint.
+    pub headers: HeaderMap,
+}
+
+impl<E: Endpoint> Versioned<E> {
+    /// Sends the endpoint unchanged, until a version or headers are set.
+    pub fn new(endpoint: E) -> Self {
+        Self {
+            endpoint,
+            version: None,
+            headers: HeaderMap::new(),
+        }
+    }
+
+    /// Replaces the version segment of the path, like `v2` in `/v2/checkout/orders`, with the given one.
+    pub fn version(mut self, version: impl ToString) -> Self {
+        self.version = Some(version.to_string());
+        self
+    }
+
+    /// Sends the given header along with the ones of the endpoint.
+    ///
+    /// # Panics
+    ///
+    /// If the name or the value is not a valid header.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        let name = HeaderName::try_from(name).expect("a valid header name");
+        let value = HeaderValue::try_from(value).expect("a valid header value");
+        self.headers.insert(name, value);
+        self
+    }
+}
+
+impl<E: Endpoint> Endpoint for Versioned<E> {
+    type Query = E::Query;
+
+    type Body = E::Body;
+
+    type Response = E::Response;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        let path = self.endpoint.relative_path();
+        let Some(version) = &self.version else {
+            return path;
+        };
+
+        let rest = path.strip_prefix('/').unwrap_or(&path);
+        match rest.split_once('/') {
+            Some((segment, rest)) if is_version(segment) => Cow::Owned(format!("/{}/{}", version, rest)),
+            _ => path,
+        }
+    }
+
+    fn method(&self) -> reqwest::Method {
+        self.endpoint.method()
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        self.endpoint.query()
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        self.endpoint.body()
+    }
+
+    fn multipart(&self) -> Option<reqwest::multipart::Form> {
+        self.endpoint.multipart()
+    }
+
+    fn request_id(&self) -> Option<Cow<'_, str>> {
+        self.endpoint.request_id()
+    }
+
+    fn prefer(&self) -> Option<Prefer> {
+        self.endpoint.prefer()
+    }
+
+    fn headers(&self) -> HeaderParams {
+        self.endpoint.headers()
+    }
+
+    fn extra_headers(&self) -> HeaderMap {
+        let mut headers = self.endpoint.extra_headers();
+        headers.extend(self.headers.clone());
+        headers
+    }
+
+    fn operation_name(&self) -> Cow<'_, str> {
+        self.endpoint.operation_name()
+    }
+}
+
+/// Whether the path segment is an API version, like `v2`.
+fn is_version(segment: &str) -> bool {
+    segment
+        .strip_prefix('v')
+        .is_some_and(|number| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Returns the relative path of the endpoint along with its query string, if any.
+pub(crate) fn path_and_query<E: Endpoint + ?Sized>(endpoint: &E) -> String {
+    let mut path = endpoint.relative_path().into_owned();
+
+    if let Some(query) = endpoint.query() {
+        let query_string = serde_qs::to_string(&query).expect("serialize the query correctly");
+        if !query_string.is_empty() {
+            path.push('?');
+            path.push_str(&query_string);
+        }
+    }
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Query, api::invoice::ListInvoices};
+
+    #[test]
+    fn test_path_and_query() {
+        let list = ListInvoices::new(Query::default());
+        assert_eq!(path_and_query(&list), "/v2/invoicing/invoices");
+
+        let list = ListInvoices::new(Query {
+            page: Some(2),
+            page_size: Some(20),
+            ..Default::default()
+        });
+        assert_eq!(path_and_query(&list), "/v2/invoicing/invoices?page=2&page_size=20");
+    }
 }
@@ -1,32 +1,105 @@
 //! Errors created by this crate.
-use crate::data::common::LinkDescription;
+use crate::data::{
+    common::{Currency, LinkDescription, Money, UpcType},
+    orders::Order,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
+use std::time::Duration;
 
 /// A paypal api response error.
+///
+/// <https://developer.paypal.com/api/rest/responses/#link-failedrequests>
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PaypalError {
-    /// The error name.
+    /// The error name, like `UNPROCESSABLE_ENTITY`.
+    #[serde(default)]
     pub name: String,
     /// The error message.
     pub message: Option<String>,
-    /// Paypal debug id
+    /// Paypal debug id, needed by PayPal support to look into a failed request.
     pub debug_id: Option<String>,
     /// Error details
-    pub details: Vec<HashMap<String, String>>,
+    #[serde(default)]
+    pub details: Vec<ErrorDetail>,
     /// Only available on Identity errors
     pub error: Option<String>,
     /// Only available on Identity errors
     pub error_description: Option<String>,
     /// Links with more information about the error.
+    #[serde(default)]
     pub links: Vec<LinkDescription>,
+    /// How long to wait before trying again, sent along with rate limit errors.
+    #[serde(skip)]
+    pub retry_after: Option<Duration>,
+    /// The HTTP status of the response the error was received in.
+    #[serde(skip)]
+    pub status: Option<reqwest::StatusCode>,
+    /// The error response as it was received, including the fields not known by this crate.
+    #[serde(skip)]
+    pub raw: serde_json::Value,
+}
+
+/// The details of an error, usually about one field of the request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorDetail {
+    /// The field that caused the error, as a JSON pointer.
+    pub field: Option<String>,
+    /// The value of the field that caused the error.
+    pub value: Option<String>,
+    /// Where the field that caused the error is, like `body`, `path` or `query`.
+    pub location: Option<String>,
+    /// The unique fine-grained application-level error code, like `INSTRUMENT_DECLINED`.
+    #[serde(default)]
+    pub issue: String,
+    /// The human-readable description of the issue.
+    pub description: Option<String>,
+    /// Links with more information about the issue.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl PaypalError {
+    /// Parses an error response body, keeping it in [PaypalError::raw].
+    pub fn from_json(body: &[u8]) -> Result<Self, serde_json::Error> {
+        let raw: serde_json::Value = serde_json::from_slice(body)?;
+        let mut error: PaypalError = serde_json::from_value(raw.clone())?;
+        // Identity errors only have an `error` code.
+        if error.name.is_empty()
+            && let Some(code) = &error.error
+        {
+            error.name = code.clone();
+        }
+        error.raw = raw;
+        Ok(error)
+    }
+
+    /// Whether any of the details has the given issue, like `INSTRUMENT_DECLINED`.
+    pub fn has_issue(&self, issue: &str) -> bool {
+        self.details.iter().any(|detail| detail.issue == issue)
+    }
 }
 
 impl fmt::Display for PaypalError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:#?}", self)
+        write!(f, "{}", self.name)?;
+        if let Some(message) = self.message.as_ref().or(self.error_description.as_ref()) {
+            write!(f, ": {}", message)?;
+        }
+        if let Some(debug_id) = &self.debug_id {
+            write!(f, " (debug id: {})", debug_id)?;
+        }
+        for detail in &self.details {
+            write!(f, "\n{}", detail.issue)?;
+            if let Some(field) = &detail.field {
+                write!(f, " at {}", field)?;
+            }
+            if let Some(description) = &detail.description {
+                write!(f, ": {}", description)?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -34,11 +107,24 @@ impl Error for PaypalError {}
 
 /// A response error, it may be paypal related or an error related to the http request itself.
 #[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
 pub enum ResponseError {
     /// A paypal api error.
     ApiError(PaypalError),
     /// A http error.
     HttpError(reqwest::Error),
+    /// The response body could not be deserialized.
+    ParseError(serde_json::Error),
+    /// The request, including its retries, took longer than the [Client::deadline](crate::Client::deadline).
+    DeadlineExceeded(Duration),
+    /// No credentials were found for the tenant, see [MultiTenantClient](crate::tenant::MultiTenantClient).
+    UnknownTenant(String),
+    /// The request wasn't sent because too many requests failed recently, see [CircuitBreaker](crate::circuit_breaker::CircuitBreaker).
+    CircuitOpen,
+    /// The request was aborted by its cancellation signal, see [Client::execute_until](crate::Client::execute_until).
+    Cancelled,
+    /// The link can't be followed because its target isn't a valid URL, see [Client::follow](crate::Client::follow).
+    InvalidLink(String),
 }
 
 impl fmt::Display for ResponseError {
@@ -46,6 +132,12 @@ impl fmt::Display for ResponseError {
         match self {
             ResponseError::ApiError(e) => write!(f, "{}", e),
             ResponseError::HttpError(e) => write!(f, "{}", e),
+            ResponseError::ParseError(e) => write!(f, "{}", e),
+            ResponseError::DeadlineExceeded(deadline) => write!(f, "the request took longer than {:?}", deadline),
+            ResponseError::UnknownTenant(tenant) => write!(f, "no credentials for the tenant {}", tenant),
+            ResponseError::CircuitOpen => write!(f, "the circuit breaker is open after too many failed requests"),
+            ResponseError::Cancelled => write!(f, "the request was cancelled"),
+            ResponseError::InvalidLink(href) => write!(f, "{:?} is not a valid link", href),
         }
     }
 }
@@ -55,6 +147,100 @@ impl Error for ResponseError {
         match self {
             ResponseError::ApiError(e) => Some(e),
             ResponseError::HttpError(e) => Some(e),
+            ResponseError::ParseError(e) => Some(e),
+            ResponseError::DeadlineExceeded(_)
+            | ResponseError::UnknownTenant(_)
+            | ResponseError::CircuitOpen
+            | ResponseError::Cancelled
+            | ResponseError::InvalidLink(_) => None,
+        }
+    }
+}
+
+impl ResponseError {
+    /// Whether the request was rejected because too many requests were made in a short period of time.
+    pub fn is_rate_limited(&self) -> bool {
+        match self {
+            ResponseError::ApiError(e) => {
+                e.status == Some(reqwest::StatusCode::TOO_MANY_REQUESTS) || e.name == "RATE_LIMIT_REACHED"
+            }
+            ResponseError::HttpError(e) => e.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS),
+            _ => false,
+        }
+    }
+
+    /// How long PayPal asked to wait before trying again, from the `Retry-After` header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ResponseError::ApiError(e) => e.retry_after,
+            _ => None,
+        }
+    }
+
+    /// Whether the request was rejected because the access token is invalid, like when it was revoked or expired.
+    pub fn is_unauthorized(&self) -> bool {
+        match self {
+            ResponseError::ApiError(e) => {
+                e.status == Some(reqwest::StatusCode::UNAUTHORIZED)
+                    || e.name == "AUTHENTICATION_FAILURE"
+                    || e.name == "invalid_token"
+            }
+            ResponseError::HttpError(e) => e.status() == Some(reqwest::StatusCode::UNAUTHORIZED),
+            _ => false,
+        }
+    }
+
+    /// Whether the request failed for a reason that may go away by itself, like a PayPal server error or a network failure.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            ResponseError::ApiError(e) => {
+                e.status.is_some_and(|status| status.is_server_error())
+                    || e.name == "INTERNAL_SERVER_ERROR"
+                    || e.name == "SERVICE_UNAVAILABLE"
+            }
+            ResponseError::HttpError(e) => {
+                e.is_connect() || e.is_timeout() || e.status().is_some_and(|status| status.is_server_error())
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ResponseError {
+    /// Turns an unsuccessful response into an error, falling back to a http error carrying the status
+    /// when the body is not a PayPal error, like some gateway errors.
+    pub(crate) async fn from_response(res: reqwest::Response) -> Self {
+        let status = res.status();
+        let status_error = res.error_for_status_ref().err();
+        let headers = res.headers().clone();
+
+        match res.bytes().await {
+            Ok(body) => Self::from_body(status, status_error, &headers, &body),
+            Err(e) => ResponseError::HttpError(e),
+        }
+    }
+
+    /// See [ResponseError::from_response], for a body that was already read.
+    pub(crate) fn from_body(
+        status: reqwest::StatusCode,
+        status_error: Option<reqwest::Error>,
+        headers: &reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let retry_after = headers
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse().ok())
+            .map(Duration::from_secs);
+
+        match (PaypalError::from_json(body), status_error) {
+            (Ok(e), _) => ResponseError::ApiError(PaypalError {
+                retry_after,
+                status: Some(status),
+                ..e
+            }),
+            (Err(_), Some(e)) => ResponseError::HttpError(e),
+            (Err(e), None) => ResponseError::ParseError(e),
         }
     }
 }
@@ -66,6 +252,13 @@ impl From<PaypalError> for ResponseError {
     }
 }
 
+// Implemented so we can use ? directly on it.
+impl From<serde_json::Error> for ResponseError {
+    fn from(e: serde_json::Error) -> Self {
+        ResponseError::ParseError(e)
+    }
+}
+
 // Implemented so we can use ? directly on it.
 impl From<reqwest::Error> for ResponseError {
     fn from(e: reqwest::Error) -> Self {
@@ -85,6 +278,172 @@ impl fmt::Display for InvalidCurrencyError {
 
 impl Error for InvalidCurrencyError {}
 
+/// When the mime type of an [EvidenceFile](crate::data::disputes::EvidenceFile) is invalid.
+#[derive(Debug)]
+pub struct InvalidMimeTypeError(pub String);
+
+impl fmt::Display for InvalidMimeTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a valid mime type", self.0)
+    }
+}
+
+impl Error for InvalidMimeTypeError {}
+
+/// When a money amount is invalid, see [Money::normalize](crate::data::common::Money::normalize).
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidMoneyError {
+    /// The value is not a decimal number.
+    Malformed(String),
+    /// The value has more decimal places than the currency supports, PayPal rejects it with `DECIMALS_NOT_SUPPORTED`.
+    TooManyDecimals {
+        /// The value.
+        value: String,
+        /// The currency of the value.
+        currency: Currency,
+    },
+}
+
+impl fmt::Display for InvalidMoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidMoneyError::Malformed(value) => write!(f, "{:?} is not a valid amount", value),
+            InvalidMoneyError::TooManyDecimals { value, currency } => write!(
+                f,
+                "{:?} has more than the {} decimal places supported by {}",
+                value,
+                currency.decimal_places(),
+                currency
+            ),
+        }
+    }
+}
+
+impl Error for InvalidMoneyError {}
+
+/// When the amounts of a purchase unit are inconsistent, see [PurchaseUnit::validate](crate::data::orders::PurchaseUnit::validate).
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidAmountError {
+    /// An amount is invalid.
+    InvalidMoney(InvalidMoneyError),
+    /// An item quantity is not a positive integer.
+    InvalidQuantity(String),
+    /// The amounts are not all in the same currency.
+    CurrencyMismatch {
+        /// The currency of the first amount.
+        expected: Currency,
+        /// The currency of another amount.
+        found: Currency,
+    },
+    /// A total doesn't match the sum of its parts, PayPal rejects it with `AMOUNT_MISMATCH` or `ITEM_TOTAL_MISMATCH`.
+    Mismatch {
+        /// The name of the total: `amount`, `item_total` or `tax_total`.
+        field: &'static str,
+        /// The sum of its parts.
+        expected: Money,
+        /// The total.
+        found: Money,
+    },
+}
+
+impl fmt::Display for InvalidAmountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidAmountError::InvalidMoney(e) => write!(f, "{}", e),
+            InvalidAmountError::InvalidQuantity(quantity) => write!(f, "{:?} is not a valid quantity", quantity),
+            InvalidAmountError::CurrencyMismatch { expected, found } => {
+                write!(f, "expected amounts in {}, got {}", expected, found)
+            }
+            InvalidAmountError::Mismatch { field, expected, found } => write!(
+                f,
+                "{} is {} but its parts sum up to {}",
+                field, found.value, expected.value
+            ),
+        }
+    }
+}
+
+impl Error for InvalidAmountError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InvalidAmountError::InvalidMoney(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<InvalidMoneyError> for InvalidAmountError {
+    fn from(e: InvalidMoneyError) -> Self {
+        InvalidAmountError::InvalidMoney(e)
+    }
+}
+
+/// When a phone number is not a valid E.164 number, see [PhoneNumber::parse](crate::data::orders::PhoneNumber::parse).
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidPhoneNumberError(pub String);
+
+impl fmt::Display for InvalidPhoneNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a valid E.164 phone number", self.0)
+    }
+}
+
+impl Error for InvalidPhoneNumberError {}
+
+/// When a product code doesn't have the format of its type, see [ItemUpc::new](crate::data::common::ItemUpc::new).
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidUpcError {
+    /// The type of the code.
+    pub upc_type: UpcType,
+    /// The code.
+    pub value: String,
+}
+
+impl fmt::Display for InvalidUpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a valid {} code", self.value, self.upc_type)
+    }
+}
+
+impl Error for InvalidUpcError {}
+
+/// When a purchase unit reference id can't be used in a patch path, see [Patch::purchase_unit_path](crate::data::common::Patch::purchase_unit_path).
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidReferenceIdError(pub String);
+
+impl fmt::Display for InvalidReferenceIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} can't be used in a patch path, as it contains a quote", self.0)
+    }
+}
+
+impl Error for InvalidReferenceIdError {}
+
+/// When an address doesn't follow the conventions of its country, see [AddressBuilder::build_checked](crate::data::common::AddressBuilder::build_checked).
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidAddressError {
+    /// A component required in the country is not set.
+    Missing(&'static str),
+    /// A component has a value that is not allowed.
+    Invalid {
+        /// The name of the component, like `postal_code`.
+        field: &'static str,
+        /// Why the value is invalid.
+        reason: String,
+    },
+}
+
+impl fmt::Display for InvalidAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidAddressError::Missing(field) => write!(f, "the address {} is not set", field),
+            InvalidAddressError::Invalid { field, reason } => write!(f, "the address {} is invalid: {}", field, reason),
+        }
+    }
+}
+
+impl Error for InvalidAddressError {}
+
 /// When a country is invalid.
 #[derive(Debug)]
 pub struct InvalidCountryError(pub String);
@@ -96,3 +455,92 @@ impl fmt::Display for InvalidCountryError {
 }
 
 impl Error for InvalidCountryError {}
+
+/// When an endpoint invocation can't be turned into a [RequestDescriptor](crate::descriptor::RequestDescriptor).
+#[derive(Debug)]
+pub enum DescriptorError {
+    /// The body could not be serialized.
+    Serialize(serde_json::Error),
+    /// The endpoint uploads files, which can't be stored in a descriptor.
+    Multipart,
+    /// The endpoint sets a header which can't be stored in a descriptor, with its name.
+    Header(String),
+}
+
+impl fmt::Display for DescriptorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DescriptorError::Serialize(e) => write!(f, "{}", e),
+            DescriptorError::Multipart => write!(f, "multipart requests can't be described"),
+            DescriptorError::Header(name) => write!(f, "the {} header can't be described", name),
+        }
+    }
+}
+
+impl Error for DescriptorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DescriptorError::Serialize(e) => Some(e),
+            DescriptorError::Multipart | DescriptorError::Header(_) => None,
+        }
+    }
+}
+
+/// When the configuration of a [Client](crate::Client) is missing or invalid, see [ClientConfig](crate::config::ClientConfig).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// A required variable is not set, or empty.
+    Missing(&'static str),
+    /// A variable has a value that is not allowed.
+    Invalid {
+        /// The name of the variable.
+        name: &'static str,
+        /// Why the value is invalid.
+        reason: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Missing(name) => write!(f, "{} is not set", name),
+            ConfigError::Invalid { name, reason } => write!(f, "{} is invalid: {}", name, reason),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+/// When the trackers of an [AddOrderTrackingBatch](crate::api::tracking::AddOrderTrackingBatch) could not be added.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum BatchTrackingError {
+    /// The batch has no trackers, so nothing was sent.
+    Empty,
+    /// Some of the trackers could not be added.
+    Failed {
+        /// The order as returned by the last tracker successfully added, if any.
+        order: Option<Order>,
+        /// The total number of trackers in the batch.
+        total: usize,
+        /// The capture ids of the trackers that failed, along with the error.
+        failed: Vec<(String, ResponseError)>,
+    },
+}
+
+impl fmt::Display for BatchTrackingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchTrackingError::Empty => write!(f, "the batch has no trackers"),
+            BatchTrackingError::Failed { total, failed, .. } => {
+                write!(f, "failed to add {} of {} trackers", failed.len(), total)?;
+                for (capture_id, e) in failed {
+                    write!(f, "\n{}: {}", capture_id, e)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for BatchTrackingError {}
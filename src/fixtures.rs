@@ -0,0 +1,112 @@
+//! Example payloads of the PayPal documentation, to check the data types against what the api sends.
+//!
+//! Requires the `fixtures` feature.
+//!
+//! ```
+//! use paypal_rs::{data::orders::Order, fixtures};
+//!
+//! let order: Order = fixtures::assert_round_trip(fixtures::ORDER);
+//! assert_eq!(order.id, "5O190127TN364715T");
+//! ```
+
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+/// A completed order, as returned by [ShowOrderDetails](crate::api::orders::ShowOrderDetails).
+pub const ORDER: &str = include_str!("fixtures/order.json");
+/// A completed capture, deserialized as a [Capture](crate::data::orders::Capture).
+pub const CAPTURE: &str = include_str!("fixtures/capture.json");
+/// A completed refund, deserialized as a [Refund](crate::data::orders::Refund).
+pub const REFUND: &str = include_str!("fixtures/refund.json");
+/// A draft invoice, deserialized as an [Invoice](crate::data::invoice::Invoice).
+pub const INVOICE: &str = include_str!("fixtures/invoice.json");
+/// A `PAYMENT.CAPTURE.COMPLETED` notification, deserialized as a [WebhookEvent](crate::data::webhooks::WebhookEvent).
+pub const WEBHOOK_EVENT: &str = include_str!("fixtures/webhook_event.json");
+
+/// Deserializes the json into `T` and serializes it back, panicking unless the result is the same json.
+///
+/// Timestamps are compared as instants, as they are serialized in UTC whatever the offset they were sent with.
+pub fn assert_round_trip<T: Serialize + DeserializeOwned>(json: &str) -> T {
+    let expected: Value = serde_json::from_str(json).expect("the fixture is valid json");
+    let value: T = match serde_json::from_value(expected.clone()) {
+        Ok(value) => value,
+        Err(e) => panic!("could not deserialize {}: {}", std::any::type_name::<T>(), e),
+    };
+    let found = serde_json::to_value(&value).expect("serialize the value correctly");
+
+    if let Some(difference) = difference("$", &expected, &found) {
+        panic!("{} does not round trip: {}", std::any::type_name::<T>(), difference);
+    }
+    value
+}
+
+/// Describes the first difference between the two values, if any.
+fn difference(path: &str, expected: &Value, found: &Value) -> Option<String> {
+    match (expected, found) {
+        (Value::Object(expected), Value::Object(found)) => {
+            for (key, value) in expected {
+                let path = format!("{}.{}", path, key);
+                match found.get(key) {
+                    Some(found) => {
+                        if let Some(difference) = difference(&path, value, found) {
+                            return Some(difference);
+                        }
+                    }
+                    None => return Some(format!("{} is missing", path)),
+                }
+            }
+            found
+                .keys()
+                .find(|key| !expected.contains_key(*key))
+                .map(|key| format!("{}.{} is unexpected", path, key))
+        }
+        (Value::Array(expected), Value::Array(found)) if expected.len() == found.len() => expected
+            .iter()
+            .zip(found)
+            .enumerate()
+            .find_map(|(i, (expected, found))| difference(&format!("{}[{}]", path, i), expected, found)),
+        (Value::String(expected), Value::String(found)) if same_instant(expected, found) => None,
+        _ if expected == found => None,
+        _ => Some(format!("{} is {} instead of {}", path, found, expected)),
+    }
+}
+
+fn same_instant(expected: &str, found: &str) -> bool {
+    match (
+        chrono::DateTime::parse_from_rfc3339(expected),
+        chrono::DateTime::parse_from_rfc3339(found),
+    ) {
+        (Ok(expected), Ok(found)) => expected == found,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{
+        invoice::Invoice,
+        orders::{Capture, DisbursementMode, Order, Refund},
+        webhooks::WebhookEvent,
+    };
+
+    #[test]
+    fn test_fixtures_round_trip() {
+        let order: Order = assert_round_trip(ORDER);
+        let payments = order.purchase_units.unwrap()[0].payments.clone().unwrap();
+        assert_eq!(payments.captures[0].disbursement_mode, Some(DisbursementMode::Instant));
+
+        assert_round_trip::<Capture>(CAPTURE);
+        assert_round_trip::<Refund>(REFUND);
+        assert_round_trip::<Invoice>(INVOICE);
+
+        let event: WebhookEvent = assert_round_trip(WEBHOOK_EVENT);
+        assert_round_trip::<Capture>(&event.resource.to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "$.unknown_field is missing")]
+    fn test_round_trip_difference() {
+        assert_round_trip::<Capture>(&CAPTURE.replacen('{', "{\"unknown_field\": 1,", 1));
+    }
+}
@@ -0,0 +1,117 @@
+//! Configuration of the http client sending the requests, see [Client::with_http_config](crate::Client::with_http_config).
+
+use derive_builder::Builder;
+use std::time::Duration;
+
+/// The configuration of the http client sending the requests.
+///
+/// Keeping connections alive avoids renegotiating tls on every request of long runs of calls:
+///
+/// ```
+/// use paypal_rs::http_config::HttpConfigBuilder;
+/// use std::time::Duration;
+///
+/// let config = HttpConfigBuilder::default()
+///     .pool_max_idle_per_host(8usize)
+///     .pool_idle_timeout(Duration::from_secs(300))
+///     .tcp_keepalive(Duration::from_secs(60))
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// Pinning PayPal certificates: trust only the given root certificates, so a certificate issued by
+/// any other authority is rejected even if the system trusts it.
+///
+/// ```no_run
+/// use paypal_rs::{Client, PaypalEnv, http_config::HttpConfigBuilder};
+///
+/// let pem = std::fs::read("certs/paypal-root.pem").unwrap();
+/// let config = HttpConfigBuilder::default()
+///     .root_certificates(vec![reqwest::Certificate::from_pem(&pem).unwrap()])
+///     .built_in_root_certificates(false)
+///     .build()
+///     .unwrap();
+///
+/// let client = Client::new("clientid".to_string(), "secret".to_string(), PaypalEnv::Live)
+///     .with_http_config(&config)
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into), default)]
+pub struct HttpConfig {
+    /// The root certificates trusted in addition to the built-in ones.
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    pub root_certificates: Vec<reqwest::Certificate>,
+    /// Whether the built-in root certificates of the tls backend are trusted.
+    ///
+    /// Disable it along with [HttpConfig::root_certificates] to trust only those.
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    pub built_in_root_certificates: bool,
+    /// The maximum number of idle connections kept per host, unlimited unless set.
+    #[builder(setter(strip_option))]
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long idle connections are kept, 90 seconds unless set.
+    #[builder(setter(strip_option))]
+    pub pool_idle_timeout: Option<Duration>,
+    /// The interval of the tcp keepalive probes, disabled unless set.
+    #[builder(setter(strip_option))]
+    pub tcp_keepalive: Option<Duration>,
+    /// The interval of the HTTP/2 pings keeping the connections alive, disabled unless set.
+    #[cfg(feature = "http2")]
+    #[builder(setter(strip_option))]
+    pub http2_keep_alive_interval: Option<Duration>,
+    /// Whether the HTTP/2 flow control windows adapt to the connection, instead of using fixed sizes.
+    #[cfg(feature = "http2")]
+    pub http2_adaptive_window: bool,
+}
+
+// Derivable only without a tls backend.
+#[allow(clippy::derivable_impls)]
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            #[cfg(any(feature = "native-tls", feature = "rustls"))]
+            root_certificates: Vec::new(),
+            #[cfg(any(feature = "native-tls", feature = "rustls"))]
+            built_in_root_certificates: true,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            tcp_keepalive: None,
+            #[cfg(feature = "http2")]
+            http2_keep_alive_interval: None,
+            #[cfg(feature = "http2")]
+            http2_adaptive_window: false,
+        }
+    }
+}
+
+impl HttpConfig {
+    /// Builds a http client with this configuration.
+    pub fn build_client(&self) -> Result<reqwest::Client, reqwest::Error> {
+        let mut builder = reqwest::Client::builder().tcp_keepalive(self.tcp_keepalive);
+
+        if let Some(max_idle) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(idle_timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(idle_timeout);
+        }
+
+        #[cfg(feature = "http2")]
+        {
+            builder = builder
+                .http2_keep_alive_interval(self.http2_keep_alive_interval)
+                .http2_adaptive_window(self.http2_adaptive_window);
+        }
+
+        #[cfg(any(feature = "native-tls", feature = "rustls"))]
+        {
+            builder = builder.tls_built_in_root_certs(self.built_in_root_certificates);
+            for certificate in &self.root_certificates {
+                builder = builder.add_root_certificate(certificate.clone());
+            }
+        }
+
+        builder.build()
+    }
+}
@@ -91,15 +91,36 @@
 #![forbid(unsafe_code)]
 
 pub mod api;
+pub mod bulk;
+pub mod circuit_breaker;
 pub mod client;
+pub mod config;
 pub mod countries;
 pub mod data;
+pub mod descriptor;
 pub mod endpoint;
 pub mod errors;
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
+pub mod http_config;
+#[cfg(feature = "test-util")]
+pub mod mock;
+pub mod pagination;
+pub mod redact;
+pub mod response;
+pub mod retry;
+#[cfg(feature = "tower")]
+pub mod service;
+pub mod tenant;
+pub mod throttle;
+pub mod token_store;
+pub mod transport;
+#[cfg(feature = "test-util")]
+pub mod vcr;
 pub use client::*;
 
 use derive_builder::Builder;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 /// The paypal api endpoint used on a live application.
@@ -164,6 +185,50 @@ pub struct HeaderParams {
     pub request_id: Option<String>,
     /// The media type. Required for operations with a request body.
     pub content_type: Option<String>,
+    /// How much of the resource to return, overriding [Endpoint::prefer](endpoint::Endpoint::prefer).
+    #[builder(default)]
+    pub prefer: Option<Prefer>,
+    /// Makes the sandbox fail the request with the given error, like `INSTRUMENT_DECLINED`, to test the error handling.
+    ///
+    /// Sent in the `PayPal-Mock-Response` header, which the live environment ignores.
+    #[builder(default)]
+    pub mock_application_code: Option<String>,
+}
+
+/// The value of the `Prefer` header, which decides how much of the resource POST, PUT and PATCH requests return.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Prefer {
+    /// Only the id, status and HATEOAS links of the resource.
+    Minimal,
+    /// The complete resource, which avoids having to fetch it afterwards.
+    #[default]
+    Representation,
+}
+
+impl HeaderParams {
+    /// Fills the headers that are not set with the given ones.
+    pub fn or(self, defaults: HeaderParams) -> HeaderParams {
+        HeaderParams {
+            merchant_payer_id: self.merchant_payer_id.or(defaults.merchant_payer_id),
+            client_metadata_id: self.client_metadata_id.or(defaults.client_metadata_id),
+            partner_attribution_id: self.partner_attribution_id.or(defaults.partner_attribution_id),
+            request_id: self.request_id.or(defaults.request_id),
+            content_type: self.content_type.or(defaults.content_type),
+            prefer: self.prefer.or(defaults.prefer),
+            mock_application_code: self.mock_application_code.or(defaults.mock_application_code),
+        }
+    }
+}
+
+impl Prefer {
+    /// Returns the header value.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Prefer::Minimal => "return=minimal",
+            Prefer::Representation => "return=representation",
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -0,0 +1,208 @@
+//! A [Transport] answering requests with canned responses, to test code using the client without network access.
+//!
+//! Requires the `test-util` feature.
+//!
+//! ```
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<(), paypal_rs::errors::ResponseError> {
+//! use paypal_rs::{Client, PaypalEnv, api::orders::ShowOrderDetails, mock::MockTransport};
+//! use std::sync::Arc;
+//!
+//! let transport = Arc::new(MockTransport::new().with_json(
+//!     reqwest::Method::GET,
+//!     "/v2/checkout/orders/5O190127TN364715T",
+//!     200,
+//!     serde_json::json!({ "id": "5O190127TN364715T", "status": "APPROVED", "links": [] }),
+//! ));
+//!
+//! let mut client = Client::new("clientid".to_string(), "secret".to_string(), PaypalEnv::Sandbox)
+//!     .with_transport(transport.clone());
+//! client.get_access_token().await?;
+//!
+//! let order = client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+//! assert_eq!(order.id, "5O190127TN364715T");
+//! assert_eq!(transport.requests().len(), 2);
+//! # Ok(())
+//! # }
+//! ```
+
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use std::sync::Mutex;
+
+use crate::transport::Transport;
+
+/// A canned response, returned to the requests matching its method and path.
+#[derive(Debug, Clone)]
+struct Route {
+    method: Method,
+    path: String,
+    status: StatusCode,
+    body: Vec<u8>,
+}
+
+/// A request received by a [MockTransport].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// The request method.
+    pub method: Method,
+    /// The request path, without the query string.
+    pub path: String,
+    /// The query string, if any.
+    pub query: Option<String>,
+    /// The request headers.
+    pub headers: HeaderMap,
+    /// The request body.
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// Deserializes the json body of the request.
+    pub fn json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::from_slice(&self.body)
+    }
+}
+
+/// A [Transport] answering the requests with the responses registered for their method and path.
+///
+/// The token endpoint answers with a valid access token, unless another response is registered for it.
+/// Requests without a matching response get a 404 `RESOURCE_NOT_FOUND` error.
+#[derive(Debug)]
+pub struct MockTransport {
+    routes: Vec<Route>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl Default for MockTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockTransport {
+    /// Creates a transport only answering the token requests.
+    pub fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            requests: Mutex::default(),
+        }
+        .with_json(
+            Method::POST,
+            "/v1/oauth2/token",
+            200,
+            serde_json::json!({
+                "scope": "https://uri.paypal.com/services/payments/payment",
+                "access_token": "MOCKACCESSTOKEN",
+                "token_type": "Bearer",
+                "app_id": "APP-80W284485P519543T",
+                "expires_in": 32400,
+                "nonce": "mock"
+            }),
+        )
+    }
+
+    /// Answers the requests with the given method and path with a json body.
+    ///
+    /// Responses registered later take precedence, so the defaults can be overridden.
+    ///
+    /// # Panics
+    ///
+    /// If the status is not a valid http status.
+    pub fn with_json(self, method: Method, path: impl ToString, status: u16, body: serde_json::Value) -> Self {
+        self.with_body(method, path, status, body.to_string())
+    }
+
+    /// Answers the requests with the given method and path with a raw body, like the content of a fixture file.
+    ///
+    /// # Panics
+    ///
+    /// If the status is not a valid http status.
+    pub fn with_body(mut self, method: Method, path: impl ToString, status: u16, body: impl Into<Vec<u8>>) -> Self {
+        self.routes.push(Route {
+            method,
+            path: path.to_string(),
+            status: StatusCode::from_u16(status).expect("a valid http status"),
+            body: body.into(),
+        });
+        self
+    }
+
+    /// Returns the requests received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().expect("mock lock poisoned").clone()
+    }
+
+    fn respond(&self, method: &Method, path: &str) -> (StatusCode, Vec<u8>) {
+        match self
+            .routes
+            .iter()
+            .rev()
+            .find(|route| route.method == method && route.path == path)
+        {
+            Some(route) => (route.status, route.body.clone()),
+            None => {
+                let body = serde_json::json!({
+                    "name": "RESOURCE_NOT_FOUND",
+                    "message": format!("No mock response for {} {}", method, path),
+                    "debug_id": "mock",
+                    "details": [],
+                    "links": []
+                });
+                (StatusCode::NOT_FOUND, body.to_string().into_bytes())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, reqwest::Error> {
+        let recorded = RecordedRequest {
+            method: request.method().clone(),
+            path: request.url().path().to_string(),
+            query: request.url().query().map(str::to_string),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(<[u8]>::to_vec)
+                .unwrap_or_default(),
+        };
+
+        let (status, body) = self.respond(&recorded.method, &recorded.path);
+        self.requests.lock().expect("mock lock poisoned").push(recorded);
+
+        let response = http::Response::builder()
+            .status(status)
+            .header("content-type", "application/json")
+            .body(body)
+            .expect("a valid mock response");
+        Ok(reqwest::Response::from(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Client, PaypalEnv, api::invoice::GetInvoice, errors::ResponseError};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_unmatched_request() {
+        let transport = Arc::new(MockTransport::new());
+        let mut client = Client::new("clientid".to_string(), "secret".to_string(), PaypalEnv::Sandbox)
+            .with_transport(transport.clone());
+        client.get_access_token().await.unwrap();
+
+        let result = client.execute(&GetInvoice::new("INV2-Z56S-5LLA-Q52L-CPZ5")).await;
+        assert!(matches!(result, Err(ResponseError::ApiError(e)) if e.name == "RESOURCE_NOT_FOUND"));
+
+        let requests = transport.requests();
+        assert_eq!(requests[1].path, "/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5");
+        assert_eq!(
+            requests[1].headers["authorization"].to_str().unwrap(),
+            "Bearer MOCKACCESSTOKEN"
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Paging through the list endpoints, see [Client::pages] and [Client::items].
+
+use futures::{Stream, TryStreamExt, stream};
+
+use crate::{
+    Client,
+    data::common::{LinkDescription, LinkRel},
+    endpoint::Endpoint,
+    errors::ResponseError,
+};
+
+/// A list endpoint returning its results one page at a time.
+pub trait PaginatedEndpoint: Endpoint + Clone {
+    /// The type of the listed items.
+    type Item;
+
+    /// The page requested by this endpoint, if set.
+    fn page(&self) -> Option<i32>;
+
+    /// Requests the given page, starting at 1.
+    fn set_page(&mut self, page: i32);
+
+    /// The total number of pages, when the response includes it.
+    fn total_pages(response: &Self::Response) -> Option<i32>;
+
+    /// The HATEOAS links of the response, used to find the next page when the total is unknown.
+    fn links(response: &Self::Response) -> &[LinkDescription];
+
+    /// The number of items in the page.
+    fn len(response: &Self::Response) -> usize;
+
+    /// Takes the items out of the page.
+    fn into_items(response: Self::Response) -> Vec<Self::Item>;
+}
+
+/// Returns the page following the given one, or `None` if it was the last page.
+fn next_page<E: PaginatedEndpoint>(page: i32, response: &E::Response) -> Option<i32> {
+    if E::len(response) == 0 {
+        return None;
+    }
+
+    let has_next = match E::total_pages(response) {
+        Some(total_pages) => page < total_pages,
+        None => E::links(response).iter().any(|link| link.rel == Some(LinkRel::Next)),
+    };
+    has_next.then_some(page + 1)
+}
+
+impl Client {
+    /// Returns a stream of the pages of a list endpoint, from the endpoint page (or the first one) onwards.
+    ///
+    /// The stream ends after yielding the first error.
+    ///
+    /// ```no_run
+    /// # async fn run(client: paypal_rs::Client) {
+    /// use futures::TryStreamExt;
+    /// use paypal_rs::api::invoice::ListInvoices;
+    ///
+    /// let pages = client.pages(ListInvoices::new(Default::default()));
+    /// futures::pin_mut!(pages);
+    ///
+    /// while let Some(page) = pages.try_next().await.unwrap() {
+    ///     println!("{} invoices", page.items.len());
+    /// }
+    /// # }
+    /// ```
+    pub fn pages<'a, E: PaginatedEndpoint + 'a>(
+        &'a self,
+        endpoint: E,
+    ) -> impl Stream<Item = Result<E::Response, ResponseError>> + 'a {
+        let first_page = endpoint.page().unwrap_or(1);
+
+        stream::try_unfold(Some(first_page), move |page| {
+            let mut endpoint = endpoint.clone();
+            async move {
+                let Some(page) = page else {
+                    return Ok::<_, ResponseError>(None);
+                };
+
+                endpoint.set_page(page);
+                let response = self.execute(&endpoint).await?;
+                let next = next_page::<E>(page, &response);
+                Ok(Some((response, next)))
+            }
+        })
+    }
+
+    /// Returns a stream of the items of a list endpoint, fetching the pages as they are needed.
+    ///
+    /// The stream ends after yielding the first error.
+    pub fn items<'a, E: PaginatedEndpoint + 'a>(
+        &'a self,
+        endpoint: E,
+    ) -> impl Stream<Item = Result<E::Item, ResponseError>> + 'a {
+        self.pages(endpoint)
+            .map_ok(|page| stream::iter(E::into_items(page).into_iter().map(Ok)))
+            .try_flatten()
+    }
+}
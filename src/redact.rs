@@ -0,0 +1,136 @@
+//! Redaction of secrets and card data from request and response bodies, so they can be logged.
+
+use serde_json::Value;
+
+/// The fields whose value is always replaced.
+const SECRET_FIELDS: &[&str] = &[
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "client_token",
+    "client_secret",
+    "secret",
+    "password",
+    "authorization",
+    "security_code",
+    "cvv",
+    "cvv2",
+    "cvc",
+];
+
+/// The fields holding card numbers, of which only the last 4 digits are kept.
+const CARD_NUMBER_FIELDS: &[&str] = &["number", "card_number", "account_number"];
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Redacts the secrets and card data found in a json value.
+///
+/// Tokens, secrets and card security codes are replaced, while card numbers keep their last 4 digits.
+///
+/// ```
+/// use paypal_rs::redact::redact_json;
+///
+/// let mut body = serde_json::json!({
+///     "payment_source": {
+///         "card": { "number": "4111111111111111", "security_code": "123", "expiry": "2027-02" }
+///     }
+/// });
+/// redact_json(&mut body);
+/// assert_eq!(body["payment_source"]["card"]["number"], "************1111");
+/// assert_eq!(body["payment_source"]["card"]["security_code"], "[REDACTED]");
+/// assert_eq!(body["payment_source"]["card"]["expiry"], "2027-02");
+/// ```
+pub fn redact_json(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let key = key.to_ascii_lowercase();
+                if SECRET_FIELDS.contains(&key.as_str()) {
+                    *value = Value::String(REDACTED.to_string());
+                } else if CARD_NUMBER_FIELDS.contains(&key.as_str())
+                    && let Value::String(number) = value
+                {
+                    *number = mask_card_number(number);
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_json),
+        Value::String(text) if looks_like_card_number(text) => *text = mask_card_number(text),
+        _ => {}
+    }
+}
+
+/// Redacts the secrets of a `x-www-form-urlencoded` body, like the authorization code of a token request.
+///
+/// ```
+/// use paypal_rs::redact::redact_form;
+///
+/// let body = redact_form("grant_type=authorization_code&code=C21AAH1");
+/// assert_eq!(body, "grant_type=authorization_code&code=[REDACTED]");
+/// ```
+pub fn redact_form(body: &str) -> String {
+    body.split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if key == "code" || SECRET_FIELDS.contains(&key.to_ascii_lowercase().as_str()) => {
+                format!("{}={}", key, REDACTED)
+            }
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Redacts a header value if it carries credentials.
+pub fn redact_header(name: &str, value: &str) -> String {
+    match name.to_ascii_lowercase().as_str() {
+        "authorization" | "paypal-auth-assertion" => REDACTED.to_string(),
+        _ => value.to_string(),
+    }
+}
+
+fn looks_like_card_number(text: &str) -> bool {
+    let digits = text.chars().filter(|c| !c.is_whitespace() && *c != '-').count();
+    (13..=19).contains(&digits)
+        && text
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_whitespace() || c == '-')
+}
+
+fn mask_card_number(number: &str) -> String {
+    let digits: Vec<char> = number.chars().filter(char::is_ascii_digit).collect();
+    if digits.len() <= 4 {
+        return REDACTED.to_string();
+    }
+    let last: String = digits[digits.len() - 4..].iter().collect();
+    format!("{}{}", "*".repeat(digits.len() - 4), last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_json() {
+        let mut body = serde_json::json!({
+            "access_token": "A21AAFs9nq5",
+            "note": "4111 1111 1111 1111",
+            "items": [{ "name": "Shirt", "cvv": "123" }],
+            "amount": { "value": "10.00" }
+        });
+        redact_json(&mut body);
+
+        assert_eq!(body["access_token"], REDACTED);
+        assert_eq!(body["note"], "************1111");
+        assert_eq!(body["items"][0]["cvv"], REDACTED);
+        assert_eq!(body["items"][0]["name"], "Shirt");
+        assert_eq!(body["amount"]["value"], "10.00");
+    }
+
+    #[test]
+    fn test_redact_header() {
+        assert_eq!(redact_header("Authorization", "Bearer A21AAFs9nq5"), REDACTED);
+        assert_eq!(redact_header("Prefer", "return=minimal"), "return=minimal");
+    }
+}
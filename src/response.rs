@@ -0,0 +1,72 @@
+//! Metadata of the responses, see [Client::execute_with_meta](crate::Client::execute_with_meta).
+
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use std::time::Duration;
+
+/// A response body along with the metadata of the response.
+#[derive(Debug, Clone)]
+pub struct ResponseWithMeta<T> {
+    /// The http status.
+    pub status: StatusCode,
+    /// The `Paypal-Debug-Id` header, identifying the request when contacting PayPal support.
+    pub debug_id: Option<String>,
+    /// The rate limit headers, when PayPal sends them.
+    pub rate_limit: RateLimit,
+    /// All the response headers.
+    pub headers: HeaderMap,
+    /// The deserialized body.
+    pub body: T,
+}
+
+impl<T> ResponseWithMeta<T> {
+    pub(crate) fn new(status: StatusCode, headers: HeaderMap, body: T) -> Self {
+        Self {
+            status,
+            debug_id: header_str(&headers, "paypal-debug-id").map(str::to_string),
+            rate_limit: RateLimit::from_headers(&headers),
+            headers,
+            body,
+        }
+    }
+
+    /// Transforms the body, keeping the metadata.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ResponseWithMeta<U> {
+        ResponseWithMeta {
+            status: self.status,
+            debug_id: self.debug_id,
+            rate_limit: self.rate_limit,
+            headers: self.headers,
+            body: f(self.body),
+        }
+    }
+}
+
+/// The rate limit state reported by the response headers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The number of requests allowed in the current window, from `X-RateLimit-Limit`.
+    pub limit: Option<u64>,
+    /// The number of requests left in the current window, from `X-RateLimit-Remaining`.
+    pub remaining: Option<u64>,
+    /// How long until the window resets, from `X-RateLimit-Reset`.
+    pub reset: Option<Duration>,
+    /// How long to wait before sending more requests, from `Retry-After`.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimit {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let number = |name| header_str(headers, name).and_then(|value| value.trim().parse::<u64>().ok());
+        Self {
+            limit: number("x-ratelimit-limit"),
+            remaining: number("x-ratelimit-remaining"),
+            reset: number("x-ratelimit-reset").map(Duration::from_secs),
+            retry_after: number("retry-after").map(Duration::from_secs),
+        }
+    }
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
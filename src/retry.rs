@@ -0,0 +1,157 @@
+//! Retrying of requests that failed for transient reasons, like rate limiting or a PayPal outage.
+
+use derive_builder::Builder;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::errors::ResponseError;
+
+/// How requests that failed for transient reasons are retried, see [Client::with_retry_policy](crate::Client::with_retry_policy).
+///
+/// Requests rejected with a 429 are always safe to retry. Server errors and network failures
+/// are only retried when the request is idempotent, that is when it isn't a POST or PATCH,
+/// or when it carries a `PayPal-Request-Id`.
+///
+/// ```
+/// use paypal_rs::retry::RetryPolicyBuilder;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicyBuilder::default()
+///     .max_attempts(5u32)
+///     .base_delay(Duration::from_millis(200))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into), default)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled on every following retry.
+    pub base_delay: Duration,
+    /// The upper bound of the delay between two attempts.
+    pub max_delay: Duration,
+    /// Whether the delays are randomized, so clients failing at the same time don't retry at the same time.
+    pub jitter: bool,
+    /// Limits the retries made by every client using this policy.
+    #[builder(setter(strip_option))]
+    pub budget: Option<Arc<RetryBudget>>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            budget: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the delay to wait before the given retry, starting at 1.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_delay);
+
+        if self.jitter {
+            // Keeps at least half of the delay, so the backoff still grows.
+            delay.mul_f64(0.5 + fastrand::f64() / 2.0)
+        } else {
+            delay
+        }
+    }
+
+    /// Returns the delay to wait before retrying after the given error, honoring its `Retry-After`.
+    pub(crate) fn delay_after(&self, error: &ResponseError, retry: u32) -> Duration {
+        match error.retry_after() {
+            Some(retry_after) => retry_after.min(self.max_delay),
+            None => self.delay(retry),
+        }
+    }
+
+    /// Whether the request that failed with the given error can be attempted again.
+    pub(crate) fn should_retry(&self, error: &ResponseError, attempt: u32, idempotent: bool) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+
+        let retryable = error.is_rate_limited() || (idempotent && error.is_transient());
+        retryable && self.budget.as_ref().is_none_or(|budget| budget.withdraw())
+    }
+}
+
+/// A limit on the number of retries made over a period of time, shared by all the requests using it.
+///
+/// It stops retries from piling up during an outage, when most requests would fail anyway.
+#[derive(Debug)]
+pub struct RetryBudget {
+    max_retries: u32,
+    period: Duration,
+    state: Mutex<(Instant, u32)>,
+}
+
+impl RetryBudget {
+    /// Allows at most `max_retries` retries every `period`.
+    pub fn new(max_retries: u32, period: Duration) -> Self {
+        Self {
+            max_retries,
+            period,
+            state: Mutex::new((Instant::now(), 0)),
+        }
+    }
+
+    /// Takes a retry from the budget, returning whether there was any left.
+    pub fn withdraw(&self) -> bool {
+        let mut state = self.state.lock().expect("retry budget lock poisoned");
+        let (started, used) = &mut *state;
+
+        if started.elapsed() >= self.period {
+            *started = Instant::now();
+            *used = 0;
+        }
+
+        if *used < self.max_retries {
+            *used += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(5),
+            jitter: false,
+            ..Default::default()
+        };
+
+        assert_eq!(policy.delay(1), Duration::from_secs(1));
+        assert_eq!(policy.delay(2), Duration::from_secs(2));
+        assert_eq!(policy.delay(3), Duration::from_secs(4));
+        assert_eq!(policy.delay(4), Duration::from_secs(5));
+
+        let policy = RetryPolicy { jitter: true, ..policy };
+        let delay = policy.delay(2);
+        assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_budget() {
+        let budget = RetryBudget::new(2, Duration::from_secs(60));
+        assert!(budget.withdraw());
+        assert!(budget.withdraw());
+        assert!(!budget.withdraw());
+    }
+}
@@ -0,0 +1,147 @@
+//! A [tower_service::Service] executing endpoints, to compose tower layers around PayPal calls.
+//!
+//! Requires the `tower` feature.
+//!
+//! ```no_run
+//! # async fn run(client: paypal_rs::Client) -> Result<(), tower::BoxError> {
+//! use paypal_rs::{api::orders::ShowOrderDetails, service::EndpointService};
+//! use std::time::Duration;
+//! use tower::{ServiceBuilder, ServiceExt};
+//!
+//! let service = ServiceBuilder::new()
+//!     .timeout(Duration::from_secs(10))
+//!     .service(EndpointService::new(client));
+//!
+//! let order = service.oneshot(ShowOrderDetails::new("5O190127TN364715T")).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use crate::{Client, HeaderParams, endpoint::Endpoint, errors::ResponseError};
+
+/// A service executing the endpoints of type `E` it is called with, see [Client::execute_ext].
+///
+/// Clones share the same client, and so the same access token.
+#[derive(Debug)]
+pub struct EndpointService<E> {
+    client: Arc<Client>,
+    headers: HeaderParams,
+    endpoint: PhantomData<fn(E)>,
+}
+
+impl<E> Clone for EndpointService<E> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            headers: self.headers.clone(),
+            endpoint: PhantomData,
+        }
+    }
+}
+
+impl<E> EndpointService<E> {
+    /// Creates a service executing the endpoints with the given client.
+    ///
+    /// You must remember to call [Client::get_access_token] on the client first.
+    pub fn new(client: impl Into<Arc<Client>>) -> Self {
+        Self {
+            client: client.into(),
+            headers: HeaderParams::default(),
+            endpoint: PhantomData,
+        }
+    }
+
+    /// Sends the given headers with every request, see [Client::execute_ext].
+    pub fn with_headers(mut self, headers: HeaderParams) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// The client executing the endpoints.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl<E> tower_service::Service<E> for EndpointService<E>
+where
+    E: Endpoint + Send + Sync + 'static,
+    E::Response: Send,
+{
+    type Response = E::Response;
+    type Error = ResponseError;
+    type Future = Pin<Box<dyn Future<Output = Result<E::Response, ResponseError>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, endpoint: E) -> Self::Future {
+        let client = self.client.clone();
+        let headers = self.headers.clone();
+        Box::pin(async move { client.execute_ext(&endpoint, headers).await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PaypalEnv, api::orders::ShowOrderDetails};
+    use std::time::Duration;
+    use tower::{ServiceBuilder, ServiceExt};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_endpoint_service() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/oauth2/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "A21AAFs9nq5",
+                "token_type": "Bearer",
+                "expires_in": 32400
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/checkout/orders/5O190127TN364715T"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "5O190127TN364715T",
+                "status": "APPROVED",
+                "links": []
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let mut client = Client::new(
+            "clientid".to_string(),
+            "secret".to_string(),
+            PaypalEnv::Mock(mock_server.uri()),
+        );
+        client.get_access_token().await.unwrap();
+
+        let service = ServiceBuilder::new()
+            .timeout(Duration::from_secs(10))
+            .service(EndpointService::new(client));
+
+        let order = service
+            .clone()
+            .oneshot(ShowOrderDetails::new("5O190127TN364715T"))
+            .await
+            .unwrap();
+        assert_eq!(order.id, "5O190127TN364715T");
+
+        let error = service.oneshot(ShowOrderDetails::new("UNKNOWN")).await.unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ResponseError>(),
+            Some(ResponseError::HttpError(_))
+        ));
+    }
+}
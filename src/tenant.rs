@@ -0,0 +1,125 @@
+//! Acting on behalf of many merchants, each with their own credentials, from a single client.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::{Client, HeaderParams, endpoint::Endpoint, errors::ResponseError};
+
+/// The credentials of the PayPal application of a tenant.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Credentials {
+    /// The client id.
+    pub client_id: String,
+    /// The secret.
+    pub secret: String,
+}
+
+impl Credentials {
+    /// New constructor.
+    pub fn new(client_id: impl ToString, secret: impl ToString) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            secret: secret.to_string(),
+        }
+    }
+}
+
+/// Looks up the credentials of a tenant, for example in a database or a secrets manager.
+#[async_trait]
+pub trait CredentialProvider: std::fmt::Debug + Send + Sync {
+    /// Returns the credentials of the given tenant, `None` if it is unknown.
+    async fn credentials(&self, tenant: &str) -> Option<Credentials>;
+}
+
+#[async_trait]
+impl CredentialProvider for HashMap<String, Credentials> {
+    async fn credentials(&self, tenant: &str) -> Option<Credentials> {
+        self.get(tenant).cloned()
+    }
+}
+
+/// A client acting on behalf of many tenants, selected on every request.
+///
+/// The client of each tenant is created on its first request, from the credentials of the [CredentialProvider]
+/// and the configuration of the base client, then kept along with its access token.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), paypal_rs::errors::ResponseError> {
+/// use paypal_rs::{Client, PaypalEnv, api::orders::ShowOrderDetails};
+/// use paypal_rs::tenant::{Credentials, MultiTenantClient};
+/// use std::collections::HashMap;
+///
+/// let credentials = HashMap::from([
+///     ("shop-1".to_string(), Credentials::new("clientid1", "secret1")),
+///     ("shop-2".to_string(), Credentials::new("clientid2", "secret2")),
+/// ]);
+/// let base = Client::new(String::new(), String::new(), PaypalEnv::Sandbox);
+/// let client = MultiTenantClient::new(base, credentials);
+///
+/// let order = client.execute("shop-1", &ShowOrderDetails::new("5O190127TN364715T")).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MultiTenantClient {
+    base: Client,
+    provider: Arc<dyn CredentialProvider>,
+    clients: Mutex<HashMap<String, Arc<Client>>>,
+}
+
+impl MultiTenantClient {
+    /// Creates the clients of the tenants from the given base client, whose credentials aren't used.
+    pub fn new(base: Client, provider: impl CredentialProvider + 'static) -> Self {
+        Self {
+            base,
+            provider: Arc::new(provider),
+            clients: Mutex::default(),
+        }
+    }
+
+    /// Returns the client of the given tenant, which has an access token.
+    pub async fn client(&self, tenant: &str) -> Result<Arc<Client>, ResponseError> {
+        if let Some(client) = self.clients.lock().expect("tenant lock poisoned").get(tenant) {
+            return Ok(client.clone());
+        }
+
+        let credentials = self
+            .provider
+            .credentials(tenant)
+            .await
+            .ok_or_else(|| ResponseError::UnknownTenant(tenant.to_string()))?;
+
+        let mut client = self.base.with_credentials(credentials.client_id, credentials.secret);
+        client.get_access_token().await?;
+
+        let mut clients = self.clients.lock().expect("tenant lock poisoned");
+        Ok(clients.entry(tenant.to_string()).or_insert(Arc::new(client)).clone())
+    }
+
+    /// Forgets the client of the given tenant, so its credentials are looked up again, for example after a rotation.
+    pub fn forget(&self, tenant: &str) {
+        self.clients.lock().expect("tenant lock poisoned").remove(tenant);
+    }
+
+    /// Executes the given endpoint on behalf of the given tenant.
+    pub async fn execute<E>(&self, tenant: &str, endpoint: &E) -> Result<E::Response, ResponseError>
+    where
+        E: Endpoint,
+    {
+        self.client(tenant).await?.execute(endpoint).await
+    }
+
+    /// Executes the given endpoint on behalf of the given tenant, with the given headers.
+    pub async fn execute_ext<E>(
+        &self,
+        tenant: &str,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> Result<E::Response, ResponseError>
+    where
+        E: Endpoint,
+    {
+        self.client(tenant).await?.execute_ext(endpoint, headers).await
+    }
+}
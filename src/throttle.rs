@@ -0,0 +1,81 @@
+//! Client side rate limiting, to stay under the PayPal rate limits on large batches.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A token bucket limiting how many requests are sent per second, see [Client::with_throttle](crate::Client::with_throttle).
+///
+/// Requests over the limit wait for their turn instead of failing.
+///
+/// ```
+/// use paypal_rs::throttle::Throttle;
+///
+/// // At most 10 requests per second, with bursts of up to 20 requests.
+/// let throttle = Throttle::new(10.0, 20);
+/// ```
+#[derive(Debug)]
+pub struct Throttle {
+    requests_per_second: f64,
+    burst: u32,
+    state: Mutex<(Instant, f64)>,
+}
+
+impl Throttle {
+    /// Allows `requests_per_second` requests on average, and bursts of up to `burst` requests.
+    ///
+    /// # Panics
+    ///
+    /// If `requests_per_second` is not positive.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        assert!(requests_per_second > 0.0, "requests_per_second must be positive");
+        let burst = burst.max(1);
+        Self {
+            requests_per_second,
+            burst,
+            state: Mutex::new((Instant::now(), burst as f64)),
+        }
+    }
+
+    /// Waits until a request can be sent.
+    pub async fn acquire(&self) {
+        while let Some(wait) = self.try_acquire() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Takes a token if there is one, otherwise returns how long until there is.
+    fn try_acquire(&self) -> Option<Duration> {
+        let mut state = self.state.lock().expect("throttle lock poisoned");
+        let (refilled, tokens) = &mut *state;
+
+        let now = Instant::now();
+        *tokens =
+            (*tokens + now.duration_since(*refilled).as_secs_f64() * self.requests_per_second).min(self.burst as f64);
+        *refilled = now;
+
+        if *tokens >= 1.0 {
+            *tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - *tokens) / self.requests_per_second))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_throttle() {
+        let throttle = Throttle::new(20.0, 2);
+        let start = Instant::now();
+
+        // The burst goes through right away, the rest at 20 per second.
+        for _ in 0..4 {
+            throttle.acquire().await;
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+}
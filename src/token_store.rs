@@ -0,0 +1,75 @@
+//! Sharing access tokens between clients, so many workers don't each request their own.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use crate::client::AccessToken;
+
+/// An access token along with the time it expires, as kept in a [TokenStore].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredToken {
+    /// The access token.
+    pub access_token: AccessToken,
+    /// When the access token expires.
+    pub expires_at: SystemTime,
+}
+
+impl StoredToken {
+    /// How long until the token expires, `None` if it already did.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.expires_at.duration_since(SystemTime::now()).ok()
+    }
+}
+
+/// Stores the client credentials access tokens, see [Client::with_token_store](crate::Client::with_token_store).
+///
+/// Tokens are keyed by the credentials and options they were requested with, so a store can be shared
+/// by clients of different applications or acting on behalf of different merchants.
+///
+/// Implementations backed by a database or a cache like Redis let a fleet of workers share their tokens.
+/// A failing store should behave like an empty one, the client then requests a new token.
+#[async_trait]
+pub trait TokenStore: std::fmt::Debug + Send + Sync {
+    /// Returns the token stored under the given key, if any.
+    async fn load(&self, key: &str) -> Option<StoredToken>;
+
+    /// Stores a token under the given key, replacing the previous one.
+    async fn save(&self, key: &str, token: StoredToken);
+
+    /// Removes the token stored under the given key.
+    async fn remove(&self, key: &str);
+}
+
+/// A [TokenStore] keeping the tokens in memory, shared by the clients of a single process.
+#[derive(Debug, Default)]
+pub struct InMemoryTokenStore {
+    tokens: Mutex<HashMap<String, StoredToken>>,
+}
+
+impl InMemoryTokenStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TokenStore for InMemoryTokenStore {
+    async fn load(&self, key: &str) -> Option<StoredToken> {
+        self.tokens.lock().expect("token store lock poisoned").get(key).cloned()
+    }
+
+    async fn save(&self, key: &str, token: StoredToken) {
+        self.tokens
+            .lock()
+            .expect("token store lock poisoned")
+            .insert(key.to_string(), token);
+    }
+
+    async fn remove(&self, key: &str) {
+        self.tokens.lock().expect("token store lock poisoned").remove(key);
+    }
+}
@@ -0,0 +1,19 @@
+//! The transport sending the http requests, which can be replaced to intercept them.
+
+use async_trait::async_trait;
+
+/// Sends the http requests of a [Client](crate::Client), see [Client::with_transport](crate::Client::with_transport).
+///
+/// The client sends its requests with its [reqwest::Client] unless a transport is set.
+#[async_trait]
+pub trait Transport: std::fmt::Debug + Send + Sync {
+    /// Sends the request and returns its response.
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, reqwest::Error>;
+}
+
+#[async_trait]
+impl Transport for reqwest::Client {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, reqwest::Error> {
+        reqwest::Client::execute(self, request).await
+    }
+}
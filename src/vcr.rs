@@ -0,0 +1,306 @@
+//! Recording the responses of a real PayPal environment to disk, and replaying them in tests.
+//!
+//! Requires the `test-util` feature.
+//!
+//! A [Cassette] records the requests sent and the responses received, with their secrets redacted,
+//! and answers the same requests with the same responses once replayed, without network access.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! use paypal_rs::{Client, PaypalEnv, api::orders::ShowOrderDetails, vcr::Cassette};
+//! use std::sync::Arc;
+//!
+//! // Records against the sandbox the first time, replays the recording afterwards.
+//! let cassette = Arc::new(Cassette::load_or_record("tests/cassettes/show_order.json")?);
+//!
+//! let mut client = Client::new("clientid".to_string(), "secret".to_string(), PaypalEnv::Sandbox)
+//!     .with_transport(cassette);
+//! client.get_access_token().await?;
+//! client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use async_trait::async_trait;
+use reqwest::{StatusCode, header};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::{
+    redact::{redact_form, redact_header, redact_json},
+    transport::Transport,
+};
+
+/// A request and the response it got.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request method.
+    pub method: String,
+    /// The request path, including the query string.
+    pub path: String,
+    /// The request body, with its secrets redacted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<String>,
+    /// The response status.
+    pub status: u16,
+    /// The response headers, with their secrets redacted.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// The response body, with its secrets redacted.
+    #[serde(default)]
+    pub body: String,
+}
+
+/// Whether a [Cassette] records or replays.
+#[derive(Debug)]
+enum Mode {
+    Record(reqwest::Client),
+    Replay,
+}
+
+/// A [Transport] recording the interactions to a file, or replaying them from it.
+///
+/// Replayed requests are answered with the first recorded interaction with the same method and path
+/// that wasn't used yet, so a flow calling the same endpoint twice gets both responses in order.
+/// Requests without a recorded interaction get a 404 `RESOURCE_NOT_FOUND` error.
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: Mode,
+    interactions: Mutex<Vec<(Interaction, bool)>>,
+}
+
+impl Cassette {
+    /// Sends the requests with the given http client, and records them to the given file.
+    ///
+    /// The file is overwritten after every request.
+    pub fn record(path: impl AsRef<Path>, client: reqwest::Client) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            mode: Mode::Record(client),
+            interactions: Mutex::default(),
+        }
+    }
+
+    /// Replays the interactions recorded to the given file.
+    pub fn replay(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let content = std::fs::read(path.as_ref())?;
+        let interactions: Vec<Interaction> = serde_json::from_slice(&content)?;
+        Ok(Self {
+            path: path.as_ref().to_path_buf(),
+            mode: Mode::Replay,
+            interactions: Mutex::new(
+                interactions
+                    .into_iter()
+                    .map(|interaction| (interaction, false))
+                    .collect(),
+            ),
+        })
+    }
+
+    /// Replays the given file if it exists, otherwise records to it with a default http client.
+    pub fn load_or_record(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        if path.as_ref().exists() {
+            Self::replay(path)
+        } else {
+            Ok(Self::record(path, reqwest::Client::new()))
+        }
+    }
+
+    /// Whether the requests are sent and recorded, rather than replayed.
+    pub fn is_recording(&self) -> bool {
+        matches!(self.mode, Mode::Record(_))
+    }
+
+    /// Returns the interactions recorded or loaded so far.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.lock().iter().map(|(interaction, _)| interaction.clone()).collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<(Interaction, bool)>> {
+        self.interactions.lock().expect("cassette lock poisoned")
+    }
+
+    async fn record_interaction(
+        &self,
+        client: &reqwest::Client,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let method = request.method().to_string();
+        let path = path_and_query(request.url());
+        let request_body = request.body().and_then(reqwest::Body::as_bytes).map(redacted_body);
+
+        let response = client.execute(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        let interaction = Interaction {
+            method,
+            path,
+            request_body,
+            status: status.as_u16(),
+            headers: headers
+                .iter()
+                // The redacted body may not have the original length.
+                .filter(|(name, _)| *name != header::CONTENT_LENGTH && *name != header::TRANSFER_ENCODING)
+                .filter_map(|(name, value)| {
+                    let value = value.to_str().ok()?;
+                    Some((name.to_string(), redact_header(name.as_str(), value)))
+                })
+                .collect(),
+            body: redacted_body(&body),
+        };
+
+        let interactions = {
+            let mut interactions = self.lock();
+            interactions.push((interaction, true));
+            interactions
+                .iter()
+                .map(|(interaction, _)| interaction.clone())
+                .collect::<Vec<_>>()
+        };
+        let content = serde_json::to_vec_pretty(&interactions).expect("serialize the interactions correctly");
+        if let Err(e) = std::fs::write(&self.path, content) {
+            log::warn!(target: "paypal_rs", "could not write the cassette {}: {}", self.path.display(), e);
+        }
+
+        // The recorded body is redacted, the caller gets the real one.
+        let mut builder = http::Response::builder().status(status);
+        for (name, value) in &headers {
+            builder = builder.header(name, value);
+        }
+        Ok(reqwest::Response::from(
+            builder.body(body.to_vec()).expect("a valid recorded response"),
+        ))
+    }
+
+    fn replay_interaction(&self, request: &reqwest::Request) -> reqwest::Response {
+        let method = request.method().as_str();
+        let path = path_and_query(request.url());
+
+        let mut interactions = self.lock();
+        let found = interactions
+            .iter_mut()
+            .find(|(interaction, used)| !used && interaction.method == method && interaction.path == path);
+
+        let response = match found {
+            Some((interaction, used)) => {
+                *used = true;
+                let mut builder = http::Response::builder().status(interaction.status);
+                for (name, value) in &interaction.headers {
+                    builder = builder.header(name, value);
+                }
+                builder.body(interaction.body.clone().into_bytes())
+            }
+            None => {
+                let body = serde_json::json!({
+                    "name": "RESOURCE_NOT_FOUND",
+                    "message": format!("No recorded response for {} {}", method, path),
+                    "debug_id": "cassette",
+                    "details": [],
+                    "links": []
+                });
+                http::Response::builder()
+                    .status(StatusCode::NOT_FOUND)
+                    .header("content-type", "application/json")
+                    .body(body.to_string().into_bytes())
+            }
+        };
+        reqwest::Response::from(response.expect("a valid replayed response"))
+    }
+}
+
+#[async_trait]
+impl Transport for Cassette {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, reqwest::Error> {
+        match &self.mode {
+            Mode::Record(client) => self.record_interaction(client, request).await,
+            Mode::Replay => Ok(self.replay_interaction(&request)),
+        }
+    }
+}
+
+fn path_and_query(url: &reqwest::Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    }
+}
+
+/// Returns the body with its secrets redacted, as json when it is json and as a form otherwise.
+fn redacted_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            redact_json(&mut value);
+            value.to_string()
+        }
+        Err(_) => redact_form(&String::from_utf8_lossy(body)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Client, PaypalEnv, api::orders::ShowOrderDetails};
+    use std::sync::Arc;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/oauth2/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "A21AAFs9nq5",
+                "token_type": "Bearer",
+                "expires_in": 32400
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/checkout/orders/5O190127TN364715T"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "5O190127TN364715T",
+                "status": "APPROVED",
+                "links": []
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let file = std::env::temp_dir().join(format!("paypal-rs-cassette-{}.json", uuid::Uuid::new_v4()));
+        let show = ShowOrderDetails::new("5O190127TN364715T");
+
+        let cassette = Arc::new(Cassette::record(&file, reqwest::Client::new()));
+        let mut client = Client::new(
+            "clientid".to_string(),
+            "secret".to_string(),
+            PaypalEnv::Mock(mock_server.uri()),
+        )
+        .with_transport(cassette);
+        client.get_access_token().await.unwrap();
+        assert_eq!(client.current_token().unwrap().access_token, "A21AAFs9nq5");
+        client.execute(&show).await.unwrap();
+        drop(mock_server);
+
+        let cassette = Arc::new(Cassette::replay(&file).unwrap());
+        let recorded = cassette.interactions();
+        assert_eq!(recorded.len(), 2);
+        assert!(!recorded[0].body.contains("A21AAFs9nq5"));
+
+        let mut client = Client::new(
+            "clientid".to_string(),
+            "secret".to_string(),
+            PaypalEnv::Mock("http://localhost:1".to_string()),
+        )
+        .with_transport(cassette);
+        client.get_access_token().await.unwrap();
+        let order = client.execute(&show).await.unwrap();
+        assert_eq!(order.id, "5O190127TN364715T");
+
+        std::fs::remove_file(file).unwrap();
+    }
+}
@@ -1,5 +1,9 @@
-use paypal_rs::{Client, PaypalEnv};
-use wiremock::matchers::{basic_auth, body_string, header, method, path};
+use paypal_rs::api::orders::ShowOrderDetails;
+use paypal_rs::token_store::InMemoryTokenStore;
+use paypal_rs::{Client, GrantType, PaypalEnv, TokenOptionsBuilder, TokenResponseType};
+use std::sync::Arc;
+use std::time::Duration;
+use wiremock::matchers::{basic_auth, bearer_token, body_string, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 fn create_client(url: &str) -> Client {
@@ -33,3 +37,547 @@ async fn test_auth() -> color_eyre::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_authorization_code_and_refresh() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(body_string("grant_type=authorization_code&code=C21AAH1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token_type": "Bearer",
+            "expires_in": 28800,
+            "refresh_token": "R23AAFsUOq8",
+            "access_token": "A23AAHMrZP3"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(body_string("grant_type=refresh_token&refresh_token=R23AAFsUOq8"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token_type": "Bearer",
+            "expires_in": 28800,
+            "access_token": "A23AAHMrZP4"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+
+    client.exchange_authorization_code("C21AAH1").await?;
+    assert_eq!(client.auth.grant_type, GrantType::AuthorizationCode);
+    assert_eq!(client.auth.refresh_token.as_deref(), Some("R23AAFsUOq8"));
+
+    client.refresh_access_token("R23AAFsUOq8").await?;
+    assert_eq!(client.auth.grant_type, GrantType::RefreshToken);
+    assert_eq!(client.auth.access_token.as_ref().unwrap().access_token, "A23AAHMrZP4");
+    assert_eq!(client.auth.refresh_token.as_deref(), Some("R23AAFsUOq8"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_revoke_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string("grant_type=authorization_code&code=C21AAH1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token_type": "Bearer",
+            "expires_in": 28800,
+            "refresh_token": "R23AAFsUOq8",
+            "access_token": "A23AAHMrZP3"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token/terminate"))
+        .and(basic_auth("clientid", "secret"))
+        .and(body_string("token=R23AAFsUOq8&token_type_hint=REFRESH_TOKEN"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+
+    client.exchange_authorization_code("C21AAH1").await?;
+    client.revoke_current_token().await?;
+    assert!(client.auth.access_token.is_none());
+    assert!(client.auth.refresh_token.is_none());
+    assert!(client.access_token_expired());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_target_subject() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string(
+            "grant_type=client_credentials&target_subject=2UBHZ6WZB2UQE",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string(
+            "grant_type=client_credentials&target_subject=8L2XG9QXQ5PRW",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+
+    let merchant_a = TokenOptionsBuilder::default().target_subject("2UBHZ6WZB2UQE").build()?;
+    client.get_access_token_with(merchant_a.clone()).await?;
+    assert_eq!(client.token_subject(), Some("2UBHZ6WZB2UQE"));
+
+    // The cached token is reused for the same merchant.
+    client.get_access_token_with(merchant_a).await?;
+    client.get_access_token().await?;
+
+    // But never for another merchant.
+    let merchant_b = TokenOptionsBuilder::default().target_subject("8L2XG9QXQ5PRW").build()?;
+    client.get_access_token_with(merchant_b).await?;
+    assert_eq!(client.token_subject(), Some("8L2XG9QXQ5PRW"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_id_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string(
+            "grant_type=client_credentials&response_type=client_token+id_token&target_customer_id=customer_1234",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "scope": "openid",
+            "access_token": "A21AAFs9nq5",
+            "token_type": "Bearer",
+            "app_id": "APP-80W284485P519543T",
+            "expires_in": 32400,
+            "id_token": "eyJraWQiOiI4ODJlZDk",
+            "nonce": "2022-08-03T15:35:36ZaYZlGvEkV4yVSz8g6bAKFoGSEzuy3CQcz3ljhibkOHg"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+
+    let options = TokenOptionsBuilder::default()
+        .response_types(vec![TokenResponseType::ClientToken, TokenResponseType::IdToken])
+        .target_customer_id("customer_1234")
+        .build()?;
+    client.get_access_token_with(options).await?;
+    assert_eq!(client.id_token().as_deref(), Some("eyJraWQiOiI4ODJlZDk"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_token_refreshed_before_expiry() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "EXPIRINGTOKEN",
+            "token_type": "Bearer",
+            "expires_in": 30
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "FRESHTOKEN",
+            "token_type": "Bearer",
+            "expires_in": 32400
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("FRESHTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "CREATED",
+            "links": []
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri()).with_refresh_margin(Duration::from_secs(60));
+
+    // The token expires within the margin, so it is renewed by the first request only.
+    client.get_access_token().await?;
+    assert!(client.access_token_expired());
+
+    client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    assert!(!client.access_token_expired());
+    assert_eq!(client.current_token().unwrap().access_token, "FRESHTOKEN");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_revoke_renewed_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "EXPIRINGTOKEN",
+            "token_type": "Bearer",
+            "expires_in": 30
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "FRESHTOKEN",
+            "token_type": "Bearer",
+            "expires_in": 32400
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("FRESHTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "CREATED",
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    // The renewed token is the one revoked, not the expired one.
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token/terminate"))
+        .and(basic_auth("clientid", "secret"))
+        .and(body_string("token=FRESHTOKEN&token_type_hint=ACCESS_TOKEN"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri()).with_refresh_margin(Duration::from_secs(60));
+
+    client.get_access_token().await?;
+    client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    client.revoke_current_token().await?;
+    assert!(client.current_token().is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_single_flight_refresh() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "EXPIRINGTOKEN",
+            "token_type": "Bearer",
+            "expires_in": 30
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({
+                    "access_token": "FRESHTOKEN",
+                    "token_type": "Bearer",
+                    "expires_in": 32400
+                }))
+                .set_delay(Duration::from_millis(100)),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("FRESHTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "CREATED",
+            "links": []
+        })))
+        .expect(20)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri()).with_refresh_margin(Duration::from_secs(60));
+    client.get_access_token().await?;
+    assert!(client.access_token_expired());
+
+    // All the requests find the token expired, a single one renews it.
+    let show = ShowOrderDetails::new("5O190127TN364715T");
+    let results = futures::future::join_all((0..20).map(|_| client.execute(&show))).await;
+    assert!(results.iter().all(Result::is_ok));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_single_flight_refresh_across_clones() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "EXPIRINGTOKEN",
+            "token_type": "Bearer",
+            "expires_in": 30
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({
+                    "access_token": "FRESHTOKEN",
+                    "token_type": "Bearer",
+                    "expires_in": 32400
+                }))
+                .set_delay(Duration::from_millis(100)),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("FRESHTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "CREATED",
+            "links": []
+        })))
+        .expect(21)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri()).with_refresh_margin(Duration::from_secs(60));
+    client.get_access_token().await?;
+
+    // Each task holds its own clone, they still share a single renewal.
+    let tasks: Vec<_> = (0..20)
+        .map(|_| {
+            let client = client.clone();
+            tokio::spawn(async move { client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await })
+        })
+        .collect();
+    for task in tasks {
+        task.await??;
+    }
+
+    // The renewed token is seen by the original client as well.
+    assert_eq!(client.current_token().unwrap().access_token, "FRESHTOKEN");
+    client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_clones_share_renewed_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "EXPIRINGTOKEN",
+            "token_type": "Bearer",
+            "expires_in": 30
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "FRESHTOKEN",
+            "token_type": "Bearer",
+            "expires_in": 32400
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string(
+            "grant_type=client_credentials&target_subject=2UBHZ6WZB2UQE",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "MERCHANTTOKEN",
+            "token_type": "Bearer",
+            "expires_in": 32400
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "CREATED",
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri()).with_refresh_margin(Duration::from_secs(60));
+    client.get_access_token().await?;
+    let first = client.clone();
+    let second = client.clone();
+
+    // The token renewed by one clone is used by the others, which don't renew it again.
+    first.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    assert_eq!(second.current_token().unwrap().access_token, "FRESHTOKEN");
+    second.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+
+    // A clone acting for a merchant keeps that token to itself.
+    let mut merchant = client.clone();
+    let options = TokenOptionsBuilder::default().target_subject("2UBHZ6WZB2UQE").build()?;
+    merchant.get_access_token_with(options).await?;
+    assert_eq!(merchant.current_token().unwrap().access_token, "MERCHANTTOKEN");
+    assert_eq!(client.current_token().unwrap().access_token, "FRESHTOKEN");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_env() -> color_eyre::Result<()> {
+    let oauth_server = MockServer::start().await;
+    let api_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .expect(1)
+        .mount(&oauth_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "CREATED",
+            "links": []
+        })))
+        .expect(1)
+        .mount(&api_server)
+        .await;
+
+    let env = PaypalEnv::Custom {
+        api_url: format!("{}/", api_server.uri()),
+        oauth_url: oauth_server.uri(),
+    };
+    let mut client = Client::new("clientid".to_string(), "secret".to_string(), env);
+
+    client.get_access_token().await?;
+    client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shared_token_store() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token_type": "Bearer",
+            "expires_in": 32400,
+            "access_token": "A21AAFs9nq5"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(body_string(
+            "grant_type=client_credentials&target_subject=2UBHZ6WZB2UQE",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token_type": "Bearer",
+            "expires_in": 32400,
+            "access_token": "A21AAMerchant"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let store = Arc::new(InMemoryTokenStore::new());
+
+    // Two workers, each with its own client, share the token.
+    let mut first = create_client(&mock_server.uri()).with_token_store(store.clone());
+    let mut second = create_client(&mock_server.uri()).with_token_store(store.clone());
+    first.get_access_token().await?;
+    second.get_access_token().await?;
+    assert_eq!(second.current_token().unwrap().access_token, "A21AAFs9nq5");
+
+    // Tokens acting on behalf of a merchant are kept apart.
+    let options = TokenOptionsBuilder::default().target_subject("2UBHZ6WZB2UQE").build()?;
+    second.get_access_token_with(options).await?;
+    assert_eq!(second.current_token().unwrap().access_token, "A21AAMerchant");
+    assert_eq!(first.current_token().unwrap().access_token, "A21AAFs9nq5");
+
+    Ok(())
+}
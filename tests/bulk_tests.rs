@@ -0,0 +1,260 @@
+use std::time::Duration;
+
+use paypal_rs::{Client, PaypalEnv};
+use paypal_rs::{
+    api::payments::RefundCapturedPayment,
+    bulk::{Batch, BulkRefund, RefundCheckpoint, RefundKey},
+    data::{
+        common::{Currency, Money},
+        payment::RefundRequest,
+    },
+    retry::RetryPolicyBuilder,
+};
+use wiremock::matchers::{basic_auth, bearer_token, body_string, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+fn refund_response(id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "status": "COMPLETED",
+        "amount": { "currency_code": "USD", "value": "10.00" },
+        "seller_payable_breakdown": {
+            "total_refunded_amount": { "currency_code": "USD", "value": "10.00" }
+        },
+        "links": []
+    })
+}
+
+#[tokio::test]
+async fn test_bulk_refund() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    // The first attempt is rate limited, the retry succeeds.
+    Mock::given(method("POST"))
+        .and(path("/v2/payments/captures/CAPTURE-1/refund"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(header("PayPal-Request-Id", "incident-CAPTURE-1-0"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+            "name": "RATE_LIMIT_REACHED",
+            "message": "Too many requests. Blocked due to rate limiting.",
+            "debug_id": "90957fca61718"
+        })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/payments/captures/CAPTURE-1/refund"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(refund_response("REFUND-1")))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/payments/captures/CAPTURE-3/refund"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+            "name": "UNPROCESSABLE_ENTITY",
+            "message": "The requested action could not be performed.",
+            "debug_id": "90957fca61719"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let mut checkpoint = RefundCheckpoint::default();
+    checkpoint.completed.insert(RefundKey::new("CAPTURE-2", 0));
+
+    let refunds = ["CAPTURE-1", "CAPTURE-2", "CAPTURE-3"]
+        .into_iter()
+        .map(|id| RefundCapturedPayment::new(id, Default::default()))
+        .collect();
+
+    let report = BulkRefund::new(refunds)
+        .retry_policy(
+            RetryPolicyBuilder::default()
+                .base_delay(Duration::from_millis(10))
+                .build()?,
+        )
+        .idempotency_prefix("incident-")
+        .resume_from(checkpoint)
+        .run(&client)
+        .await;
+
+    assert_eq!(report.refunded.len(), 1);
+    assert_eq!(report.refunded[0].id, "REFUND-1");
+    assert_eq!(report.skipped, vec![RefundKey::new("CAPTURE-2", 0)]);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].0, RefundKey::new("CAPTURE-3", 0));
+    assert!(report.checkpoint.completed.contains(&RefundKey::new("CAPTURE-1", 0)));
+    assert!(report.checkpoint.completed.contains(&RefundKey::new("CAPTURE-2", 0)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_partial_refunds() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    for index in 0..2 {
+        Mock::given(method("POST"))
+            .and(path("/v2/payments/captures/CAPTURE-1/refund"))
+            .and(header("PayPal-Request-Id", format!("bulk-refund-CAPTURE-1-{}", index)))
+            .respond_with(ResponseTemplate::new(201).set_body_json(refund_response(&format!("REFUND-{}", index))))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let partial_refund = || {
+        RefundCapturedPayment::new(
+            "CAPTURE-1",
+            RefundRequest {
+                amount: Some(Money {
+                    currency_code: Currency::USD,
+                    value: "5.00".to_string(),
+                }),
+                ..Default::default()
+            },
+        )
+    };
+    let report = BulkRefund::new(vec![partial_refund(), partial_refund()])
+        .run(&client)
+        .await;
+
+    assert_eq!(report.refunded.len(), 2);
+    assert!(report.failed.is_empty());
+    assert_eq!(
+        report.checkpoint.completed.into_iter().collect::<Vec<_>>(),
+        vec![RefundKey::new("CAPTURE-1", 0), RefundKey::new("CAPTURE-1", 1)]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_refund_default_retry() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/payments/captures/CAPTURE-1/refund"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+            "name": "RATE_LIMIT_REACHED",
+            "message": "Too many requests. Blocked due to rate limiting."
+        })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/payments/captures/CAPTURE-1/refund"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(refund_response("REFUND-1")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Neither the client nor the bulk refund has a retry policy.
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let refunds = vec![RefundCapturedPayment::new("CAPTURE-1", Default::default())];
+    let report = BulkRefund::new(refunds).run(&client).await;
+
+    assert_eq!(report.refunded.len(), 1);
+    assert!(report.failed.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    for id in ["1", "3"] {
+        Mock::given(method("POST"))
+            .and(path(format!("/v2/payments/captures/CAPTURE-{}/refund", id)))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .set_body_json(refund_response(&format!("REFUND-{}", id)))
+                    // The first request completes last.
+                    .set_delay(Duration::from_millis(if id == "1" { 100 } else { 0 })),
+            )
+            .mount(&mock_server)
+            .await;
+    }
+
+    Mock::given(method("POST"))
+        .and(path("/v2/payments/captures/CAPTURE-2/refund"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+            "name": "UNPROCESSABLE_ENTITY",
+            "message": "The requested action could not be performed.",
+            "debug_id": "90957fca61719"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let refunds = ["CAPTURE-1", "CAPTURE-2", "CAPTURE-3"]
+        .into_iter()
+        .map(|id| RefundCapturedPayment::new(id, Default::default()));
+
+    let results = Batch::new(refunds).concurrency(3).run(&client).await;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().id, "REFUND-1");
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap().id, "REFUND-3");
+
+    Ok(())
+}
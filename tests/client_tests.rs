@@ -0,0 +1,1029 @@
+use paypal_rs::api::orders::*;
+use paypal_rs::api::payments::{RefundCapturedPayment, VoidAuthorizedPayment};
+use paypal_rs::api::vault::DeletePaymentToken;
+use paypal_rs::circuit_breaker::CircuitBreaker;
+use paypal_rs::data::common::{LinkDescription, LinkRel};
+use paypal_rs::data::orders::*;
+use paypal_rs::descriptor::RequestDescriptor;
+use paypal_rs::endpoint::{Endpoint, Idempotent, Versioned};
+use paypal_rs::errors::ResponseError;
+use paypal_rs::http_config::HttpConfigBuilder;
+use paypal_rs::retry::RetryPolicyBuilder;
+use paypal_rs::tenant::{Credentials, MultiTenantClient};
+use paypal_rs::throttle::Throttle;
+use paypal_rs::{Client, HeaderParams, PaypalEnv, Prefer};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use wiremock::matchers::{basic_auth, bearer_token, body_string, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+async fn mock_auth(mock_server: &MockServer) {
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(mock_server)
+        .await;
+}
+
+fn order_json() -> serde_json::Value {
+    serde_json::json!({
+        "id": "5O190127TN364715T",
+        "status": "CREATED",
+        "links": []
+    })
+}
+
+#[tokio::test]
+async fn test_retry_transient_errors() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+            "name": "RATE_LIMIT_REACHED",
+            "message": "Too many requests. Blocked due to rate limiting."
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(order_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let policy = RetryPolicyBuilder::default()
+        .max_attempts(3u32)
+        .base_delay(Duration::from_millis(10))
+        .build()?;
+    let mut client = create_client(&mock_server.uri()).with_retry_policy(policy);
+    client.get_access_token().await?;
+
+    let order = client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    assert_eq!(order.status, OrderStatus::Created);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_retry_of_non_idempotent_requests() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let policy = RetryPolicyBuilder::default()
+        .base_delay(Duration::from_millis(10))
+        .build()?;
+    let mut client = create_client(&mock_server.uri()).with_retry_policy(policy);
+    client.get_access_token().await?;
+
+    let error = client
+        .execute(&CaptureOrder::new("5O190127TN364715T"))
+        .await
+        .unwrap_err();
+    assert!(error.is_transient());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_after_and_throttle() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("Retry-After", "1")
+                .set_body_json(serde_json::json!({
+                    "name": "RATE_LIMIT_REACHED",
+                    "message": "Too many requests. Blocked due to rate limiting."
+                })),
+        )
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(order_json()))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let error = client
+        .execute(&ShowOrderDetails::new("5O190127TN364715T"))
+        .await
+        .unwrap_err();
+    assert!(error.is_rate_limited());
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(1)));
+
+    let client = client.with_throttle(Throttle::new(10.0, 1));
+    let start = Instant::now();
+    client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    assert!(start.elapsed() >= Duration::from_millis(90));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_api_errors_classified_by_status() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    // The 401 is answered twice, as the request is sent again with a new token.
+    for (status, name, times) in [
+        (502, "BAD_GATEWAY", 1),
+        (429, "TOO_MANY_REQUESTS", 1),
+        (401, "NOT_AUTHORIZED", 2),
+    ] {
+        Mock::given(method("GET"))
+            .and(path("/v2/checkout/orders/5O190127TN364715T"))
+            .respond_with(ResponseTemplate::new(status).set_body_json(serde_json::json!({
+                "name": name,
+                "message": "Something went wrong."
+            })))
+            .up_to_n_times(times)
+            .expect(times)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+    let show = ShowOrderDetails::new("5O190127TN364715T");
+
+    let error = client.execute(&show).await.unwrap_err();
+    assert!(matches!(&error, ResponseError::ApiError(e) if e.status == Some(reqwest::StatusCode::BAD_GATEWAY)));
+    assert!(error.is_transient());
+    assert!(client.execute(&show).await.unwrap_err().is_rate_limited());
+    assert!(client.execute(&show).await.unwrap_err().is_unauthorized());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_idempotent_request_is_retried() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    let capture = Idempotent::generate(CaptureOrder::new("5O190127TN364715T"));
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(header("PayPal-Request-Id", capture.request_id.as_str()))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(header("PayPal-Request-Id", capture.request_id.as_str()))
+        .respond_with(ResponseTemplate::new(201).set_body_json(order_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let policy = RetryPolicyBuilder::default()
+        .base_delay(Duration::from_millis(10))
+        .build()?;
+    let mut client = create_client(&mock_server.uri()).with_retry_policy(policy);
+    client.get_access_token().await?;
+
+    client.execute(&capture).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_prefer_header() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(header("Prefer", "return=representation"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(order_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(header("Prefer", "return=minimal"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(order_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let capture = CaptureOrder::new("5O190127TN364715T");
+    client.execute(&capture).await?;
+    client
+        .execute_ext(
+            &capture,
+            HeaderParams {
+                prefer: Some(Prefer::Minimal),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_default_prefer_header() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(header("Prefer", "return=minimal"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(order_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri()).with_default_headers(HeaderParams {
+        prefer: Some(Prefer::Minimal),
+        ..Default::default()
+    });
+    client.get_access_token().await?;
+
+    client.execute(&CaptureOrder::new("5O190127TN364715T")).await?;
+
+    Ok(())
+}
+
+/// Captures an order approved on a device identified by the client metadata id.
+struct DeviceCapture(CaptureOrder);
+
+impl Endpoint for DeviceCapture {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Order;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        self.0.relative_path()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        self.0.method()
+    }
+
+    fn headers(&self) -> HeaderParams {
+        HeaderParams {
+            client_metadata_id: Some("device-1".to_string()),
+            ..self.0.headers()
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_endpoint_headers() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(header("PayPal-Client-Metadata-Id", "device-1"))
+        .and(header("Prefer", "return=representation"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(order_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(header("PayPal-Client-Metadata-Id", "device-2"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(order_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let capture = DeviceCapture(CaptureOrder::new("5O190127TN364715T"));
+    client.execute(&capture).await?;
+    client
+        .execute_ext(
+            &capture,
+            HeaderParams {
+                client_metadata_id: Some("device-2".to_string()),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_versioned_endpoint() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v3/checkout/orders/5O190127TN364715T"))
+        .and(header("PayPal-Api-Version", "2025-01-01"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(order_json()))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let show = Versioned::new(ShowOrderDetails::new("5O190127TN364715T"))
+        .version("v3")
+        .header("PayPal-Api-Version", "2025-01-01");
+    let order = client.execute(&show).await?;
+    assert_eq!(order.id, "5O190127TN364715T");
+
+    // A stored descriptor is sent again with the same version headers.
+    let stored = serde_json::to_string(&RequestDescriptor::from_endpoint(&show)?)?;
+    let descriptor: RequestDescriptor = serde_json::from_str(&stored)?;
+    assert_eq!(descriptor.headers["PayPal-Api-Version"], "2025-01-01");
+    let order = client.execute_descriptor(&descriptor).await?;
+    assert_eq!(order["id"], "5O190127TN364715T");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_structured_api_error() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+            "name": "UNPROCESSABLE_ENTITY",
+            "message": "The requested action could not be performed, semantically incorrect, or failed business validation.",
+            "debug_id": "f0ca5b5cf6b5e",
+            "details": [{
+                "issue": "INSTRUMENT_DECLINED",
+                "description": "The instrument presented was either declined by the processor or bank, or it can't be used for this payment."
+            }],
+            "links": [{
+                "href": "https://developer.paypal.com/docs/api/orders/v2/#error-INSTRUMENT_DECLINED",
+                "rel": "information_link",
+                "method": "GET"
+            }],
+            "processor_response": { "response_code": "0500" }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let error = client
+        .execute(&CaptureOrder::new("5O190127TN364715T"))
+        .await
+        .unwrap_err();
+    let ResponseError::ApiError(error) = error else {
+        panic!("expected an api error");
+    };
+    assert_eq!(error.name, "UNPROCESSABLE_ENTITY");
+    assert_eq!(error.debug_id.as_deref(), Some("f0ca5b5cf6b5e"));
+    assert!(error.has_issue("INSTRUMENT_DECLINED"));
+    assert_eq!(error.links.len(), 1);
+    assert_eq!(error.raw["processor_response"]["response_code"], "0500");
+    assert!(error.to_string().contains("f0ca5b5cf6b5e"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_response_metadata() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Paypal-Debug-Id", "b1f2c3d4e5f60")
+                .insert_header("X-RateLimit-Remaining", "42")
+                .set_body_json(order_json()),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let response = client
+        .execute_with_meta(&ShowOrderDetails::new("5O190127TN364715T"))
+        .await?;
+    assert_eq!(response.status, 200);
+    assert_eq!(response.debug_id.as_deref(), Some("b1f2c3d4e5f60"));
+    assert_eq!(response.rate_limit.remaining, Some(42));
+    assert_eq!(response.body.id, "5O190127TN364715T");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_http_client() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(header("X-Gateway-Route", "payments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(order_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut default_headers = reqwest::header::HeaderMap::new();
+    default_headers.insert("X-Gateway-Route", "payments".parse()?);
+    let http_client = reqwest::Client::builder().default_headers(default_headers).build()?;
+
+    let mut client = create_client(&mock_server.uri()).with_http_client(http_client);
+    client.get_access_token().await?;
+    client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_request_timeout_and_deadline() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_delay(Duration::from_millis(500))
+                .set_body_json(order_json()),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri()).with_request_timeout(Duration::from_millis(50));
+    client.get_access_token().await?;
+
+    let error = client
+        .execute(&ShowOrderDetails::new("5O190127TN364715T"))
+        .await
+        .unwrap_err();
+    assert!(error.is_transient());
+
+    // Retrying the timeouts can't go past the deadline.
+    let policy = RetryPolicyBuilder::default()
+        .max_attempts(10u32)
+        .base_delay(Duration::from_millis(10))
+        .build()?;
+    let client = client
+        .with_retry_policy(policy)
+        .with_deadline(Duration::from_millis(200));
+    let start = Instant::now();
+    let error = client
+        .execute(&ShowOrderDetails::new("5O190127TN364715T"))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, ResponseError::DeadlineExceeded(_)));
+    assert!(start.elapsed() < Duration::from_millis(400));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_multi_tenant_client() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    for shop in ["shop1", "shop2"] {
+        Mock::given(method("POST"))
+            .and(path("/v1/oauth2/token"))
+            .and(basic_auth(format!("{shop}-clientid"), format!("{shop}-secret")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "token_type": "Bearer",
+                "expires_in": 32400,
+                "access_token": format!("{shop}-token")
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/checkout/orders/5O190127TN364715T"))
+            .and(bearer_token(format!("{shop}-token")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(order_json()))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let credentials = HashMap::from([
+        ("shop1".to_string(), Credentials::new("shop1-clientid", "shop1-secret")),
+        ("shop2".to_string(), Credentials::new("shop2-clientid", "shop2-secret")),
+    ]);
+    let client = MultiTenantClient::new(create_client(&mock_server.uri()), credentials);
+
+    let show = ShowOrderDetails::new("5O190127TN364715T");
+    for shop in ["shop1", "shop2", "shop1", "shop2"] {
+        client.execute(shop, &show).await?;
+    }
+
+    let unknown = client.execute("shop3", &show).await;
+    assert!(matches!(unknown, Err(ResponseError::UnknownTenant(tenant)) if tenant == "shop3"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_circuit_breaker() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+            "name": "SERVICE_UNAVAILABLE",
+            "message": "Service Unavailable.",
+            "debug_id": "90957fca61718",
+            "links": []
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri()).with_circuit_breaker(CircuitBreaker::new(
+        0.5,
+        2,
+        Duration::from_secs(60),
+        Duration::from_secs(60),
+    ));
+    client.get_access_token().await?;
+
+    let show = ShowOrderDetails::new("5O190127TN364715T");
+    for _ in 0..2 {
+        let result = client.execute(&show).await;
+        assert!(matches!(result, Err(ResponseError::ApiError(_))));
+    }
+
+    // The circuit is open, the request isn't sent.
+    let result = client.execute(&show).await;
+    assert!(matches!(result, Err(ResponseError::CircuitOpen)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_execute_until_cancelled() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(order_json())
+                .set_delay(Duration::from_secs(5)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let start = Instant::now();
+    let result = client
+        .execute_until(
+            &ShowOrderDetails::new("5O190127TN364715T"),
+            HeaderParams::default(),
+            tokio::time::sleep(Duration::from_millis(50)),
+        )
+        .await;
+
+    assert!(matches!(result, Err(ResponseError::Cancelled)));
+    assert!(start.elapsed() < Duration::from_secs(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refresh_rejected_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    // The token was revoked, the retry with a new one succeeds.
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+            "error": "invalid_token",
+            "error_description": "Token signature verification failed"
+        })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(order_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let order = client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    assert_eq!(order.id, "5O190127TN364715T");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_execute_raw() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    let mut order = order_json();
+    order["new_field"] = serde_json::json!({ "not": "modeled" });
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Paypal-Debug-Id", "f1bc7d5f06a4b")
+                .set_body_json(&order),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let response = client.execute_raw(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    assert_eq!(response.status, 200);
+    assert_eq!(response.debug_id.as_deref(), Some("f1bc7d5f06a4b"));
+
+    let body: serde_json::Value = serde_json::from_slice(&response.body)?;
+    assert_eq!(body["new_field"]["not"], "modeled");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_execute_as() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    let mut order = order_json();
+    order["new_field"] = serde_json::json!({ "not": "modeled" });
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&order))
+        .mount(&mock_server)
+        .await;
+
+    #[derive(serde::Deserialize)]
+    struct NewField {
+        not: String,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct LeanOrder {
+        id: String,
+        new_field: NewField,
+    }
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let order: LeanOrder = client.execute_as(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    assert_eq!(order.id, "5O190127TN364715T");
+    assert_eq!(order.new_field.not, "modeled");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_follow_link() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    let mut order = order_json();
+    order["links"] = serde_json::json!([
+        {
+            "href": "https://www.sandbox.paypal.com/checkoutnow?token=5O190127TN364715T",
+            "rel": "approve",
+            "method": "GET"
+        },
+        {
+            "href": "https://api-m.sandbox.paypal.com/v2/checkout/orders/5O190127TN364715T/capture",
+            "rel": "capture",
+            "method": "POST"
+        },
+        {
+            "href": "https://api-m.sandbox.paypal.com/v2/checkout/orders/5O190127TN364715T/confirm-payment-source",
+            "rel": "confirm",
+            "method": "POST"
+        }
+    ]);
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&order))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_string("{}"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "COMPLETED",
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let order = client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    assert_eq!(order.links[0].rel, Some(LinkRel::Approve));
+    assert_eq!(order.links[2].rel, Some(LinkRel::Other("confirm".to_string())));
+
+    let capture = order
+        .links
+        .iter()
+        .find(|link| link.rel == Some(LinkRel::Capture))
+        .unwrap();
+    let order: Order = client.follow(capture, Some(serde_json::json!({}))).await?;
+    assert_eq!(order.status, OrderStatus::Completed);
+
+    let invalid = LinkDescription {
+        href: "not a link".to_string(),
+        ..Default::default()
+    };
+    assert!(matches!(
+        client.follow::<Order>(&invalid, None).await,
+        Err(ResponseError::InvalidLink(_))
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "strict")]
+#[tokio::test]
+async fn test_strict_deserialization() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    let mut order = serde_json::json!({
+        "id": "5O190127TN364715T",
+        "status": "APPROVED",
+        "links": []
+    });
+    order["new_field"] = serde_json::json!({ "not": "modeled" });
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&order))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let result = client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await;
+    assert!(matches!(result, Err(ResponseError::ParseError(e)) if e.to_string().contains("new_field")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_empty_responses() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/payments/authorizations/0VF52814937998046/void"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v3/vault/payment-tokens/8kk8451t"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/payments/captures/2GG279541U471931P/refund"))
+        .respond_with(ResponseTemplate::new(201))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let voided = client.execute(&VoidAuthorizedPayment::new("0VF52814937998046")).await?;
+    assert!(voided.is_none());
+
+    client.execute(&DeletePaymentToken::new("8kk8451t")).await?;
+
+    // A body is still required by the responses that can't be empty.
+    let result = client
+        .execute(&RefundCapturedPayment::new("2GG279541U471931P", Default::default()))
+        .await;
+    assert!(matches!(result, Err(ResponseError::ParseError(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_response_header() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(header(
+            "PayPal-Mock-Response",
+            r#"{"mock_application_codes":"INSTRUMENT_DECLINED"}"#,
+        ))
+        .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+            "name": "UNPROCESSABLE_ENTITY",
+            "details": [{
+                "issue": "INSTRUMENT_DECLINED",
+                "description": "The instrument presented was either declined by the processor or bank, or it can't be used for this payment."
+            }],
+            "message": "The requested action could not be performed, semantically incorrect, or failed business validation.",
+            "debug_id": "c9a75b43fc807",
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let headers = HeaderParams {
+        mock_application_code: Some("INSTRUMENT_DECLINED".to_string()),
+        ..Default::default()
+    };
+    let result = client
+        .execute_ext(&CaptureOrder::new("5O190127TN364715T"), headers)
+        .await;
+    assert!(matches!(result, Err(ResponseError::ApiError(e)) if e.has_issue("INSTRUMENT_DECLINED")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_user_agent_and_extra_headers() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let user_agent = format!("paypal-rs/{} shop/1.2", env!("CARGO_PKG_VERSION"));
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(header("X-Gateway-Route", "payments"))
+        .and(header("User-Agent", user_agent.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(header("X-Gateway-Route", "payments"))
+        .and(header("User-Agent", user_agent.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(order_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut extra_headers = reqwest::header::HeaderMap::new();
+    extra_headers.insert("X-Gateway-Route", "payments".parse()?);
+
+    let mut client = create_client(&mock_server.uri())
+        .with_extra_headers(extra_headers)
+        .with_user_agent_suffix("shop/1.2");
+    assert_eq!(client.user_agent(), user_agent);
+    client.get_access_token().await?;
+    client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_client_for_other_env() -> color_eyre::Result<()> {
+    let sandbox_server = MockServer::start().await;
+    let live_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("liveid", "livesecret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token_type": "Bearer",
+            "expires_in": 32400,
+            "access_token": "LIVETOKEN"
+        })))
+        .mount(&live_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("LIVETOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(order_json()))
+        .expect(1)
+        .mount(&live_server)
+        .await;
+
+    let sandbox = create_client(&sandbox_server.uri());
+    let mut live = sandbox.for_env(
+        PaypalEnv::Mock(live_server.uri()),
+        "liveid".to_string(),
+        "livesecret".to_string(),
+    );
+    live.get_access_token().await?;
+    live.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+
+    assert!(sandbox.current_token().is_none());
+    assert!(sandbox_server.received_requests().await.unwrap().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_http_config() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    let config = HttpConfigBuilder::default().built_in_root_certificates(false).build()?;
+    let mut client = create_client(&mock_server.uri()).with_http_config(&config)?;
+    client.get_access_token().await?;
+    assert!(client.current_token().is_some());
+
+    Ok(())
+}
@@ -0,0 +1,253 @@
+use paypal_rs::{Client, PaypalEnv};
+use paypal_rs::{
+    api::disputes::*,
+    data::common::{Currency, Money, Patch},
+    data::disputes::{
+        DisputeReason, DisputeStatus, EvidenceBuilder, EvidenceFile, EvidenceType, MakeOfferPayloadBuilder,
+        MessagePoster, OfferType, RequireEvidenceAction,
+    },
+};
+use wiremock::matchers::{
+    basic_auth, bearer_token, body_json, body_string, body_string_contains, header, method, path,
+};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+async fn mock_auth(mock_server: &MockServer) {
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_appeal_dispute() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/customer/disputes/PP-D-27803/appeal"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_string_contains("name=\"input\""))
+        .and(body_string_contains("\"evidence_type\":\"PROOF_OF_FULFILLMENT\""))
+        .and(body_string_contains("filename=\"receipt.pdf\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "links": [{
+                "href": "https://api-m.sandbox.paypal.com/v1/customer/disputes/PP-D-27803",
+                "rel": "self",
+                "method": "GET"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let evidence = EvidenceBuilder::default()
+        .evidence_type(EvidenceType::ProofOfFulfillment)
+        .notes("Delivered on time.")
+        .build()?;
+
+    assert!(EvidenceFile::new("receipt.pdf", "not a mime", Vec::new()).is_err());
+
+    let appeal = AppealDispute::new("PP-D-27803", vec![evidence]).file(EvidenceFile::new(
+        "receipt.pdf",
+        "application/pdf",
+        b"%PDF-1.4".to_vec(),
+    )?);
+
+    let response = client.execute(&appeal).await?;
+    assert_eq!(response.links.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dispute_messages() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/get_dispute_response.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/customer/disputes/PP-D-4012"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/customer/disputes/PP-D-4012/send-message"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_string("{\"message\":\"Here it is.\"}"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "links": [] })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let dispute = client.execute(&GetDispute::new("PP-D-4012")).await?;
+    assert_eq!(dispute.reason, Some(DisputeReason::MerchandiseOrServiceNotAsDescribed));
+    assert_eq!(dispute.status, Some(DisputeStatus::UnderReview));
+    assert_eq!(dispute.messages.len(), 2);
+    assert_eq!(dispute.messages[0].posted_by, Some(MessagePoster::Buyer));
+    assert_eq!(dispute.messages[1].documents.as_ref().map(Vec::len), Some(1));
+
+    client
+        .execute(&SendDisputeMessage::new("PP-D-4012", "Here it is."))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_dispute() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/v1/customer/disputes/PP-D-4012"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!([
+            { "op": "add", "path": "/partner_actions/-", "value": { "id": "AMX-22345" } }
+        ])))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let patches = vec![Patch::add(
+        "/partner_actions/-",
+        serde_json::json!({ "id": "AMX-22345" }),
+    )];
+    client.execute(&UpdateDispute::new("PP-D-4012", patches)).await?;
+
+    Ok(())
+}
+
+/// Mocks a dispute action answering with its follow-up links, expecting the given body once.
+async fn mock_action(mock_server: &MockServer, action: &str, body: serde_json::Value) {
+    Mock::given(method("POST"))
+        .and(path(format!("/v1/customer/disputes/PP-D-4012/{}", action)))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "links": [{
+                "href": "https://api-m.sandbox.paypal.com/v1/customer/disputes/PP-D-4012",
+                "rel": "self",
+                "method": "GET"
+            }]
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_dispute_offers() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    mock_action(
+        &mock_server,
+        "make-offer",
+        serde_json::json!({
+            "note": "Offer refund with replacement item.",
+            "offer_amount": { "currency_code": "USD", "value": "23.00" },
+            "offer_type": "REFUND_WITH_REPLACEMENT"
+        }),
+    )
+    .await;
+    mock_action(
+        &mock_server,
+        "accept-offer",
+        serde_json::json!({ "note": "Accepting the offer." }),
+    )
+    .await;
+    mock_action(
+        &mock_server,
+        "deny-offer",
+        serde_json::json!({ "note": "Denying the offer." }),
+    )
+    .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let offer = MakeOfferPayloadBuilder::default()
+        .note("Offer refund with replacement item.")
+        .offer_amount(Money {
+            currency_code: Currency::USD,
+            value: "23.00".to_string(),
+        })
+        .offer_type(OfferType::RefundWithReplacement)
+        .build()?;
+    let response = client.execute(&MakeOffer::new("PP-D-4012", offer)).await?;
+    assert_eq!(response.links.len(), 1);
+
+    client
+        .execute(&AcceptOffer::new("PP-D-4012").note("Accepting the offer."))
+        .await?;
+    client
+        .execute(&DenyOffer::new("PP-D-4012", "Denying the offer."))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_escalate_dispute_and_require_evidence() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    mock_action(
+        &mock_server,
+        "escalate",
+        serde_json::json!({ "note": "Escalating to PayPal claim for resolution." }),
+    )
+    .await;
+    mock_action(
+        &mock_server,
+        "require-evidence",
+        serde_json::json!({ "action": "SELLER_EVIDENCE" }),
+    )
+    .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    client
+        .execute(&EscalateDispute::new(
+            "PP-D-4012",
+            "Escalating to PayPal claim for resolution.",
+        ))
+        .await?;
+    let response = client
+        .execute(&RequireEvidence::new(
+            "PP-D-4012",
+            RequireEvidenceAction::SellerEvidence,
+        ))
+        .await?;
+    assert_eq!(response.links.len(), 1);
+
+    Ok(())
+}
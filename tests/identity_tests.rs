@@ -0,0 +1,94 @@
+use paypal_rs::api::identity::{GenerateClientToken, GetUserInfo};
+use paypal_rs::{Client, PaypalEnv};
+use wiremock::matchers::{basic_auth, bearer_token, body_string, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+async fn mock_auth(mock_server: &MockServer) {
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_get_user_info() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/identity/openidconnect/userinfo"))
+        .and(query_param("schema", "openid"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user_id": "https://www.paypal.com/webapps/auth/identity/user/mWq6_1sU85v5EG9yHdPxJRrhGHrnMJ-1PQKtX6pcsmA",
+            "name": "identity test",
+            "given_name": "identity",
+            "family_name": "test",
+            "payer_id": "WDJJHEBZ4X2LY",
+            "address": {
+                "street_address": "1 Main St",
+                "locality": "San Jose",
+                "region": "CA",
+                "postal_code": "95131",
+                "country": "US"
+            },
+            "verified_account": "true",
+            "emails": [{
+                "value": "user1@example.com",
+                "primary": true
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let user_info = client.execute(&GetUserInfo::new()).await?;
+    assert_eq!(user_info.payer_id.as_deref(), Some("WDJJHEBZ4X2LY"));
+    assert_eq!(user_info.verified_account, Some(true));
+    assert_eq!(user_info.emails[0].value, "user1@example.com");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_client_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/identity/generate-token"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_string("{\"customer_id\":\"customer_1234\"}"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "client_token": "eyJicmFpbnRyZWUiOnsiYXV0aG9yaXphdGlvbkZpbmdlcnByaW50IjoiYjA0MWE2M2JlMTM4M2NlZGUxZTI3OWFlNDlhMWIyNzZlY2FjOTYzOWU2NjlhMGIzODQyYTdkMTY3NzcwYmY0OHxtZXJjaGFudF9pZD1yd3dua3FnMnhnNTZobTJuJnB1YmxpY19rZXk9czlic3BuaGtxMmYzaDk0NCZjcmVhdGVkX2F0PTIwMTgtMTEtMTRUMTE6MTg6MDAuMTU3WiJ9LCJwYXlwYWwiOnsiYWNjZXNzVG9rZW4iOiJBMjFBQUhNVExyMmctVDlhSTJacUZHUmlFZ0ZFZGRHTGwxTzRlX0lvdk9ESVg2Q3pSdW5BVy02TzI2MjdiWUJ2cDNjQ0FNWi1lTFBNc2NDWnN0bDUyNHJyUGhUQklJNlBBIn19",
+            "expires_in": 3600
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let token = client
+        .execute(&GenerateClientToken::new().customer_id("customer_1234"))
+        .await?;
+    assert_eq!(token.expires_in, 3600);
+
+    Ok(())
+}
@@ -1,9 +1,14 @@
 use paypal_rs::{Client, PaypalEnv};
 use paypal_rs::{
     api::orders::*,
-    data::{common::AddressBuilder, orders::*},
+    countries::Country,
+    data::{
+        common::{AddressBuilder, Patch},
+        orders::*,
+        vault::*,
+    },
 };
-use wiremock::matchers::{basic_auth, bearer_token, body_string, header, method, path};
+use wiremock::matchers::{basic_auth, bearer_token, body_json, body_partial_json, body_string, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 fn create_client(url: &str) -> Client {
@@ -68,16 +73,16 @@ async fn test_create_order() -> color_eyre::Result<()> {
                                 .admin_area_1("CA")
                                 .admin_area_2("San Jose")
                                 .postal_code("95131")
-                                .country_code("US")
+                                .country_code(Country::US)
                                 .build()?,
                         )
                         .build()?,
                 )
                 .stored_credential(
                     StoredCredentialBuilder::default()
-                        .payment_initiator("MERCHANT")
-                        .payment_type("RECURRING")
-                        .usage("SUBSEQUENT")
+                        .payment_initiator(PaymentInitiator::Merchant)
+                        .payment_type(StoredPaymentType::Recurring)
+                        .usage(StoredCredentialUsage::Subsequent)
                         .previous_network_transaction_reference(
                             TransactionReferenceBuilder::default()
                                 .id("156GHJ654SFH543")
@@ -95,6 +100,97 @@ async fn test_create_order() -> color_eyre::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_create_order_and_vault() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(serde_json::json!({
+            "payment_source": {
+                "paypal": {
+                    "attributes": {
+                        "vault": {
+                            "store_in_vault": "ON_SUCCESS",
+                            "usage_type": "MERCHANT",
+                            "customer_type": "CONSUMER"
+                        }
+                    }
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "COMPLETED",
+            "payment_source": {
+                "paypal": {
+                    "email_address": "customer@example.com",
+                    "account_id": "QYR5Z8XDVJNXQ",
+                    "account_status": "VERIFIED",
+                    "name": { "given_name": "John", "surname": "Doe" },
+                    "address": { "country_code": "US" },
+                    "attributes": {
+                        "vault": {
+                            "id": "nkq2y9g",
+                            "status": "VAULTED",
+                            "customer": { "id": "ROaQ5r8Kvj" }
+                        }
+                    }
+                }
+            },
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let vault = VaultInstructionBuilder::default()
+        .usage_type(UsageType::Merchant)
+        .customer_type(CustomerType::Consumer)
+        .build()?;
+
+    let order = OrderPayloadBuilder::default()
+        .intent(Intent::Capture)
+        .purchase_units(vec![PurchaseUnit::new(Amount::usd("100.00"))])
+        .payment_source(
+            OrderPaymentSourceBuilder::default()
+                .paypal(
+                    PaypalPaymentSourceBuilder::default()
+                        .attributes(PaymentSourceAttributesBuilder::default().vault(vault).build()?)
+                        .build()?,
+                )
+                .build()?,
+        )
+        .build()?;
+
+    let order = client.execute(&CreateOrder::new(order)).await?;
+    let payment_source = order.payment_source.unwrap();
+    let paypal = payment_source.paypal.as_ref().unwrap();
+    assert_eq!(paypal.email_address.as_deref(), Some("customer@example.com"));
+    assert_eq!(paypal.account_status, Some(PaypalAccountStatus::Verified));
+    assert_eq!(paypal.name.as_ref().unwrap().surname, "Doe");
+
+    let vault = payment_source.vault().unwrap();
+    assert_eq!(vault.id.as_deref(), Some("nkq2y9g"));
+    assert_eq!(vault.status, Some(VaultStatus::Vaulted));
+
+    Ok(())
+}
+
 /*
 
 #[tokio::test]
@@ -165,3 +261,136 @@ async fn test_order2() -> anyhow::Result<()> {
 
     Ok(())
 } */
+
+#[tokio::test]
+async fn test_order_sca_contingency() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "PAYER_ACTION_REQUIRED",
+            "payment_source": {
+                "card": { "last_digits": "1091", "brand": "VISA", "type": "CREDIT" }
+            },
+            "links": [{
+                "href": "https://www.sandbox.paypal.com/webapps/helios?action=verify&flow=3ds&cart_id=5O190127TN364715T",
+                "rel": "payer-action",
+                "method": "GET"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "APPROVED",
+            "payment_source": {
+                "card": {
+                    "last_digits": "1091",
+                    "authentication_result": {
+                        "liability_shift": "NO",
+                        "three_d_secure": {
+                            "enrollment_status": "Y",
+                            "authentication_status": "R"
+                        }
+                    }
+                }
+            },
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let order = OrderPayloadBuilder::default()
+        .intent(Intent::Capture)
+        .purchase_units(vec![PurchaseUnit::new(Amount::usd("100.00"))])
+        .build()?;
+
+    let order = client.execute(&CreateOrder::new(order)).await?;
+    assert_eq!(order.status, OrderStatus::PayerActionRequired);
+    let card = order
+        .payment_source
+        .as_ref()
+        .and_then(|source| source.card.as_ref())
+        .unwrap();
+    assert_eq!(card.brand, Some(CardBrand::Visa));
+    assert_eq!(card.card_type, Some(CardType::Credit));
+    assert!(matches!(
+        order.sca_contingency(),
+        Some(ScaContingency::RedirectPayer { url }) if url.contains("flow=3ds")
+    ));
+
+    let order = client.execute(&ShowOrderDetails::new(&order.id)).await?;
+    let Some(ScaContingency::Rejected(result)) = order.sca_contingency() else {
+        panic!("expected the authentication to be rejected");
+    };
+    assert_eq!(result.liability_shift, Some(LiabilityShift::No));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_order() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!([
+            {
+                "op": "replace",
+                "path": "/purchase_units/@reference_id=='default'/amount",
+                "value": { "currency_code": "USD", "value": "15.00" }
+            },
+            {
+                "op": "remove",
+                "path": "/purchase_units/@reference_id=='default'/shipping/address"
+            }
+        ])))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let update = UpdateOrder::new(
+        "5O190127TN364715T",
+        vec![
+            Patch::replace(Patch::purchase_unit_path("default", "amount")?, Amount::usd("15.00")),
+            Patch::remove(Patch::purchase_unit_path("default", "shipping/address")?),
+        ],
+    );
+    client.execute(&update).await?;
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use paypal_rs::{Client, PaypalEnv};
+use paypal_rs::{api::partner_referrals::*, data::partner_referrals::*};
+use wiremock::matchers::{basic_auth, bearer_token, body_json, body_string, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+async fn mock_auth(mock_server: &MockServer) {
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_create_partner_referral() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/customer/partner-referrals"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!({
+            "tracking_id": "seller-1234",
+            "partner_config_override": { "return_url": "https://example.com/onboarded" },
+            "operations": [{
+                "operation": "API_INTEGRATION",
+                "api_integration_preference": {
+                    "rest_api_integration": {
+                        "integration_method": "PAYPAL",
+                        "integration_type": "THIRD_PARTY",
+                        "third_party_details": { "features": ["PAYMENT", "REFUND"] }
+                    }
+                }
+            }],
+            "products": ["EXPRESS_CHECKOUT"],
+            "legal_consents": [{ "type": "SHARE_DATA_CONSENT", "granted": true }]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "links": [
+                {
+                    "href": "https://uri.paypal.com/v2/customer/partner-referrals/ZjcyODU4ZWYtYTA1OC00ODIwLTk2M2EtOTZkZWQ4NmQwYzI3RU12cE5xa0xMRmk1NWxFSVJIT1JlTFdSbElCbFU1Q3lhdGhESzVQcU9iRT0=",
+                    "rel": "self",
+                    "method": "GET"
+                },
+                {
+                    "href": "https://www.paypal.com/merchantsignup/partner/onboardingentry?token=ZjcyODU4ZWYtYTA1OC00ODIwLTk2M2EtOTZkZWQ4NmQwYzI3RU12cE5xa0xMRmk1NWxFSVJIT1JlTFdSbElCbFU1Q3lhdGhESzVQcU9iRT0=",
+                    "rel": "action_url",
+                    "method": "GET"
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let payload = PartnerReferralPayloadBuilder::default()
+        .tracking_id("seller-1234")
+        .partner_config_override(
+            PartnerConfigOverrideBuilder::default()
+                .return_url("https://example.com/onboarded")
+                .build()?,
+        )
+        .operations(vec![Operation::third_party(vec![Feature::Payment, Feature::Refund])])
+        .products(vec![Product::ExpressCheckout])
+        .legal_consents(vec![LegalConsent::share_data()])
+        .build()?;
+
+    let referral = client.execute(&CreatePartnerReferral::new(payload)).await?;
+    assert!(
+        referral
+            .action_url()
+            .is_some_and(|url| url.starts_with("https://www.paypal.com/merchantsignup"))
+    );
+    assert_eq!(
+        referral.referral_id(),
+        Some(
+            "ZjcyODU4ZWYtYTA1OC00ODIwLTk2M2EtOTZkZWQ4NmQwYzI3RU12cE5xa0xMRmk1NWxFSVJIT1JlTFdSbElCbFU1Q3lhdGhESzVQcU9iRT0="
+        )
+    );
+
+    Ok(())
+}
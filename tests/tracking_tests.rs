@@ -0,0 +1,256 @@
+use paypal_rs::errors::BatchTrackingError;
+use paypal_rs::{Client, PaypalEnv};
+use paypal_rs::{
+    api::tracking::*,
+    data::{orders::TrackerStatus, shipment_carrier::ShipmentCarrier, tracking::*},
+};
+use wiremock::matchers::{basic_auth, bearer_token, body_json, body_string, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+async fn mock_auth(mock_server: &MockServer) {
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_add_trackers_batch() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/shipping/trackers-batch"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!({
+            "trackers": [
+                {
+                    "transaction_id": "8MC585209K746392H",
+                    "tracking_number": "443844607820",
+                    "status": "SHIPPED",
+                    "carrier": "FEDEX",
+                    "shipment_date": "2016-11-29"
+                },
+                {
+                    "transaction_id": "53Y56775AE587553X",
+                    "tracking_number": "443844607821",
+                    "status": "SHIPPED",
+                    "carrier": "FEDEX"
+                }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "tracker_identifiers": [{
+                "transaction_id": "8MC585209K746392H",
+                "tracking_number": "443844607820",
+                "links": [{
+                    "href": "https://api-m.sandbox.paypal.com/v1/shipping/trackers/8MC585209K746392H-443844607820",
+                    "rel": "self",
+                    "method": "GET"
+                }]
+            }],
+            "errors": [{
+                "name": "RESOURCE_NOT_FOUND",
+                "debug_id": "46735c7461f3d",
+                "message": "The specified resource does not exist.",
+                "details": [{
+                    "field": "/trackers/1/transaction_id",
+                    "value": "53Y56775AE587553X",
+                    "location": "body",
+                    "issue": "INVALID_TRANSACTION_ID",
+                    "description": "The requested transaction ID was not found"
+                }]
+            }],
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let trackers = vec![
+        TrackerBuilder::default()
+            .transaction_id("8MC585209K746392H")
+            .tracking_number("443844607820")
+            .status(TrackerStatus::Shipped)
+            .carrier(ShipmentCarrier::Fedex)
+            .shipment_date(chrono::NaiveDate::from_ymd_opt(2016, 11, 29).unwrap())
+            .build()?,
+        TrackerBuilder::default()
+            .transaction_id("53Y56775AE587553X")
+            .tracking_number("443844607821")
+            .status(TrackerStatus::Shipped)
+            .carrier(ShipmentCarrier::Fedex)
+            .build()?,
+    ];
+
+    let response = client.execute(&AddTrackersBatch::new(trackers)).await?;
+    assert_eq!(response.tracker_identifiers.len(), 1);
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(response.errors[0].name, "RESOURCE_NOT_FOUND");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_tracker() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/shipping/trackers/8MC585209K746392H-443844607820"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "transaction_id": "8MC585209K746392H",
+            "tracking_number": "443844607820",
+            "status": "SHIPPED",
+            "carrier": "FEDEX",
+            "shipment_date": "2016-11-29",
+            "last_updated_time": "2016-11-29T22:24:36Z",
+            "links": [{
+                "href": "https://api-m.sandbox.paypal.com/v1/shipping/trackers/8MC585209K746392H-443844607820",
+                "rel": "self",
+                "method": "GET"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let tracker = client
+        .execute(&GetTracker::new("8MC585209K746392H", "443844607820"))
+        .await?;
+    assert_eq!(tracker.status, TrackerStatus::Shipped);
+    assert_eq!(tracker.id(), "8MC585209K746392H-443844607820");
+    assert!(tracker.last_updated_time.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_tracker() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/shipping/trackers/8MC585209K746392H-443844607820"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!({
+            "transaction_id": "8MC585209K746392H",
+            "tracking_number": "443844607821",
+            "status": "CANCELLED",
+            "carrier": "FEDEX"
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let tracker = TrackerBuilder::default()
+        .transaction_id("8MC585209K746392H")
+        .tracking_number("443844607821")
+        .status(TrackerStatus::Cancelled)
+        .carrier(ShipmentCarrier::Fedex)
+        .build()?;
+
+    client
+        .execute(&UpdateTracker::new("8MC585209K746392H-443844607820", tracker))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_order_tracking_batch() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/track"))
+        .and(body_json(serde_json::json!({
+            "tracking_number": "443844607820",
+            "carrier": "FEDEX",
+            "capture_id": "8MC585209K746392H",
+            "notify_payer": true
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "COMPLETED",
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/track"))
+        .and(body_json(serde_json::json!({
+            "tracking_number": "443844607821",
+            "carrier": "FEDEX",
+            "capture_id": "2GG279541U471931P",
+            "notify_payer": false
+        })))
+        .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+            "name": "UNPROCESSABLE_ENTITY",
+            "message": "The requested action could not be performed."
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let tracker = |tracking_number: &str, capture_id: &str| OrderTracking {
+        tracking_number: tracking_number.to_string(),
+        carrier: ShipmentCarrier::Fedex,
+        capture_id: capture_id.to_string(),
+        ..Default::default()
+    };
+
+    // An empty batch is rejected without sending anything.
+    let empty = AddOrderTrackingBatch::new("5O190127TN364715T", vec![], NotifyPayerPolicy::FirstOnly);
+    assert!(matches!(empty.run(&client).await, Err(BatchTrackingError::Empty)));
+
+    let batch = AddOrderTrackingBatch::new(
+        "5O190127TN364715T",
+        vec![
+            tracker("443844607820", "8MC585209K746392H"),
+            tracker("443844607821", "2GG279541U471931P"),
+        ],
+        NotifyPayerPolicy::FirstOnly,
+    );
+    match batch.run(&client).await {
+        Err(BatchTrackingError::Failed { order, total, failed }) => {
+            assert_eq!(order.unwrap().id, "5O190127TN364715T");
+            assert_eq!(total, 2);
+            assert_eq!(failed.len(), 1);
+            assert_eq!(failed[0].0, "2GG279541U471931P");
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use chrono::TimeZone;
+use futures::TryStreamExt;
+use paypal_rs::retry::RetryPolicyBuilder;
+use paypal_rs::{Client, PaypalEnv};
+use paypal_rs::{
+    api::transactions::{ListBalances, ListTransactions},
+    data::common::Currency,
+    data::transactions::{
+        AmountRange, BalancesQueryBuilder, TransactionField, TransactionSearchQueryBuilder, TransactionStatus,
+    },
+};
+use std::time::Duration;
+use wiremock::matchers::{basic_auth, bearer_token, body_string, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+async fn mock_auth(mock_server: &MockServer) {
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_list_transactions() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/list_transactions_response.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/reporting/transactions"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(query_param("start_date", "2014-07-01T00:00:00Z"))
+        .and(query_param("end_date", "2014-07-30T23:59:59Z"))
+        .and(query_param("transaction_status", "S"))
+        .and(query_param("transaction_amount", "500 TO 1005"))
+        .and(query_param("fields", "transaction_info,payer_info"))
+        .and(query_param("page_size", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let query = TransactionSearchQueryBuilder::default()
+        .start_date(chrono::Utc.with_ymd_and_hms(2014, 7, 1, 0, 0, 0).unwrap())
+        .end_date(chrono::Utc.with_ymd_and_hms(2014, 7, 30, 23, 59, 59).unwrap())
+        .transaction_status(TransactionStatus::Success)
+        .transaction_amount(AmountRange::new(500, 1005))
+        .fields(vec![TransactionField::TransactionInfo, TransactionField::PayerInfo])
+        .page_size(100)
+        .build()?;
+
+    let response = client.execute(&ListTransactions::new(query)).await?;
+    assert_eq!(response.total_pages, Some(1));
+    assert_eq!(response.transaction_details.len(), 1);
+
+    let detail = &response.transaction_details[0];
+    let info = detail.transaction_info.as_ref().unwrap();
+    assert_eq!(info.transaction_event_code.as_deref(), Some("T0006"));
+    assert_eq!(info.transaction_status, Some(TransactionStatus::Success));
+    assert_eq!(info.fee_amount.as_ref().map(|fee| fee.value.as_str()), Some("-13.79"));
+    assert_eq!(info.invoice_id.as_deref(), Some("Invoice-005"));
+    assert_eq!(
+        info.transaction_initiation_date,
+        Some(chrono::Utc.with_ymd_and_hms(2014, 7, 11, 4, 3, 52).unwrap())
+    );
+
+    let payer_name = detail
+        .payer_info
+        .as_ref()
+        .and_then(|payer| payer.payer_name.as_ref())
+        .unwrap();
+    assert_eq!(payer_name.alternate_full_name.as_deref(), Some("test consumer"));
+
+    let cart = detail.cart_info.as_ref().unwrap();
+    assert_eq!(cart.item_details[0].item_quantity.as_deref(), Some("2"));
+    assert_eq!(cart.item_details[0].tax_amounts.len(), 1);
+
+    let shipping_address = detail
+        .shipping_info
+        .as_ref()
+        .and_then(|shipping| shipping.address.as_ref())
+        .unwrap();
+    assert_eq!(shipping_address.postal_code.as_deref(), Some("560103"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_transactions() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    // The second page is rate limited once, and retried by the client.
+    Mock::given(method("GET"))
+        .and(path("/v1/reporting/transactions"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+            "name": "RATE_LIMIT_REACHED",
+            "message": "Too many requests. Blocked due to rate limiting."
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    for page in 1..=3 {
+        Mock::given(method("GET"))
+            .and(path("/v1/reporting/transactions"))
+            .and(bearer_token("TESTBEARERTOKEN"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "transaction_details": [
+                    { "transaction_info": { "transaction_id": format!("TXN-{}-1", page) } },
+                    { "transaction_info": { "transaction_id": format!("TXN-{}-2", page) } }
+                ],
+                "page": page,
+                "total_items": 6,
+                "total_pages": 3,
+                "links": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let policy = RetryPolicyBuilder::default()
+        .base_delay(Duration::from_millis(10))
+        .build()?;
+    let mut client = create_client(&mock_server.uri()).with_retry_policy(policy);
+    client.get_access_token().await?;
+
+    let query = TransactionSearchQueryBuilder::default()
+        .start_date(chrono::Utc.with_ymd_and_hms(2014, 7, 1, 0, 0, 0).unwrap())
+        .end_date(chrono::Utc.with_ymd_and_hms(2014, 7, 30, 23, 59, 59).unwrap())
+        .build()?;
+
+    let transactions: Vec<_> = ListTransactions::new(query).stream(&client).try_collect().await?;
+    assert_eq!(transactions.len(), 6);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_balances() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/reporting/balances"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(query_param("currency_code", "USD"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "balances": [{
+                "currency": "USD",
+                "primary": true,
+                "total_balance": { "currency_code": "USD", "value": "1000.00" },
+                "available_balance": { "currency_code": "USD", "value": "900.00" },
+                "withheld_balance": { "currency_code": "USD", "value": "100.00" }
+            }],
+            "account_id": "YJKFLA8N4YXZS",
+            "as_of_time": "2016-10-07T18:56:17Z",
+            "last_refresh_time": "2016-10-07T18:55:59Z"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let query = BalancesQueryBuilder::default().currency_code(Currency::USD).build()?;
+    let response = client.execute(&ListBalances::new(query)).await?;
+    assert_eq!(response.balances.len(), 1);
+    assert_eq!(response.balances[0].currency, Currency::USD);
+    assert_eq!(
+        response.balances[0]
+            .withheld_balance
+            .as_ref()
+            .map(|money| money.value.as_str()),
+        Some("100.00")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,297 @@
+use futures::TryStreamExt;
+use paypal_rs::{Client, PaypalEnv};
+use paypal_rs::{api::vault::*, data::vault::*};
+use wiremock::matchers::{basic_auth, bearer_token, body_json, body_string, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+async fn mock_auth(mock_server: &MockServer) {
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_create_setup_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v3/vault/setup-tokens"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!({
+            "payment_source": {
+                "paypal": {
+                    "description": "Description for PayPal to be shown to PayPal payer",
+                    "usage_pattern": "IMMEDIATE",
+                    "usage_type": "MERCHANT",
+                    "customer_type": "CONSUMER",
+                    "permit_multiple_payment_tokens": false,
+                    "experience_context": {
+                        "return_url": "https://example.com/returnUrl",
+                        "cancel_url": "https://example.com/cancelUrl"
+                    }
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "4G4976650J0948357",
+            "customer": { "id": "customer_4029352050" },
+            "status": "PAYER_ACTION_REQUIRED",
+            "payment_source": { "paypal": { "description": "Description for PayPal to be shown to PayPal payer" } },
+            "links": [
+                {
+                    "href": "https://www.sandbox.paypal.com/agreements/approve?approval_session_id=4G4976650J0948357",
+                    "rel": "approve",
+                    "method": "GET"
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let wallet = VaultWalletBuilder::default()
+        .description("Description for PayPal to be shown to PayPal payer")
+        .usage_pattern(UsagePattern::Immediate)
+        .usage_type(UsageType::Merchant)
+        .customer_type(CustomerType::Consumer)
+        .permit_multiple_payment_tokens(false)
+        .experience_context(
+            WalletExperienceContextBuilder::default()
+                .return_url("https://example.com/returnUrl")
+                .cancel_url("https://example.com/cancelUrl")
+                .build()?,
+        )
+        .build()?;
+
+    let payload = SetupTokenPayloadBuilder::default()
+        .payment_source(SetupTokenPaymentSourceBuilder::default().paypal(wallet).build()?)
+        .build()?;
+
+    let setup_token = client.execute(&CreateSetupToken::new(payload)).await?;
+    assert_eq!(setup_token.id, "4G4976650J0948357");
+    assert_eq!(setup_token.status, Some(SetupTokenStatus::PayerActionRequired));
+    assert!(setup_token.approve_url().is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_setup_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v3/vault/setup-tokens/5C991763VB2781612"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5C991763VB2781612",
+            "customer": { "id": "customer_4029352050" },
+            "status": "APPROVED",
+            "payment_source": { "card": { "last_digits": "1111", "expiry": "2027-02", "brand": "VISA" } },
+            "links": [{
+                "href": "https://api-m.sandbox.paypal.com/v3/vault/setup-tokens/5C991763VB2781612",
+                "rel": "self",
+                "method": "GET"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let setup_token = client.execute(&GetSetupToken::new("5C991763VB2781612")).await?;
+    assert!(setup_token.is_approved());
+    assert!(setup_token.approve_url().is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_payment_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v3/vault/payment-tokens"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!({
+            "payment_source": { "token": { "id": "5C991763VB2781612", "type": "SETUP_TOKEN" } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "8kk845",
+            "customer": { "id": "customer_4029352050" },
+            "payment_source": {
+                "paypal": {
+                    "description": "Description for PayPal to be shown to PayPal payer",
+                    "shipping": {
+                        "name": { "full_name": "Firstname Lastname" },
+                        "address": {
+                            "address_line_1": "123 Main Street",
+                            "admin_area_2": "San Jose",
+                            "admin_area_1": "CA",
+                            "postal_code": "95131",
+                            "country_code": "US"
+                        }
+                    },
+                    "usage_pattern": "IMMEDIATE",
+                    "usage_type": "MERCHANT",
+                    "customer_type": "CONSUMER",
+                    "email_address": "email@example.com",
+                    "payer_id": "payerId",
+                    "name": { "given_name": "Firstname", "surname": "Lastname" }
+                }
+            },
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let payment_token = client
+        .execute(&CreatePaymentToken::from_setup_token("5C991763VB2781612"))
+        .await?;
+    assert_eq!(payment_token.id, "8kk845");
+
+    let paypal = payment_token.payment_source.and_then(|source| source.paypal).unwrap();
+    assert_eq!(paypal.payer_id.as_deref(), Some("payerId"));
+    assert_eq!(
+        paypal
+            .shipping
+            .and_then(|shipping| shipping.name)
+            .map(|name| name.full_name),
+        Some("Firstname Lastname".to_string())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_payment_tokens() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v3/vault/payment-tokens"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(query_param("customer_id", "customer_4029352050"))
+        .and(query_param("page", "2"))
+        .and(query_param("total_required", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "customer": { "id": "customer_4029352050" },
+            "payment_tokens": [{
+                "id": "8kk8451t",
+                "customer": { "id": "customer_4029352050" },
+                "payment_source": {
+                    "card": { "name": "John Doe", "brand": "VISA", "last_digits": "1111", "expiry": "2027-02" }
+                },
+                "links": []
+            }],
+            "total_items": 6,
+            "total_pages": 2,
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let query = PaymentTokensQueryBuilder::default()
+        .customer_id("customer_4029352050")
+        .page(2)
+        .total_required(true)
+        .build()?;
+    let list = client.execute(&ListPaymentTokens::new(query)).await?;
+    assert_eq!(list.total_pages, Some(2));
+
+    let card = list.payment_tokens[0]
+        .payment_source
+        .as_ref()
+        .and_then(|source| source.card.as_ref())
+        .unwrap();
+    assert_eq!(card.last_digits.as_deref(), Some("1111"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_payment_tokens_pages() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    for page in 1..=2 {
+        Mock::given(method("GET"))
+            .and(path("/v3/vault/payment-tokens"))
+            .and(query_param("customer_id", "customer_4029352050"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "customer": { "id": "customer_4029352050" },
+                "payment_tokens": [
+                    { "id": format!("token_{page}a"), "links": [] },
+                    { "id": format!("token_{page}b"), "links": [] }
+                ],
+                "links": if page == 1 {
+                    serde_json::json!([{ "href": "https://api-m.paypal.com/v3/vault/payment-tokens?page=2", "rel": "next" }])
+                } else {
+                    serde_json::json!([])
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let tokens: Vec<PaymentToken> = client
+        .items(ListPaymentTokens::for_customer("customer_4029352050"))
+        .try_collect()
+        .await?;
+    let ids: Vec<_> = tokens.iter().map(|token| token.id.as_str()).collect();
+    assert_eq!(ids, ["token_1a", "token_1b", "token_2a", "token_2b"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_payment_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_auth(&mock_server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v3/vault/payment-tokens/8kk8451t"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    client.execute(&DeletePaymentToken::new("8kk8451t")).await?;
+
+    Ok(())
+}